@@ -37,17 +37,53 @@ pub enum Commands {
         /// Output path for rollback.json
         #[arg(short, long, value_name = "OUTPUT")]
         output: Option<PathBuf>,
+
+        /// Record checksums for same-filesystem moves too (slower, enables verified rollback)
+        #[arg(long)]
+        checksum: bool,
+
+        /// Ask configured media servers (Jellyfin/Emby/Plex) to rescan the affected folders
+        #[arg(long)]
+        refresh_library: bool,
+
+        /// Show the media server refresh requests without sending them
+        #[arg(long)]
+        refresh_dry_run: bool,
     },
 
     /// Rollback a previous execution
+    #[command(args_conflicts_with_subcommands = true)]
     Rollback {
+        #[command(subcommand)]
+        action: Option<RollbackAction>,
+
         /// Path to the rollback.json file
-        #[arg(value_name = "ROLLBACK_FILE")]
-        rollback_file: PathBuf,
+        #[arg(value_name = "ROLLBACK_FILE", required = true)]
+        rollback_file: Option<PathBuf>,
 
         /// Dry run - show what would be done
         #[arg(long)]
         dry_run: bool,
+
+        /// Only roll back operations in this sequence range (e.g. "5" or "3-10")
+        #[arg(long, value_name = "RANGE")]
+        seq: Option<String>,
+
+        /// Only roll back operations whose target is under this folder
+        #[arg(long, value_name = "FOLDER")]
+        folder: Option<PathBuf>,
+
+        /// Only roll back operations whose target path contains this title
+        #[arg(long, value_name = "TITLE")]
+        title: Option<String>,
+
+        /// Output path for the residual rollback file (partial rollback only)
+        #[arg(long, value_name = "OUTPUT")]
+        residual: Option<PathBuf>,
+
+        /// Refuse to move back files whose checksum no longer matches
+        #[arg(long)]
+        verify_checksum: bool,
     },
 
     /// Manage sessions
@@ -63,6 +99,40 @@ pub enum Commands {
         path: PathBuf,
     },
 
+    /// Watch an incoming folder and plan new downloads automatically
+    Watch {
+        /// Incoming folder to watch
+        #[arg(value_name = "INCOMING")]
+        incoming: PathBuf,
+
+        /// Target directory for organized files
+        #[arg(short, long, value_name = "TARGET")]
+        target: PathBuf,
+
+        /// Media type: movies or tvshows
+        #[arg(long, default_value = "movies")]
+        media_type: String,
+
+        /// Seconds a download must stop growing before it is planned
+        #[arg(long, default_value = "30")]
+        quiet_secs: u64,
+
+        /// Execute high-confidence items immediately (others stay pending)
+        #[arg(long)]
+        auto_execute: bool,
+
+        /// Minimum confidence for auto-execution
+        #[arg(long, default_value = "0.9")]
+        min_confidence: f32,
+    },
+
+    /// Run a local HTTP/JSON API server
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:8686")]
+        bind: String,
+    },
+
     /// Build or update the central media index
     Index {
         #[command(subcommand)]
@@ -99,6 +169,26 @@ pub enum Commands {
         #[arg(long)]
         country: Option<String>,
 
+        /// Query expression, e.g. 'actor:"Tony Leung" rating>7.5 sort:rating limit:20'
+        #[arg(short = 'q', long)]
+        query: Option<String>,
+
+        /// Only titles marked watched
+        #[arg(long, conflicts_with = "unwatched")]
+        watched: bool,
+
+        /// Only titles not marked watched
+        #[arg(long)]
+        unwatched: bool,
+
+        /// Only favorite titles
+        #[arg(long)]
+        favorite: bool,
+
+        /// Only titles with this personal tag
+        #[arg(long)]
+        tag: Option<String>,
+
         /// Show disk online/offline status
         #[arg(long)]
         show_status: bool,
@@ -183,14 +273,25 @@ pub enum IndexAction {
         #[arg(long)]
         disk_label: Option<String>,
 
-        /// Force re-index (replace existing entries)
+        /// Re-parse every NFO instead of only those that changed since the last scan
         #[arg(long)]
         force: bool,
+
+        /// Fill entries missing a TMDB ID or genres from TMDB (needs TMDB_API_KEY)
+        #[arg(long)]
+        tmdb_lookup: bool,
     },
 
     /// Show collection statistics
     Stats,
 
+    /// Show capacity and free space of the indexed disks
+    Disks {
+        /// Output format: table, simple, json
+        #[arg(long, default_value = "table")]
+        format: String,
+    },
+
     /// List contents of a specific disk
     List {
         /// Disk label to list
@@ -231,13 +332,36 @@ pub enum IndexAction {
         format: String,
     },
 
+    /// Find files with identical content, across online disks or in given folders
+    Dedup {
+        /// Folders to check (default: indexed paths of online disks)
+        #[arg(value_name = "PATH")]
+        paths: Vec<PathBuf>,
+
+        /// What to do with extra copies: report, hardlink, or plan
+        #[arg(long, default_value = "report")]
+        action: String,
+
+        /// Quarantine folder for --action plan (default: .duplicates in each checked folder)
+        #[arg(long)]
+        quarantine: Option<PathBuf>,
+
+        /// Output path of the quarantine plan (default: dedup_plan.json)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Output format: table, json
+        #[arg(long, default_value = "table")]
+        format: String,
+    },
+
     /// List movie collections (franchise series)
     Collections {
         /// Filter: complete, incomplete, or all
         #[arg(long, default_value = "all")]
         filter: String,
 
-        /// Output format: table, simple, json
+        /// Output format: table, simple, json, csv
         #[arg(long, default_value = "table")]
         format: String,
 
@@ -248,6 +372,144 @@ pub enum IndexAction {
         /// Update collection totals from TMDB and write back to NFO files
         #[arg(long)]
         update: bool,
+
+        /// Fetch each collection from TMDB and list the movies not owned
+        #[arg(long)]
+        missing: bool,
+    },
+
+    /// Show the episodes of a TV show by season, across disks
+    Episodes {
+        /// TV show title
+        #[arg(value_name = "TITLE")]
+        title: String,
+
+        /// List the episode files of this season
+        #[arg(long)]
+        season: Option<u16>,
+
+        /// Only this episode (with --season)
+        #[arg(long, requires = "season")]
+        episode: Option<u16>,
+
+        /// Output format: table, simple, json
+        #[arg(long, default_value = "table")]
+        format: String,
+    },
+
+    /// Compare TV show episodes on disk with TMDB and list missing ones
+    MissingEpisodes {
+        /// Only check TV shows matching this title
+        #[arg(long)]
+        show: Option<String>,
+
+        /// Also check specials (season 0)
+        #[arg(long)]
+        include_specials: bool,
+
+        /// Output format: table, json
+        #[arg(long, default_value = "table")]
+        format: String,
+    },
+
+    /// Rank movies worth replacing with a better copy
+    Upgrades {
+        /// Only list titles whose best copy scores below this (0-100)
+        #[arg(long, default_value = "60")]
+        below: u32,
+
+        /// Only list titles not owned above this resolution (e.g. 720p)
+        #[arg(long)]
+        max_resolution: Option<String>,
+
+        /// Maximum number of titles to list
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Output format: table, simple, json
+        #[arg(long, default_value = "table")]
+        format: String,
+    },
+
+    /// Mark titles watched or favorite, rate, tag or annotate them
+    Mark {
+        /// Titles to mark, as a search query (e.g. "Heat year:1995")
+        #[arg(value_name = "QUERY")]
+        query: String,
+
+        /// Mark as watched
+        #[arg(long, conflicts_with = "unwatched")]
+        watched: bool,
+
+        /// Mark as not watched
+        #[arg(long)]
+        unwatched: bool,
+
+        /// Watch date for --watched (default: today)
+        #[arg(long, requires = "watched")]
+        date: Option<String>,
+
+        /// Mark as favorite
+        #[arg(long, conflicts_with = "unfavorite")]
+        favorite: bool,
+
+        /// Remove the favorite mark
+        #[arg(long)]
+        unfavorite: bool,
+
+        /// Personal rating (0-10)
+        #[arg(long)]
+        rating: Option<f32>,
+
+        /// Add a personal tag (repeatable)
+        #[arg(long)]
+        tag: Vec<String>,
+
+        /// Remove a personal tag (repeatable)
+        #[arg(long)]
+        untag: Vec<String>,
+
+        /// Personal notes ("" clears them)
+        #[arg(long)]
+        notes: Option<String>,
+
+        /// Mark every matching title instead of requiring a single one
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Move indexed titles from one disk to another
+    Migrate {
+        /// Disk label to move titles from
+        #[arg(long)]
+        from: String,
+
+        /// Disk label to move titles to
+        #[arg(long)]
+        to: String,
+
+        /// Only move titles matching a search query (e.g. "genre:horror year<2000")
+        #[arg(long)]
+        filter: Option<String>,
+
+        /// Media type filter: movies, tvshows, or all
+        #[arg(long, default_value = "all")]
+        media_type: String,
+
+        /// Output path of the migration plan (default: migrate_<FROM>_to_<TO>.json)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Execute the plan now and update the index afterwards
+        #[arg(long)]
+        execute: bool,
+    },
+
+    /// Copy the central index to another storage backend
+    Convert {
+        /// Target backend: sqlite or json
+        #[arg(long)]
+        to: String,
     },
 }
 
@@ -259,7 +521,7 @@ pub enum PlanType {
         #[arg(value_name = "SOURCE")]
         source: PathBuf,
 
-        /// Target directory for organized movies
+        /// Target directory for organized movies, or "auto" to pick an indexed disk per title
         #[arg(short, long, value_name = "TARGET")]
         target: Option<PathBuf>,
 
@@ -274,7 +536,7 @@ pub enum PlanType {
         #[arg(value_name = "SOURCE")]
         source: PathBuf,
 
-        /// Target directory for organized TV shows
+        /// Target directory for organized TV shows, or "auto" to pick an indexed disk per title
         #[arg(short, long, value_name = "TARGET")]
         target: Option<PathBuf>,
 
@@ -282,6 +544,61 @@ pub enum PlanType {
         #[arg(short, long, value_name = "OUTPUT")]
         output: Option<PathBuf>,
     },
+
+    /// Generate an HTML report for a plan (and optionally its execution)
+    Report {
+        /// Path to the plan.json file
+        #[arg(value_name = "PLAN_FILE")]
+        plan_file: PathBuf,
+
+        /// Output path for the HTML report
+        #[arg(long, value_name = "HTML")]
+        html: PathBuf,
+
+        /// Rollback file from executing the plan, to show succeeded/failed items
+        #[arg(short, long, value_name = "ROLLBACK_FILE")]
+        rollback: Option<PathBuf>,
+    },
+
+    /// Export plan items and unknown files for spreadsheet triage
+    Export {
+        /// Path to the plan.json file
+        #[arg(value_name = "PLAN_FILE")]
+        plan_file: PathBuf,
+
+        /// Export format: csv
+        #[arg(short, long, default_value = "csv")]
+        format: String,
+
+        /// Output file (default: plan file with .csv extension)
+        #[arg(short, long, value_name = "OUTPUT")]
+        output: Option<PathBuf>,
+    },
+
+    /// Apply edited statuses and TMDB IDs from a CSV back into a plan
+    ImportCsv {
+        /// Path to the plan.json file
+        #[arg(value_name = "PLAN_FILE")]
+        plan_file: PathBuf,
+
+        /// Path to the edited CSV file
+        #[arg(value_name = "CSV_FILE")]
+        csv_file: PathBuf,
+
+        /// Output path for the updated plan (default: overwrite PLAN_FILE)
+        #[arg(short, long, value_name = "OUTPUT")]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum RollbackAction {
+    /// Audit whether the targets of an execution are still intact
+    Verify {
+        /// Path to the rollback.json file
+        #[arg(value_name = "ROLLBACK_FILE")]
+        rollback_file: PathBuf,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -295,4 +612,47 @@ pub enum SessionsAction {
         #[arg(value_name = "SESSION_ID")]
         session_id: String,
     },
+
+    /// Upgrade session files to the current schema version (keeps backups)
+    Migrate,
+
+    /// Roll back the most recent executed session
+    UndoLast {
+        /// Dry run - show what would be done
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Delete old sessions
+    Prune {
+        /// Delete sessions older than this age (e.g. 90d, 2w, 12h)
+        #[arg(long, value_name = "AGE")]
+        older_than: String,
+
+        /// Keep sessions that can still be rolled back
+        #[arg(long)]
+        keep_executed: bool,
+
+        /// Only show which sessions would be deleted
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Delete a session
+    Delete {
+        /// Session ID
+        #[arg(value_name = "SESSION_ID")]
+        session_id: String,
+    },
+
+    /// Compare the plans of two sessions item by item
+    Diff {
+        /// First session ID
+        #[arg(value_name = "SESSION_A")]
+        session_a: String,
+
+        /// Second session ID
+        #[arg(value_name = "SESSION_B")]
+        session_b: String,
+    },
 }
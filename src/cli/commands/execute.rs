@@ -3,15 +3,39 @@
 //! Reads a plan.json file and executes all operations,
 //! generating a rollback.json for recovery.
 
-use crate::core::executor::{self, Executor};
+use crate::core::executor::{self, Executor, ExecutorConfig};
+use crate::core::hooks::{self, HookSummary};
 use crate::core::planner;
+use crate::core::sessions;
+use crate::models::config;
+use crate::models::rollback::Rollback;
+use crate::services::media_server;
 use crate::Result;
 use chrono::Utc;
 use colored::Colorize;
 use std::path::{Path, PathBuf};
 
+/// Whether to refresh media server libraries after execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LibraryRefresh {
+    /// Do not contact media servers.
+    Off,
+    /// Print the refresh requests without sending them.
+    DryRun,
+    /// Send the refresh requests.
+    Send,
+}
+
 /// Execute a plan file.
-pub async fn execute_plan(plan_file: &Path, output: Option<&Path>) -> Result<()> {
+///
+/// With `checksum`, file hashes are recorded for every move so that a later
+/// rollback can verify files before moving them back.
+pub async fn execute_plan(
+    plan_file: &Path,
+    output: Option<&Path>,
+    checksum: bool,
+    refresh: LibraryRefresh,
+) -> Result<()> {
     println!("{}", "[EXEC] Executing plan...".bold().cyan());
     println!();
 
@@ -45,8 +69,12 @@ pub async fn execute_plan(plan_file: &Path, output: Option<&Path>) -> Result<()>
     println!();
 
     // Execute plan
-    let executor = Executor::new();
-    let rollback = executor.execute(&plan).await?;
+    let executor = Executor::with_config(ExecutorConfig {
+        checksum_renames: checksum,
+        ..Default::default()
+    });
+    let report = executor.execute_with_report(&plan).await?;
+    let rollback = &report.rollback;
 
     // Determine rollback output path
     let rollback_path = match output {
@@ -62,13 +90,50 @@ pub async fn execute_plan(plan_file: &Path, output: Option<&Path>) -> Result<()>
     };
 
     // Save rollback
-    executor::save_rollback(&rollback, &rollback_path)?;
+    executor::save_rollback(rollback, &rollback_path)?;
     println!(
         "{} {}",
         "[OK] Rollback saved to:".bold().green(),
         rollback_path.display()
     );
 
+    // Keep a copy in the plan's session so `sessions undo-last` can find it,
+    // and record the outcome in the session manifest
+    let session =
+        planner::sessions_dir().and_then(|dir| sessions::find_session_for_plan(&dir, &plan));
+    match session {
+        Ok(Some(session)) => {
+            if let Err(e) = executor::save_rollback(rollback, &session.rollback_path()) {
+                tracing::warn!("Failed to save rollback to session: {}", e);
+            }
+            let rollback_path = rollback_path
+                .canonicalize()
+                .unwrap_or(rollback_path.clone());
+            if let Err(e) = sessions::record_execution(
+                &session,
+                rollback,
+                &rollback_path,
+                report.success_count,
+                report.failed_operations.clone(),
+            ) {
+                tracing::warn!("Failed to update session manifest: {}", e);
+            }
+        }
+        Ok(None) => tracing::debug!("No session found for plan {}", plan_file.display()),
+        Err(e) => tracing::warn!("Failed to look up session: {}", e),
+    }
+
+    hooks::fire(&HookSummary::for_execution(
+        &plan,
+        rollback,
+        Some(plan_file),
+    ))
+    .await;
+
+    if refresh != LibraryRefresh::Off {
+        refresh_libraries(rollback, refresh == LibraryRefresh::DryRun).await;
+    }
+
     // Print next steps with complete commands
     println!();
     println!("{}", "[Next Steps]".bold().cyan());
@@ -82,3 +147,61 @@ pub async fn execute_plan(plan_file: &Path, output: Option<&Path>) -> Result<()>
 
     Ok(())
 }
+
+/// Ask the configured media servers to rescan the folders that received files.
+///
+/// Failures are reported but do not fail the execution.
+async fn refresh_libraries(rollback: &Rollback, dry_run: bool) {
+    println!();
+    println!("{}", "[REFRESH] Media server libraries".bold().cyan());
+
+    let servers = config::load_config().media_servers;
+    if servers.is_empty() {
+        println!(
+            "  {} No media servers configured in config.toml",
+            "[WARNING]".yellow()
+        );
+        return;
+    }
+
+    let folders = media_server::affected_folders(rollback);
+    if folders.is_empty() {
+        println!("  No folders changed.");
+        return;
+    }
+
+    for server in &servers {
+        println!("  {} {} ({})", "Server:".bold(), server.kind, server.url);
+        let requests = match media_server::build_requests(server, &folders) {
+            Ok(requests) => requests,
+            Err(e) => {
+                println!("  {} {}", "[FAILED]".red(), e);
+                continue;
+            }
+        };
+
+        if dry_run {
+            for request in &requests {
+                println!("    {} {}", request.method, request.url);
+                if let Some(ref body) = request.body {
+                    println!("      {}", body);
+                }
+            }
+            continue;
+        }
+
+        match media_server::send_requests(server, &requests).await {
+            Ok(()) => println!(
+                "  {} Refresh requested for {} folders",
+                "[OK]".green(),
+                folders.len()
+            ),
+            Err(e) => println!("  {} {}", "[FAILED]".red(), e),
+        }
+    }
+
+    if dry_run {
+        println!();
+        println!("{}", "[DRY-RUN] No requests were sent".yellow());
+    }
+}
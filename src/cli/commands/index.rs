@@ -1,11 +1,20 @@
 //! Index command implementation.
 
 use crate::cli::args::IndexAction;
-use crate::core::indexer;
+use crate::core::executor::{self, Executor, ExecutorConfig};
+use crate::core::index_store::IndexChanges;
+use crate::core::quality::{self, Quality, Recommendation};
+use crate::core::query::Query;
+use crate::core::title_match::TitleQuery;
+use crate::core::{dedup, gaps, indexer, planner, transfer};
+use crate::models::config::IndexBackend;
+use crate::models::index::{DiskInfo, EpisodeFile, MovieEntry, TvShowEntry, UserDataUpdate};
 use anyhow::Result;
+use chrono::Utc;
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// Execute index subcommand.
 pub async fn execute_index(action: IndexAction) -> Result<()> {
@@ -15,8 +24,10 @@ pub async fn execute_index(action: IndexAction) -> Result<()> {
             media_type,
             disk_label,
             force,
-        } => scan_directory(&path, &media_type, disk_label, force).await,
+            tmdb_lookup,
+        } => scan_directory(&path, &media_type, disk_label, force, tmdb_lookup).await,
         IndexAction::Stats => show_stats().await,
+        IndexAction::Disks { format } => show_disks(&format).await,
         IndexAction::List {
             disk_label,
             media_type,
@@ -29,17 +40,78 @@ pub async fn execute_index(action: IndexAction) -> Result<()> {
         IndexAction::Duplicates { media_type, format } => {
             find_duplicates(&media_type, &format).await
         }
+        IndexAction::Dedup {
+            paths,
+            action,
+            quarantine,
+            output,
+            format,
+        } => find_content_duplicates(paths, &action, quarantine, output, &format).await,
         IndexAction::Collections {
             filter,
             format,
             paths,
             update,
+            missing,
         } => {
             if update {
                 update_collections().await?;
             }
-            list_collections(&filter, &format, paths).await
+            list_collections(&filter, &format, paths, missing).await
+        }
+        IndexAction::Episodes {
+            title,
+            season,
+            episode,
+            format,
+        } => show_episodes(&title, season, episode, &format).await,
+        IndexAction::MissingEpisodes {
+            show,
+            include_specials,
+            format,
+        } => missing_episodes(show.as_deref(), include_specials, &format).await,
+        IndexAction::Upgrades {
+            below,
+            max_resolution,
+            limit,
+            format,
+        } => show_upgrades(below, max_resolution.as_deref(), limit, &format).await,
+        IndexAction::Mark {
+            query,
+            watched,
+            unwatched,
+            date,
+            favorite,
+            unfavorite,
+            rating,
+            tag,
+            untag,
+            notes,
+            all,
+        } => {
+            let flag = |on: bool, off: bool| (on || off).then_some(on);
+            let update = UserDataUpdate {
+                watched: flag(watched, unwatched),
+                watched_at: watched.then(|| {
+                    date.unwrap_or_else(|| chrono::Local::now().format("%Y-%m-%d").to_string())
+                }),
+                favorite: flag(favorite, unfavorite),
+                rating,
+                add_tags: tag,
+                remove_tags: untag,
+                notes,
+            };
+            mark_titles(&query, &update, all).await
         }
+        IndexAction::Migrate {
+            from,
+            to,
+            filter,
+            media_type,
+            output,
+            execute,
+        } => migrate_titles(&from, &to, filter.as_deref(), &media_type, output, execute).await,
+        IndexAction::Convert { to } => convert_index(&to).await,
     }
 }
 
@@ -49,6 +121,7 @@ async fn scan_directory(
     media_type: &str,
     disk_label: Option<String>,
     force: bool,
+    tmdb_lookup: bool,
 ) -> Result<()> {
     println!("{}", "[INDEX] Scanning directory...".bold().cyan());
     println!("  Path: {}", path.display());
@@ -66,19 +139,19 @@ async fn scan_directory(
         println!("  Disk UUID: {}", u);
     }
 
-    // Check if already indexed
-    if !force {
-        if let Ok(Some(existing)) = indexer::load_disk_index(&label) {
-            println!(
-                "{}",
-                format!(
-                    "[WARN] Disk '{}' already indexed ({} movies, {} TV shows)",
-                    label, existing.disk.movie_count, existing.disk.tvshow_count
-                )
-                .yellow()
-            );
-            println!("  Use --force to re-index");
-        }
+    // Entries of the previous scan of this path, unless re-indexing everything
+    let previous = if force {
+        None
+    } else {
+        let central = indexer::load_central_index()?;
+        indexer::previous_scan(&central, &label, media_type, path)
+    };
+    match &previous {
+        Some(previous) => println!(
+            "  Incremental scan against {} indexed entries (--force to re-parse all)",
+            previous.movies.len() + previous.tvshows.len()
+        ),
+        None => println!("  Full scan"),
     }
 
     println!();
@@ -90,41 +163,226 @@ async fn scan_directory(
             .template("{spinner:.green} {msg}")
             .unwrap(),
     );
-    pb.set_message("Scanning for NFO files...");
+    pb.set_message("Scanning for NFO files and media folders...");
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
 
-    let disk_index = indexer::scan_directory(path, &label, uuid, media_type)?;
+    let (mut disk_index, changes) =
+        indexer::scan_directory_incremental(path, &label, uuid, media_type, previous.as_ref())?;
 
     pb.finish_with_message("Scan complete");
 
+    if let Some(mount) = indexer::mount_info(path) {
+        indexer::record_mount(&mut disk_index.disk, &mount);
+    }
+
+    // Entries indexed from folder names carry little more than a title
+    if tmdb_lookup {
+        use crate::services::tmdb::{TmdbClient, TmdbConfig};
+
+        let client = TmdbClient::new(TmdbConfig::from_env()?);
+        println!(
+            "{}",
+            "[INDEX] Looking up missing metadata on TMDB..."
+                .bold()
+                .cyan()
+        );
+        let summary = indexer::lookup_tmdb_metadata(&mut disk_index, &client).await;
+        println!(
+            "  Matched: {}, not found: {}, failed: {}",
+            summary.matched.to_string().green(),
+            summary.not_found.to_string().yellow(),
+            summary.failed.to_string().red()
+        );
+    }
+
+    // An empty scan replaces neither the disk index nor the central entries
+    // (e.g. unmounted disk)
+    if disk_index.movies.is_empty() && disk_index.tvshows.is_empty() && changes.removed > 0 {
+        println!(
+            "{}",
+            format!(
+                "[WARN] Nothing found; the {} entries indexed before are kept. Use 'index remove' to drop them.",
+                changes.removed
+            )
+            .yellow()
+        );
+        return Ok(());
+    }
+
     // Save disk index
     indexer::save_disk_index(&disk_index)?;
 
     // Update central index
-    let mut central = indexer::load_central_index()?;
-    indexer::merge_disk_into_central(&mut central, disk_index.clone());
-    indexer::save_central_index(&central)?;
+    let totals = indexer::store_disk_in_central(disk_index.clone())?;
 
     // Print summary
     println!();
     println!("{}", "[INDEX] Complete!".bold().green());
     println!("  Movies indexed: {}", disk_index.disk.movie_count);
     println!("  TV shows indexed: {}", disk_index.disk.tvshow_count);
+    println!(
+        "  Added: {}, updated: {}, moved: {}, removed: {}, unchanged: {}",
+        changes.added.to_string().green(),
+        changes.updated.to_string().cyan(),
+        changes.moved.to_string().cyan(),
+        changes.removed.to_string().red(),
+        changes.unchanged
+    );
     println!(
         "  Total size: {:.2} GB",
         disk_index.disk.total_size_bytes as f64 / 1_073_741_824.0
     );
+    if let (Some(free), Some(capacity)) =
+        (disk_index.disk.free_bytes, disk_index.disk.capacity_bytes)
+    {
+        println!(
+            "  Free space: {} of {}",
+            format_size(free),
+            format_size(capacity)
+        );
+    }
     println!();
     println!(
         "  Central index: {} movies, {} TV shows across {} disks",
-        central.statistics.total_movies,
-        central.statistics.total_tvshows,
-        central.statistics.total_disks
+        totals.movies, totals.tvshows, totals.disks
     );
 
     Ok(())
 }
 
+/// A disk of `index disks`, with whether it is mounted now.
+#[derive(serde::Serialize)]
+struct DiskStatus {
+    #[serde(flatten)]
+    disk: DiskInfo,
+    online: bool,
+}
+
+/// Show capacity and free space of the indexed disks.
+///
+/// Mounted disks are measured now and the figures saved; offline disks show
+/// what was recorded when they were last seen.
+async fn show_disks(format: &str) -> Result<()> {
+    let index = indexer::load_central_index()?;
+
+    let mut disks: Vec<DiskStatus> = Vec::new();
+    let mut seen: Vec<DiskInfo> = Vec::new();
+    for disk in index.disks.values() {
+        let mut disk = disk.clone();
+        let mount = indexer::locate_disk(&disk.label, disk.uuid.as_deref());
+        if let Some(info) = mount.as_deref().and_then(indexer::mount_info) {
+            indexer::record_mount(&mut disk, &info);
+            seen.push(disk.clone());
+        }
+        disks.push(DiskStatus {
+            disk,
+            online: mount.is_some(),
+        });
+    }
+    if !seen.is_empty() {
+        indexer::update_disks_in_central(&seen)?;
+    }
+
+    // Most free space first
+    disks.sort_by(|a, b| {
+        b.disk
+            .free_bytes
+            .cmp(&a.disk.free_bytes)
+            .then_with(|| a.disk.label.cmp(&b.disk.label))
+    });
+
+    let size_or_dash =
+        |bytes: Option<u64>| bytes.map(format_size).unwrap_or_else(|| "-".to_string());
+    let last_seen = |d: &DiskInfo| {
+        d.last_seen
+            .as_deref()
+            .and_then(|s| s.get(..10))
+            .unwrap_or("never")
+            .to_string()
+    };
+
+    match format {
+        "json" => {
+            println!("{}", serde_json::to_string_pretty(&disks)?);
+        }
+        "simple" => {
+            for d in &disks {
+                println!(
+                    "{}\t{}\t{}\t{}\t{}",
+                    d.disk.label,
+                    if d.online { "online" } else { "offline" },
+                    d.disk.free_bytes.map(|b| b.to_string()).unwrap_or_default(),
+                    d.disk
+                        .capacity_bytes
+                        .map(|b| b.to_string())
+                        .unwrap_or_default(),
+                    d.disk.mount_point.as_deref().unwrap_or_default()
+                );
+            }
+        }
+        _ => {
+            if disks.is_empty() {
+                println!("No disks indexed.");
+                return Ok(());
+            }
+            println!(
+                "{:<16} | {:<7} | {:<6} | {:>10} | {:>10} | {:>5} | {:>10} | {:<10}",
+                "Disk", "Status", "FS", "Capacity", "Free", "Free%", "Media", "Last seen"
+            );
+            println!("{}", "-".repeat(100));
+            for d in &disks {
+                let status = if d.online {
+                    format!("{:<7}", "Online").green()
+                } else {
+                    format!("{:<7}", "Offline").red()
+                };
+                let free_percent = d
+                    .disk
+                    .free_bytes
+                    .zip(d.disk.capacity_bytes)
+                    .filter(|(_, capacity)| *capacity > 0)
+                    .map(|(free, capacity)| {
+                        format!("{:.0}%", free as f64 * 100.0 / capacity as f64)
+                    })
+                    .unwrap_or_else(|| "-".to_string());
+                println!(
+                    "{:<16} | {} | {:<6} | {:>10} | {:>10} | {:>5} | {:>10} | {:<10}",
+                    d.disk.label.bold(),
+                    status,
+                    d.disk.filesystem.as_deref().unwrap_or("-"),
+                    size_or_dash(d.disk.capacity_bytes),
+                    size_or_dash(d.disk.free_bytes),
+                    free_percent,
+                    format_size(d.disk.total_size_bytes),
+                    last_seen(&d.disk)
+                );
+                if let Some(mount_point) = d.disk.mount_point.as_deref().filter(|_| d.online) {
+                    println!("      {}", mount_point.dimmed());
+                }
+            }
+            println!("{}", "=".repeat(60));
+            let online_free: u64 = disks
+                .iter()
+                .filter(|d| d.online)
+                .filter_map(|d| d.disk.free_bytes)
+                .sum();
+            println!(
+                "Disks: {} ({} online), free on online disks: {}",
+                disks.len(),
+                disks
+                    .iter()
+                    .filter(|d| d.online)
+                    .count()
+                    .to_string()
+                    .green(),
+                format_size(online_free).bold()
+            );
+        }
+    }
+
+    Ok(())
+}
+
 /// Show collection statistics.
 async fn show_stats() -> Result<()> {
     let mut index = indexer::load_central_index()?;
@@ -138,7 +396,7 @@ async fn show_stats() -> Result<()> {
     // Disks
     println!("{}", "Disks:".bold());
     for (label, disk) in &index.disks {
-        let status = if indexer::is_disk_online(label) {
+        let status = if indexer::is_disk_online(label, disk.uuid.as_deref()) {
             "Online".green()
         } else {
             "Offline".red()
@@ -358,7 +616,7 @@ async fn remove_disk(disk_label: &str, confirm: bool) -> Result<()> {
 
     index.rebuild_indexes();
     index.update_statistics();
-    indexer::save_central_index(&index)?;
+    indexer::remove_disk_from_central(&index, disk_label)?;
 
     // Remove disk index file
     let disk_index_path = indexer::disk_indexes_dir()?.join(format!("{}.json", disk_label));
@@ -387,6 +645,12 @@ struct DuplicateEntry {
     size_bytes: u64,
     size_human: String,
     online: bool,
+    /// Quality score of a movie copy (0-100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quality_score: Option<u32>,
+    /// Which movie copies to keep or delete
+    #[serde(skip_serializing_if = "Option::is_none")]
+    recommendation: Option<Recommendation>,
 }
 
 /// Data structure for duplicate group.
@@ -401,6 +665,27 @@ struct DuplicateGroup {
     total_size_human: String,
 }
 
+/// Episodes of a TV show, grouped across disks.
+#[derive(Debug, Clone, serde::Serialize)]
+struct ShowEpisodes {
+    title: String,
+    year: Option<u16>,
+    tmdb_id: Option<u64>,
+    seasons: Vec<indexer::SeasonSummary>,
+    /// Episode files of the requested season
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    files: Vec<EpisodeLocation>,
+}
+
+/// An episode file and the disk holding it.
+#[derive(Debug, Clone, serde::Serialize)]
+struct EpisodeLocation {
+    disk: String,
+    online: bool,
+    #[serde(flatten)]
+    file: EpisodeFile,
+}
+
 /// Format bytes to human-readable string.
 fn format_size(bytes: u64) -> String {
     const KB: u64 = 1024;
@@ -441,6 +726,7 @@ async fn find_duplicates(media_type: &str, format: &str) -> Result<()> {
         for (tmdb_id, movies) in tmdb_to_movies {
             if movies.len() > 1 {
                 let total_size: u64 = movies.iter().map(|m| m.size_bytes).sum();
+                let recommendations = quality::recommend_copies(&movies);
                 duplicates.push(DuplicateGroup {
                     tmdb_id,
                     title: movies[0].title.clone(),
@@ -448,12 +734,15 @@ async fn find_duplicates(media_type: &str, format: &str) -> Result<()> {
                     media_type: "movie".to_string(),
                     entries: movies
                         .iter()
-                        .map(|m| DuplicateEntry {
+                        .zip(recommendations)
+                        .map(|(m, recommendation)| DuplicateEntry {
                             disk: m.disk.clone(),
                             path: m.relative_path.clone(),
                             size_bytes: m.size_bytes,
                             size_human: format_size(m.size_bytes),
-                            online: indexer::is_disk_online(&m.disk),
+                            online: indexer::is_disk_online(&m.disk, m.disk_uuid.as_deref()),
+                            quality_score: Some(Quality::of(m).score()),
+                            recommendation: Some(recommendation),
                         })
                         .collect(),
                     total_size_bytes: total_size,
@@ -489,7 +778,9 @@ async fn find_duplicates(media_type: &str, format: &str) -> Result<()> {
                             path: t.relative_path.clone(),
                             size_bytes: t.size_bytes,
                             size_human: format_size(t.size_bytes),
-                            online: indexer::is_disk_online(&t.disk),
+                            online: indexer::is_disk_online(&t.disk, t.disk_uuid.as_deref()),
+                            quality_score: None,
+                            recommendation: None,
                         })
                         .collect(),
                     total_size_bytes: total_size,
@@ -500,7 +791,7 @@ async fn find_duplicates(media_type: &str, format: &str) -> Result<()> {
     }
 
     // Sort by total size (largest first)
-    duplicates.sort_by(|a, b| b.total_size_bytes.cmp(&a.total_size_bytes));
+    duplicates.sort_by_key(|g| std::cmp::Reverse(g.total_size_bytes));
 
     // Output
     match format {
@@ -526,9 +817,15 @@ async fn find_duplicates(media_type: &str, format: &str) -> Result<()> {
                     );
                     for entry in &group.entries {
                         let status = if entry.online { "online" } else { "offline" };
+                        let advice = match (entry.recommendation, entry.quality_score) {
+                            (Some(r), Some(score)) => {
+                                format!(" {} (score {})", recommendation_label(r), score)
+                            }
+                            _ => String::new(),
+                        };
                         println!(
-                            "  - {} ({}) [{}]: {}",
-                            entry.disk, status, entry.size_human, entry.path
+                            "  - {} ({}) [{}]{}: {}",
+                            entry.disk, status, entry.size_human, advice, entry.path
                         );
                     }
                     println!();
@@ -573,11 +870,24 @@ async fn find_duplicates(media_type: &str, format: &str) -> Result<()> {
                         } else {
                             "Offline".red()
                         };
+                        let advice = match (entry.recommendation, entry.quality_score) {
+                            (Some(r), Some(score)) => {
+                                let label = format!("{:<6} {:>3}", recommendation_label(r), score);
+                                let label = match r {
+                                    Recommendation::Keep => label.green(),
+                                    Recommendation::Delete => label.red(),
+                                    Recommendation::Review => label.yellow(),
+                                };
+                                format!("{} | ", label)
+                            }
+                            _ => String::new(),
+                        };
                         println!(
-                            "  {:>12} | {:>10} | {} | {}",
+                            "  {:>12} | {:>10} | {} | {}{}",
                             entry.disk.bold(),
                             entry.size_human,
                             status,
+                            advice,
                             entry.path
                         );
                     }
@@ -603,13 +913,164 @@ async fn find_duplicates(media_type: &str, format: &str) -> Result<()> {
                     "Potential space savings: {}",
                     format_size(total_wasted).bold().green()
                 );
+
+                let deletable: Vec<&DuplicateEntry> = duplicates
+                    .iter()
+                    .flat_map(|g| &g.entries)
+                    .filter(|e| e.recommendation == Some(Recommendation::Delete))
+                    .collect();
+                if !deletable.is_empty() {
+                    println!(
+                        "Safe to delete: {} movie copies ({})",
+                        deletable.len().to_string().bold(),
+                        format_size(deletable.iter().map(|e| e.size_bytes).sum())
+                            .bold()
+                            .green()
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn recommendation_label(recommendation: Recommendation) -> &'static str {
+    match recommendation {
+        Recommendation::Keep => "keep",
+        Recommendation::Delete => "delete",
+        Recommendation::Review => "review",
+    }
+}
+
+/// Find files with identical content and report, hard-link or plan their removal.
+async fn find_content_duplicates(
+    paths: Vec<PathBuf>,
+    action: &str,
+    quarantine: Option<PathBuf>,
+    output: Option<PathBuf>,
+    format: &str,
+) -> Result<()> {
+    if !matches!(action, "report" | "hardlink" | "plan") {
+        anyhow::bail!(
+            "Invalid action: {} (expected report, hardlink or plan)",
+            action
+        );
+    }
+
+    let roots = if paths.is_empty() {
+        online_disk_paths()?
+    } else {
+        paths
+    };
+    if roots.is_empty() {
+        println!("{}", "No online disks with indexed paths found.".yellow());
+        return Ok(());
+    }
+
+    if format != "json" {
+        println!("{}", "[CHECK] Comparing file contents...".bold().cyan());
+        for root in &roots {
+            println!("  {}", root.display());
+        }
+    }
+    let files = dedup::collect_video_files(&roots);
+    let duplicates = dedup::find_content_duplicates(&files);
+    let wasted: u64 = duplicates.iter().map(|d| d.wasted_bytes()).sum();
+
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&duplicates)?);
+    } else {
+        println!();
+        for set in &duplicates {
+            println!(
+                "{} {} copies - {} each",
+                "[DUPLICATE]".yellow(),
+                set.files.len(),
+                format_size(set.size_bytes)
+            );
+            println!("  {} {}", "keep".green(), set.keep().display());
+            for extra in set.extras() {
+                println!("  {} {}", "extra".red(), extra.display());
+            }
+        }
+        println!("{}", "=".repeat(60));
+        println!(
+            "Files checked: {}, duplicate sets: {}",
+            files.len().to_string().bold(),
+            duplicates.len().to_string().bold()
+        );
+        println!(
+            "Potential space savings: {}",
+            format_size(wasted).bold().green()
+        );
+    }
+
+    match action {
+        "hardlink" => {
+            let mut freed = 0;
+            let mut skipped = 0;
+            for set in &duplicates {
+                let (bytes, not_linked) = dedup::hardlink_duplicates(set)?;
+                freed += bytes;
+                skipped += not_linked.len();
+            }
+            println!(
+                "{} Hard-linked duplicates, freed {}",
+                "[OK]".green().bold(),
+                format_size(freed)
+            );
+            if skipped > 0 {
+                println!(
+                    "{} {} copies on another filesystem were left as they are",
+                    "[SKIP]".yellow(),
+                    skipped
+                );
             }
         }
+        "plan" => {
+            let plan = dedup::quarantine_plan(&duplicates, &roots, quarantine.as_deref())?;
+            let output = output.unwrap_or_else(|| PathBuf::from("dedup_plan.json"));
+            planner::save_plan(&plan, &output)?;
+            println!(
+                "{} Plan to quarantine {} copies saved to {}",
+                "[OK]".green().bold(),
+                plan.items.len(),
+                output.display()
+            );
+            println!(
+                "  Review it, then run: media-organizer execute {}",
+                output.display()
+            );
+        }
+        _ => {}
     }
 
     Ok(())
 }
 
+/// Indexed paths of the disks that are online.
+fn online_disk_paths() -> Result<Vec<PathBuf>> {
+    let index = indexer::load_central_index()?;
+    let mut roots: Vec<PathBuf> = Vec::new();
+    for disk in index.disks.values() {
+        let Some(mount) = indexer::locate_disk(&disk.label, disk.uuid.as_deref()) else {
+            continue;
+        };
+        let paths = indexer::current_disk_paths(disk, &mount)
+            .into_iter()
+            .filter(|p| p.is_dir());
+        for path in paths {
+            if !roots.iter().any(|r| path.starts_with(r)) {
+                roots.retain(|r| !r.starts_with(&path));
+                roots.push(path);
+            }
+        }
+    }
+    roots.sort();
+    Ok(roots)
+}
+
 /// Update collection totals from TMDB API and write back to NFO files.
 async fn update_collections() -> Result<()> {
     use crate::services::tmdb::{TmdbClient, TmdbConfig};
@@ -743,19 +1204,29 @@ async fn update_collections() -> Result<()> {
     }
 
     // Also update the movie entries with collection_total_movies
+    let mut changes = IndexChanges::default();
     for movie in &mut index.movies {
         if let Some(collection_id) = movie.collection_id {
             if let Some(collection) = index.collections.get(&collection_id) {
-                if collection.total_in_collection > 0 {
+                if collection.total_in_collection > 0
+                    && movie.collection_total_movies != Some(collection.total_in_collection)
+                {
                     movie.collection_total_movies = Some(collection.total_in_collection);
+                    changes.movies.push(movie.clone());
                 }
             }
         }
     }
+    changes.collections = collections_to_update
+        .iter()
+        .filter_map(|id| index.collections.get(id))
+        .filter(|c| c.total_in_collection > 0)
+        .cloned()
+        .collect();
 
     // Save updated index
     index.update_statistics();
-    indexer::save_central_index(&index)?;
+    indexer::save_central_changes(&index, &changes)?;
 
     println!("{}", "[OK] Collection info updated".bold().green());
 
@@ -801,10 +1272,17 @@ struct CollectionOutput {
     total_in_collection: usize,
     status: String,
     movies: Vec<CollectionMovieOutput>,
+    /// Released movies not owned (with --missing)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    missing: Vec<gaps::MissingMovie>,
+    /// Movies not released yet (with --missing)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    unreleased: Vec<gaps::MissingMovie>,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
 struct CollectionMovieOutput {
+    tmdb_id: u64,
     title: String,
     year: Option<u16>,
     disk: String,
@@ -812,8 +1290,27 @@ struct CollectionMovieOutput {
     online: bool,
 }
 
+/// A movie of a collection as a CSV row.
+#[derive(Debug, Clone, serde::Serialize)]
+struct CollectionCsvRow<'a> {
+    collection_id: u64,
+    collection: &'a str,
+    tmdb_id: u64,
+    title: &'a str,
+    year: Option<u16>,
+    /// owned, missing or unreleased
+    status: &'a str,
+    release_date: Option<&'a str>,
+    disk: &'a str,
+}
+
 /// List movie collections.
-async fn list_collections(filter: &str, format: &str, show_paths: bool) -> Result<()> {
+async fn list_collections(
+    filter: &str,
+    format: &str,
+    show_paths: bool,
+    missing: bool,
+) -> Result<()> {
     let index = indexer::load_central_index()?;
 
     // Build collection output list
@@ -852,12 +1349,16 @@ async fn list_collections(filter: &str, format: &str, show_paths: bool) -> Resul
                     };
 
                     let online = if !disk.is_empty() {
-                        indexer::is_disk_online(&disk)
+                        indexer::is_disk_online(
+                            &disk,
+                            movie_entry.and_then(|m| m.disk_uuid.as_deref()),
+                        )
                     } else {
                         false
                     };
 
                     CollectionMovieOutput {
+                        tmdb_id: cm.tmdb_id,
                         title: cm.title.clone(),
                         year: cm.year,
                         disk,
@@ -874,10 +1375,18 @@ async fn list_collections(filter: &str, format: &str, show_paths: bool) -> Resul
                 total_in_collection: c.total_in_collection,
                 status,
                 movies,
+                missing: Vec::new(),
+                unreleased: Vec::new(),
             }
         })
         .collect();
 
+    let collections = if missing {
+        fetch_missing_movies(&index, collections, format).await?
+    } else {
+        collections
+    };
+
     // Apply filter
     let collections: Vec<_> = match filter {
         "complete" => collections
@@ -901,6 +1410,44 @@ async fn list_collections(filter: &str, format: &str, show_paths: bool) -> Resul
             let json = serde_json::to_string_pretty(&collections)?;
             println!("{}", json);
         }
+        "csv" => {
+            let mut wtr = csv::Writer::from_writer(std::io::stdout());
+            for c in &collections {
+                let row = |tmdb_id, title, year, status, release_date, disk| CollectionCsvRow {
+                    collection_id: c.id,
+                    collection: &c.name,
+                    tmdb_id,
+                    title,
+                    year,
+                    status,
+                    release_date,
+                    disk,
+                };
+                for movie in &c.movies {
+                    wtr.serialize(row(
+                        movie.tmdb_id,
+                        &movie.title,
+                        movie.year,
+                        "owned",
+                        None,
+                        &movie.disk,
+                    ))?;
+                }
+                for (status, movies) in [("missing", &c.missing), ("unreleased", &c.unreleased)] {
+                    for movie in movies {
+                        wtr.serialize(row(
+                            movie.tmdb_id,
+                            &movie.title,
+                            movie.year,
+                            status,
+                            movie.release_date.as_deref(),
+                            "",
+                        ))?;
+                    }
+                }
+            }
+            wtr.flush()?;
+        }
         "simple" => {
             if collections.is_empty() {
                 println!("No collections found.");
@@ -931,6 +1478,17 @@ async fn list_collections(filter: &str, format: &str, show_paths: bool) -> Resul
                             );
                         }
                     }
+                    for movie in &c.missing {
+                        let year_str = movie.year.map(|y| format!("({})", y)).unwrap_or_default();
+                        println!("  - {} {} [missing]", movie.title, year_str);
+                    }
+                    for movie in &c.unreleased {
+                        println!(
+                            "  - {} [unreleased {}]",
+                            movie.title,
+                            movie.release_date.as_deref().unwrap_or("TBA")
+                        );
+                    }
                     println!();
                 }
             }
@@ -1009,6 +1567,23 @@ async fn list_collections(filter: &str, format: &str, show_paths: bool) -> Resul
                             );
                         }
                     }
+                    for movie in &c.missing {
+                        let year_str = movie.year.map(|y| format!("({})", y)).unwrap_or_default();
+                        println!(
+                            "    {} {} | {}",
+                            movie.title.yellow(),
+                            year_str,
+                            "Missing".yellow()
+                        );
+                    }
+                    for movie in &c.unreleased {
+                        println!(
+                            "    {} | {} {}",
+                            movie.title.dimmed(),
+                            "Unreleased".dimmed(),
+                            movie.release_date.as_deref().unwrap_or("TBA").dimmed()
+                        );
+                    }
                     println!();
                 }
             }
@@ -1017,3 +1592,683 @@ async fn list_collections(filter: &str, format: &str, show_paths: bool) -> Resul
 
     Ok(())
 }
+
+/// Fetch collections from TMDB and fill in their missing movies.
+///
+/// Totals and status are recomputed from the released movies, so upcoming
+/// sequels do not make a collection incomplete.
+async fn fetch_missing_movies(
+    index: &crate::models::index::CentralIndex,
+    mut collections: Vec<CollectionOutput>,
+    format: &str,
+) -> Result<Vec<CollectionOutput>> {
+    use crate::services::tmdb::{TmdbClient, TmdbConfig};
+
+    let client = TmdbClient::new(TmdbConfig::from_env()?);
+    let today = gaps::today();
+
+    let pb = if format == "table" {
+        ProgressBar::new(collections.len() as u64)
+    } else {
+        ProgressBar::hidden()
+    };
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("{spinner:.green} [{bar:40.cyan/blue}] {pos}/{len} {msg}")
+            .unwrap()
+            .progress_chars("=> "),
+    );
+
+    for output in &mut collections {
+        pb.set_message(output.name.clone());
+        if let Some(collection) = index.collections.get(&output.id) {
+            match gaps::check_collection(collection, &client, &today).await {
+                Ok(gaps) => {
+                    output.total_in_collection = gaps.total;
+                    output.status = if gaps.is_complete() {
+                        "Complete".to_string()
+                    } else {
+                        "Incomplete".to_string()
+                    };
+                    output.missing = gaps.missing;
+                    output.unreleased = gaps.unreleased;
+                }
+                Err(e) => {
+                    tracing::warn!("[COLLECTION] Failed to fetch {}: {}", output.id, e);
+                }
+            }
+        }
+        pb.inc(1);
+
+        // Rate limiting: small delay between API calls
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    pb.finish_and_clear();
+
+    Ok(collections)
+}
+
+/// Update the user data of the titles matching a query.
+///
+/// Copies of a title on several disks are marked together. Matching more
+/// than one title needs `--all`, so a loose query does not mark half the
+/// library by accident.
+async fn mark_titles(query: &str, update: &UserDataUpdate, all: bool) -> Result<()> {
+    if update == &UserDataUpdate::default() {
+        anyhow::bail!("Nothing to change: use --watched, --unwatched, --favorite, --rating, --tag, --untag or --notes");
+    }
+    if let Some(rating) = update.rating.filter(|r| !(0.0..=10.0).contains(r)) {
+        anyhow::bail!("Invalid rating: {} (expected 0-10)", rating);
+    }
+    let query = Query::parse(query)?;
+
+    let mut index = indexer::load_central_index()?;
+
+    // Copies share their TMDB ID
+    let mut titles: Vec<(String, String)> = Vec::new();
+    let mut add_title = |key: String, label: String| {
+        if !titles.iter().any(|(k, _)| *k == key) {
+            titles.push((key, label));
+        }
+    };
+    let movie_key = |m: &MovieEntry| match m.tmdb_id {
+        Some(id) => format!("movie:{}", id),
+        None => m.id.clone(),
+    };
+    let tvshow_key = |t: &TvShowEntry| match t.tmdb_id {
+        Some(id) => format!("tvshow:{}", id),
+        None => t.id.clone(),
+    };
+    let year = |y: Option<u16>| y.map(|y| format!(" ({})", y)).unwrap_or_default();
+    for movie in index.movies.iter().filter(|m| query.matches_movie(m)) {
+        add_title(
+            movie_key(movie),
+            format!("{}{}", movie.title, year(movie.year)),
+        );
+    }
+    for tvshow in index.tvshows.iter().filter(|t| query.matches_tvshow(t)) {
+        add_title(
+            tvshow_key(tvshow),
+            format!("{}{} [TV]", tvshow.title, year(tvshow.year)),
+        );
+    }
+
+    if titles.is_empty() {
+        println!("{}", "No titles match the query.".yellow());
+        return Ok(());
+    }
+    if titles.len() > 1 && !all {
+        println!(
+            "{}",
+            format!("[WARN] {} titles match the query:", titles.len()).yellow()
+        );
+        for (_, label) in &titles {
+            println!("  {}", label);
+        }
+        println!("  Narrow the query, or use --all to mark them all");
+        return Ok(());
+    }
+
+    let keys: Vec<&str> = titles.iter().map(|(k, _)| k.as_str()).collect();
+    let mut changes = IndexChanges::default();
+    for movie in &mut index.movies {
+        if keys.contains(&movie_key(movie).as_str()) {
+            movie.user_data.apply(update);
+            changes.movies.push(movie.clone());
+        }
+    }
+    for tvshow in &mut index.tvshows {
+        if keys.contains(&tvshow_key(tvshow).as_str()) {
+            tvshow.user_data.apply(update);
+            changes.tvshows.push(tvshow.clone());
+        }
+    }
+    index.updated_at = Utc::now().to_rfc3339();
+    indexer::save_central_changes(&index, &changes)?;
+
+    println!(
+        "{}",
+        format!("[OK] Updated {} titles", titles.len())
+            .bold()
+            .green()
+    );
+    for (_, label) in &titles {
+        println!("  {}", label);
+    }
+
+    Ok(())
+}
+
+/// Move titles from one disk to another with an ordinary plan.
+///
+/// The plan is always saved. With `--execute` it is run right away and the
+/// titles whose files all moved are reassigned to the target disk.
+async fn migrate_titles(
+    from: &str,
+    to: &str,
+    filter: Option<&str>,
+    media_type: &str,
+    output: Option<PathBuf>,
+    execute: bool,
+) -> Result<()> {
+    let media_types: &[&str] = match media_type {
+        "movies" => &["movies"],
+        "tvshows" => &["tvshows"],
+        "all" => &["movies", "tvshows"],
+        other => anyhow::bail!(
+            "Invalid media type: {} (expected movies, tvshows or all)",
+            other
+        ),
+    };
+    if from == to {
+        anyhow::bail!("Source and target disk are the same: {}", from);
+    }
+    let filter = filter.map(Query::parse).transpose()?;
+
+    let mut index = indexer::load_central_index()?;
+    let online_disk = |label: &str| -> Result<(DiskInfo, PathBuf)> {
+        let mut disk = index.disks.get(label).cloned().ok_or_else(|| {
+            anyhow::anyhow!(
+                "Disk '{}' is not indexed, run `media-organizer index scan` on it first",
+                label
+            )
+        })?;
+        let mount = indexer::locate_disk(&disk.label, disk.uuid.as_deref())
+            .ok_or_else(|| anyhow::anyhow!("Disk '{}' is not mounted", label))?;
+        if let Some(info) = indexer::mount_info(&mount) {
+            indexer::record_mount(&mut disk, &info);
+        }
+        Ok((disk, mount))
+    };
+    let (from_disk, _) = online_disk(from)?;
+    let (mut to_disk, to_mount) = online_disk(to)?;
+
+    // Titles keep their folder layout below the media type's root
+    let mut source_roots: HashMap<String, PathBuf> = HashMap::new();
+    let mut target_roots: HashMap<String, PathBuf> = HashMap::new();
+    for &media_type in media_types {
+        let Some(source_root) = from_disk.paths.get(media_type).map(PathBuf::from) else {
+            continue;
+        };
+        let target_root = to_disk
+            .paths
+            .get(media_type)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| to_mount.join(source_root.file_name().unwrap_or_default()));
+        source_roots.insert(media_type.to_string(), source_root);
+        target_roots.insert(media_type.to_string(), target_root);
+    }
+
+    let entries =
+        transfer::select_entries(&index, from, &source_roots, &target_roots, filter.as_ref());
+    if entries.is_empty() {
+        println!("{}", format!("No titles on {} to migrate.", from).yellow());
+        return Ok(());
+    }
+
+    println!("{}", "[MIGRATE] Planning migration...".bold().cyan());
+    println!("  From: {}", from);
+    println!("  To:   {}", to);
+    let size: u64 = index
+        .movies
+        .iter()
+        .filter(|m| entries.iter().any(|e| e.id == m.id))
+        .map(|m| m.size_bytes)
+        .chain(
+            index
+                .tvshows
+                .iter()
+                .filter(|t| entries.iter().any(|e| e.id == t.id))
+                .map(|t| t.size_bytes),
+        )
+        .sum();
+    println!("  Titles: {} ({})", entries.len(), format_size(size));
+    if let Some(free) = to_disk.free_bytes.filter(|free| *free < size) {
+        println!(
+            "{} {} has only {} free",
+            "[WARN]".yellow(),
+            to,
+            format_size(free)
+        );
+    }
+
+    let plan = transfer::migration_plan(&entries, from, to)?;
+    let output =
+        output.unwrap_or_else(|| PathBuf::from(format!("migrate_{}_to_{}.json", from, to)));
+    planner::save_plan(&plan, &output)?;
+    println!(
+        "{} Plan to move {} titles saved to {}",
+        "[OK]".green().bold(),
+        plan.items.len(),
+        output.display()
+    );
+
+    if !execute {
+        println!();
+        println!("{}", "[Next Steps]".bold().cyan());
+        println!("  Review the plan, then run it and update the index with:");
+        println!(
+            "    media-organizer index migrate --from {} --to {} --execute",
+            from, to
+        );
+        println!("  Or execute the plan yourself and rescan both disks afterwards.");
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", "[MIGRATE] Moving files...".bold().cyan());
+    let executor = Executor::with_config(ExecutorConfig::default());
+    let report = executor.execute_with_report(&plan).await?;
+    let filename = format!("rollback_{}.json", Utc::now().format("%Y%m%d_%H%M%S"));
+    let rollback_path = output
+        .parent()
+        .map(|p| p.join(&filename))
+        .unwrap_or_else(|| PathBuf::from(&filename));
+    executor::save_rollback(&report.rollback, &rollback_path)?;
+    println!(
+        "{} {}",
+        "[OK] Rollback saved to:".bold().green(),
+        rollback_path.display()
+    );
+
+    // Only titles with every operation done move to the target disk
+    let planned: Vec<&Path> = plan
+        .items
+        .iter()
+        .map(|i| i.target.folder.as_ref())
+        .collect();
+    let migrated: Vec<&transfer::MigrationEntry> = entries
+        .iter()
+        .filter(|e| planned.contains(&e.target_dir.as_path()))
+        .filter(|e| {
+            !report
+                .failed_operations
+                .iter()
+                .any(|f| f.path.starts_with(&e.target_dir))
+        })
+        .collect();
+    for entry in &migrated {
+        if let Some(root) = target_roots.get(&entry.media_type) {
+            to_disk
+                .paths
+                .entry(entry.media_type.clone())
+                .or_insert_with(|| root.to_string_lossy().to_string());
+        }
+    }
+    if let Some(info) = indexer::mount_info(&to_mount) {
+        indexer::record_mount(&mut to_disk, &info);
+    }
+    let changes = transfer::apply_migration(&mut index, &migrated, from, &to_disk);
+    indexer::save_central_changes(&index, &changes)?;
+
+    println!("{}", "=".repeat(60));
+    println!(
+        "Migrated: {}, failed: {}",
+        migrated.len().to_string().bold().green(),
+        (plan.items.len() - migrated.len()).to_string().bold()
+    );
+    for failed in &report.failed_operations {
+        println!(
+            "  {} {}: {}",
+            "[FAILED]".red(),
+            failed.path.display(),
+            failed.error
+        );
+    }
+    println!();
+    println!("{}", "[Next Steps]".bold().cyan());
+    println!("  To move the files back, run:");
+    println!(
+        "    {}",
+        format!("media-organizer rollback {}", rollback_path.display()).bold()
+    );
+    println!("  and rescan both disks afterwards.");
+
+    Ok(())
+}
+
+/// Copy the central index to another storage backend.
+async fn convert_index(to: &str) -> Result<()> {
+    let backend = match to {
+        "sqlite" => IndexBackend::Sqlite,
+        "json" => IndexBackend::Json,
+        other => anyhow::bail!("Unknown index backend '{}': use sqlite or json", other),
+    };
+
+    let totals = indexer::convert_central_index(backend)?;
+    let path = match backend {
+        IndexBackend::Sqlite => indexer::central_db_path()?,
+        IndexBackend::Json => indexer::central_index_path()?,
+    };
+
+    println!(
+        "{}",
+        format!(
+            "[OK] Converted central index to {}: {} movies, {} TV shows across {} disks",
+            backend, totals.movies, totals.tvshows, totals.disks
+        )
+        .bold()
+        .green()
+    );
+    println!("  {} {}", "File:".bold(), path.display());
+
+    if indexer::index_backend() != backend {
+        println!();
+        println!("{}", "[Next Steps]".bold().cyan());
+        println!("  Use the converted index by adding to config.toml:");
+        println!("    [index]");
+        println!("    backend = \"{}\"", backend);
+    }
+
+    Ok(())
+}
+
+/// Show the episodes of TV shows matching a title.
+async fn show_episodes(
+    title: &str,
+    season: Option<u16>,
+    episode: Option<u16>,
+    format: &str,
+) -> Result<()> {
+    let index = indexer::load_central_index()?;
+    let query = TitleQuery::new(title);
+
+    let groups = indexer::group_tvshows(index.tvshows.iter().filter(|show| {
+        query
+            .score_entry(&show.title, show.original_title.as_deref())
+            .is_some()
+    }));
+
+    let shows: Vec<ShowEpisodes> = groups
+        .iter()
+        .map(|group| {
+            let mut files: Vec<EpisodeLocation> = group
+                .iter()
+                .flat_map(|show| {
+                    show.episode_files
+                        .iter()
+                        .filter(|f| Some(f.season) == season)
+                        .filter(|f| episode.is_none_or(|e| f.episode == e))
+                        .map(|f| EpisodeLocation {
+                            disk: show.disk.clone(),
+                            online: indexer::is_disk_online(&show.disk, show.disk_uuid.as_deref()),
+                            file: f.clone(),
+                        })
+                })
+                .collect();
+            files.sort_by_key(|f| f.file.episode);
+            ShowEpisodes {
+                title: group[0].title.clone(),
+                year: group[0].year,
+                tmdb_id: group[0].tmdb_id,
+                seasons: indexer::summarize_seasons(group)
+                    .into_iter()
+                    .filter(|s| season.is_none_or(|n| s.season == n))
+                    .collect(),
+                files,
+            }
+        })
+        .collect();
+
+    match format {
+        "json" => println!("{}", serde_json::to_string_pretty(&shows)?),
+        "simple" => {
+            for show in &shows {
+                for summary in &show.seasons {
+                    println!(
+                        "{}\tS{:02}\t{}\t{}\t{}",
+                        show.title,
+                        summary.season,
+                        summary.episodes.len(),
+                        format_episode_list(&summary.missing),
+                        summary.disks.join(",")
+                    );
+                }
+                for f in &show.files {
+                    println!(
+                        "{}\tS{:02}E{:02}\t{}\t{}",
+                        show.title, f.file.season, f.file.episode, f.disk, f.file.relative_path
+                    );
+                }
+            }
+        }
+        _ => {
+            if shows.is_empty() {
+                println!("{}", "No matching TV shows found.".yellow());
+                return Ok(());
+            }
+            for show in &shows {
+                let year_str = show.year.map(|y| format!("({})", y)).unwrap_or_default();
+                let tmdb_str = show
+                    .tmdb_id
+                    .map(|id| format!(" - tmdb{}", id))
+                    .unwrap_or_default();
+                println!(
+                    "{} {} {}{}",
+                    "[TVSHOW]".magenta(),
+                    show.title.bold(),
+                    year_str,
+                    tmdb_str
+                );
+                if show.seasons.is_empty() {
+                    println!("  No episode files indexed (re-run 'index scan' to record them)");
+                }
+                for summary in &show.seasons {
+                    let status = if summary.is_complete() {
+                        "complete".green()
+                    } else {
+                        format!("missing {}", format_episode_list(&summary.missing)).yellow()
+                    };
+                    println!(
+                        "  S{:02} | {:>3} episodes | {:>10} | {} | {}",
+                        summary.season,
+                        summary.episodes.len(),
+                        format_size(summary.size_bytes),
+                        summary.disks.join(", ").bold(),
+                        status
+                    );
+                }
+                for f in &show.files {
+                    let status = if f.online {
+                        "Online".green()
+                    } else {
+                        "Offline".red()
+                    };
+                    println!(
+                        "    S{:02}E{:02} | {:>6} | {:>12} | {} | {}",
+                        f.file.season,
+                        f.file.episode,
+                        f.file.resolution.as_deref().unwrap_or("-"),
+                        f.disk.bold(),
+                        status,
+                        f.file.relative_path
+                    );
+                }
+                println!();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// List aired episodes missing from disk, per TV show and season.
+async fn missing_episodes(show: Option<&str>, include_specials: bool, format: &str) -> Result<()> {
+    use crate::services::tmdb::{TmdbClient, TmdbConfig};
+
+    let index = indexer::load_central_index()?;
+    let client = TmdbClient::new(TmdbConfig::from_env()?);
+
+    if format != "json" {
+        println!(
+            "{}",
+            "[CHECK] Comparing episodes with TMDB...".bold().cyan()
+        );
+    }
+    let report = gaps::find_missing_episodes(&index, &client, show, include_specials).await?;
+
+    if format == "json" {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!();
+    for show in &report.shows {
+        let year_str = show.year.map(|y| format!("({})", y)).unwrap_or_default();
+        println!(
+            "{} {} {} - tmdb{} - {}",
+            "[TVSHOW]".magenta(),
+            show.title.bold(),
+            year_str,
+            show.tmdb_id,
+            show.disks.join(", ")
+        );
+        for season in &show.seasons {
+            let missing: Vec<u16> = season.missing.iter().map(|e| e.episode).collect();
+            let status = if missing.is_empty() {
+                "complete".green()
+            } else {
+                format!("missing {}", format_episode_list(&missing)).yellow()
+            };
+            let unaired = if season.unaired.is_empty() {
+                String::new()
+            } else {
+                format!(" ({} unaired)", season.unaired.len())
+            };
+            println!(
+                "  S{:02} | {:>3}/{:<3} | {}{}",
+                season.season, season.owned, season.total, status, unaired
+            );
+            for episode in &season.missing {
+                println!(
+                    "    S{:02}E{:02} | {} | {}",
+                    episode.season,
+                    episode.episode,
+                    episode.air_date.as_deref().unwrap_or("-"),
+                    episode.title
+                );
+            }
+        }
+        println!();
+    }
+
+    for skipped in &report.skipped {
+        println!(
+            "{} {}: {}",
+            "[SKIP]".yellow(),
+            skipped.title,
+            skipped.reason
+        );
+    }
+
+    let total: usize = report.shows.iter().map(|s| s.missing_count()).sum();
+    println!("{}", "=".repeat(60));
+    println!(
+        "Shows checked: {}, missing episodes: {}",
+        report.shows.len().to_string().bold(),
+        total.to_string().bold()
+    );
+
+    Ok(())
+}
+
+/// List movies worth replacing with a better copy.
+async fn show_upgrades(
+    below: u32,
+    max_resolution: Option<&str>,
+    limit: Option<usize>,
+    format: &str,
+) -> Result<()> {
+    let index = indexer::load_central_index()?;
+    let mut candidates = quality::upgrade_candidates(&index.movies, below, max_resolution);
+    if let Some(limit) = limit {
+        candidates.truncate(limit);
+    }
+
+    match format {
+        "json" => {
+            println!("{}", serde_json::to_string_pretty(&candidates)?);
+        }
+        "simple" => {
+            for c in &candidates {
+                let year_str = c.year.map(|y| y.to_string()).unwrap_or_default();
+                println!(
+                    "{}\t{} ({})\t{}\t{}:{}",
+                    c.score,
+                    c.title,
+                    year_str,
+                    c.reasons.join(", "),
+                    c.disk,
+                    c.path
+                );
+            }
+        }
+        _ => {
+            if candidates.is_empty() {
+                println!("{}", "No upgrade candidates found.".green());
+                return Ok(());
+            }
+            println!(
+                "{:>5} | {:<40} | {:>6} | {:>5} | {:<7} | {:<12} | {:>9}",
+                "Score", "Title", "Rating", "Res", "Codec", "HDR", "Bitrate"
+            );
+            println!("{}", "-".repeat(100));
+            for c in &candidates {
+                let year_str = c.year.map(|y| format!(" ({})", y)).unwrap_or_default();
+                let title: String = format!("{}{}", c.title, year_str)
+                    .chars()
+                    .take(40)
+                    .collect();
+                println!(
+                    "{:>5} | {:<40} | {:>6} | {:>5} | {:<7} | {:<12} | {:>9}",
+                    c.score.to_string().bold(),
+                    title,
+                    c.rating
+                        .map(|r| format!("{:.1}", r))
+                        .unwrap_or_else(|| "-".to_string()),
+                    c.resolution.as_deref().unwrap_or("-"),
+                    c.video_codec.as_deref().unwrap_or("-"),
+                    c.hdr.as_deref().unwrap_or("-"),
+                    c.bitrate_kbps
+                        .map(|b| format!("{} kbps", b))
+                        .unwrap_or_else(|| "-".to_string())
+                );
+                if !c.reasons.is_empty() {
+                    println!("      {}", c.reasons.join(", ").yellow());
+                }
+            }
+            println!("{}", "=".repeat(60));
+            println!(
+                "Upgrade candidates: {}",
+                candidates.len().to_string().bold()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Format episode numbers as compact ranges, e.g. "E01-E03, E07".
+fn format_episode_list(episodes: &[u16]) -> String {
+    let mut ranges: Vec<(u16, u16)> = Vec::new();
+    for &e in episodes {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == e => *end = e,
+            _ => ranges.push((e, e)),
+        }
+    }
+    if ranges.is_empty() {
+        return "-".to_string();
+    }
+    ranges
+        .iter()
+        .map(|&(start, end)| {
+            if start == end {
+                format!("E{:02}", start)
+            } else {
+                format!("E{:02}-E{:02}", start, end)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -6,5 +6,7 @@ pub mod index;
 pub mod plan;
 pub mod rollback;
 pub mod search;
+pub mod serve;
 pub mod sessions;
 pub mod verify;
+pub mod watch;
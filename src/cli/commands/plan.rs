@@ -1,9 +1,16 @@
 //! Plan command implementation.
 //!
-//! Implements the `plan movies` and `plan tvshows` subcommands.
+//! Implements the `plan movies`, `plan tvshows`, `plan report`, `plan export`
+//! and `plan import-csv` subcommands.
 //! Coordinates scanning, parsing, TMDB lookup, and plan generation.
 
+use crate::core::hooks::{self, HookSummary};
+use crate::core::indexer;
+use crate::core::placement;
+use crate::core::plan_csv::{self, CsvEdit};
 use crate::core::planner::{self, Planner};
+use crate::core::rollback::load_rollback;
+use crate::generators::report;
 use crate::models::media::MediaType;
 use crate::Result;
 use colored::Colorize;
@@ -51,8 +58,31 @@ async fn plan_media(
         return Err(crate::Error::NotADirectory(source.display().to_string()));
     }
 
+    // With `--target auto`, items are placed on the indexed disks afterwards
+    let auto_target = target.is_some_and(|t| t == Path::new("auto"));
+    let mut placement_disks = Vec::new();
+    let mut central = None;
+    if auto_target {
+        let index = indexer::load_central_index()
+            .map_err(|e| crate::Error::other(format!("Failed to load central index: {}", e)))?;
+        placement_disks = placement::online_target_disks(&index, media_type);
+        if placement_disks.is_empty() {
+            return Err(crate::Error::other(format!(
+                "--target auto: no online indexed disk has a {} folder. Scan one with 'index scan' first.",
+                media_type
+            )));
+        }
+        central = Some(index);
+    }
+
     // Determine target path
     let target_path = match target {
+        // Generated against the disk with the most free space, then relocated
+        Some(_) if auto_target => placement_disks
+            .iter()
+            .max_by_key(|d| d.free_bytes)
+            .map(|d| d.root.clone())
+            .unwrap_or_default(),
         Some(t) => t.to_path_buf(),
         None => {
             // Default: create _organized directory next to source
@@ -76,7 +106,41 @@ async fn plan_media(
 
     // Create planner and generate plan
     let planner = Planner::new()?;
-    let plan = planner.generate(source, &target_path, media_type).await?;
+    let mut plan = planner.generate(source, &target_path, media_type).await?;
+
+    if let Some(index) = &central {
+        let summary = placement::place_plan(&mut plan, index, &mut placement_disks);
+        println!();
+        println!("{}", "[Placement]".bold().cyan());
+        // Items of one title are placed together and listed once
+        let mut lines: Vec<String> = plan
+            .items
+            .iter()
+            .filter_map(|item| {
+                let placement = item.placement.as_ref()?;
+                Some(format!(
+                    "  {} -> {}: {}",
+                    item.target.folder,
+                    placement.disk.bold(),
+                    placement.reason.dimmed()
+                ))
+            })
+            .collect();
+        lines.dedup();
+        for line in lines {
+            println!("{}", line);
+        }
+        if summary.unplaced > 0 {
+            println!(
+                "{}",
+                format!(
+                    "[WARN] {} items fit on no online disk and were moved to unknown",
+                    summary.unplaced
+                )
+                .yellow()
+            );
+        }
+    }
 
     // Print summary
     println!();
@@ -123,6 +187,8 @@ async fn plan_media(
         }
     }
 
+    hooks::fire(&HookSummary::for_plan(&plan, Some(&output_path))).await;
+
     // Print next steps with complete commands
     println!();
     println!("{}", "[Next Steps]".bold().cyan());
@@ -148,7 +214,7 @@ async fn plan_media(
 
         // Sort groups by number of files (descending)
         let mut sorted_groups: Vec<_> = grouped.into_iter().collect();
-        sorted_groups.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+        sorted_groups.sort_by_key(|g| std::cmp::Reverse(g.1.len()));
 
         for (reason, files) in sorted_groups {
             println!();
@@ -161,3 +227,211 @@ async fn plan_media(
 
     Ok(())
 }
+
+/// Generate an HTML report for a plan, optionally including execution results.
+pub async fn report_plan(plan_file: &Path, html: &Path, rollback: Option<&Path>) -> Result<()> {
+    println!("{}", "[REPORT] Generating HTML report...".bold().cyan());
+    println!();
+
+    if !plan_file.exists() {
+        return Err(crate::Error::PathNotFound(plan_file.display().to_string()));
+    }
+    let plan = planner::load_plan(plan_file)?;
+
+    let rollback = match rollback {
+        Some(path) => {
+            if !path.exists() {
+                return Err(crate::Error::PathNotFound(path.display().to_string()));
+            }
+            Some(load_rollback(path)?)
+        }
+        None => None,
+    };
+
+    println!("  {} {}", "Plan:".bold(), plan_file.display());
+    println!("  {} {}", "Items:".bold(), plan.items.len());
+    println!("  {} {}", "Unknown:".bold(), plan.unknown.len());
+    if let Some(ref rb) = rollback {
+        println!("  {} {}", "Executed at:".bold(), rb.executed_at);
+    }
+    println!();
+
+    let content = report::generate_plan_report(&plan, rollback.as_ref());
+
+    if let Some(parent) = html.parent() {
+        if !parent.as_os_str().is_empty() && !parent.exists() {
+            std::fs::create_dir_all(parent)?;
+        }
+    }
+    std::fs::write(html, content)?;
+
+    println!(
+        "{} {}",
+        "[OK] Report saved to:".bold().green(),
+        html.display()
+    );
+
+    Ok(())
+}
+
+/// Export a plan to CSV.
+pub async fn export_plan(plan_file: &Path, format: &str, output: Option<&Path>) -> Result<()> {
+    if format != "csv" {
+        return Err(crate::Error::other(format!(
+            "Unsupported export format: {} (supported: csv)",
+            format
+        )));
+    }
+
+    if !plan_file.exists() {
+        return Err(crate::Error::PathNotFound(plan_file.display().to_string()));
+    }
+    let plan = planner::load_plan(plan_file)?;
+
+    let output_path = match output {
+        Some(o) => o.to_path_buf(),
+        None => plan_file.with_extension("csv"),
+    };
+
+    let file = std::fs::File::create(&output_path)?;
+    let rows = plan_csv::write_plan_csv(&plan, file)?;
+
+    println!(
+        "{} {} rows ({} items, {} unknown) to {}",
+        "[OK] Exported".bold().green(),
+        rows,
+        plan.items.len(),
+        plan.unknown.len(),
+        output_path.display()
+    );
+    println!();
+    println!("{}", "[Next Steps]".bold().cyan());
+    println!("  1. Edit the 'status' and 'tmdb_id' columns in a spreadsheet");
+    println!("  2. Apply the changes:");
+    println!(
+        "     {}",
+        format!(
+            "media-organizer plan import-csv {} {}",
+            plan_file.display(),
+            output_path.display()
+        )
+        .bold()
+    );
+
+    Ok(())
+}
+
+/// Apply an edited CSV back into a plan.
+///
+/// Status changes are applied directly. Rows with a changed or newly filled
+/// TMDB ID are re-matched against TMDB and regenerate their target paths;
+/// unknown files that match successfully become regular plan items.
+pub async fn import_plan_csv(
+    plan_file: &Path,
+    csv_file: &Path,
+    output: Option<&Path>,
+) -> Result<()> {
+    println!("{}", "[IMPORT] Applying CSV edits to plan...".bold().cyan());
+    println!();
+
+    for path in [plan_file, csv_file] {
+        if !path.exists() {
+            return Err(crate::Error::PathNotFound(path.display().to_string()));
+        }
+    }
+
+    let mut plan = planner::load_plan(plan_file)?;
+    let rows = plan_csv::read_plan_csv(csv_file)?;
+    let edits = plan_csv::diff_rows(&plan, &rows)?;
+
+    if edits.is_empty() {
+        println!("No changes found in {}", csv_file.display());
+        return Ok(());
+    }
+
+    let status_changed = plan_csv::apply_status_edits(&mut plan, &edits);
+
+    let rematches: Vec<_> = edits
+        .iter()
+        .filter_map(|e| match e {
+            CsvEdit::Rematch { source, tmdb_id } => Some((source, *tmdb_id)),
+            _ => None,
+        })
+        .collect();
+
+    let mut rematched = 0;
+    let mut failed = 0;
+    if !rematches.is_empty() {
+        let media_type = plan
+            .media_type
+            .ok_or_else(|| crate::Error::InvalidPlanFile("Plan has no media type".to_string()))?;
+        let planner = Planner::new()?;
+
+        for (source, tmdb_id) in rematches {
+            let item_idx = plan.items.iter().position(|i| &i.source.path == source);
+            let unknown_idx = plan.unknown.iter().position(|u| &u.source.path == source);
+            let video = match (item_idx, unknown_idx) {
+                (Some(i), _) => plan.items[i].source.clone(),
+                (None, Some(u)) => plan.unknown[u].source.clone(),
+                (None, None) => continue,
+            };
+
+            match planner
+                .rematch_item(&video, tmdb_id, &plan.target_path, media_type)
+                .await
+            {
+                Ok(item) => {
+                    println!(
+                        "  {} {} -> {}",
+                        "[OK]".green(),
+                        video.filename,
+                        item.target.full_path.display()
+                    );
+                    match (item_idx, unknown_idx) {
+                        (Some(i), _) => plan.items[i] = item,
+                        (None, Some(u)) => {
+                            plan.unknown.remove(u);
+                            plan.items.push(item);
+                        }
+                        (None, None) => unreachable!(),
+                    }
+                    rematched += 1;
+                }
+                Err(e) => {
+                    println!(
+                        "  {} {} (tmdb{}): {}",
+                        "[FAILED]".red(),
+                        video.filename,
+                        tmdb_id,
+                        e
+                    );
+                    failed += 1;
+                }
+            }
+        }
+
+        planner.validate_no_duplicate_targets(&plan.items)?;
+    }
+
+    let output_path = output.unwrap_or(plan_file);
+    planner::save_plan(&plan, output_path)?;
+
+    println!();
+    println!("{}", "[Import Summary]".bold().green());
+    println!("  {} {}", "Status changes:".bold(), status_changed);
+    println!("  {} {}", "Re-matched:".bold(), rematched);
+    if failed > 0 {
+        println!(
+            "  {} {}",
+            "Re-match failed:".bold(),
+            failed.to_string().red()
+        );
+    }
+    println!(
+        "{} {}",
+        "[OK] Plan saved to:".bold().green(),
+        output_path.display()
+    );
+
+    Ok(())
+}
@@ -1,15 +1,40 @@
 //! Rollback command implementation.
 //!
 //! Reads a rollback.json file and reverses all operations
-//! to restore the original state.
+//! (or a selection of them) to restore the original state.
 
-use crate::core::rollback::{self, RollbackExecutor};
+use crate::core::hooks::{self, HookSummary};
+use crate::core::planner;
+use crate::core::rollback::{self, AuditStatus, RollbackExecutor, RollbackSelection};
+use crate::core::sessions;
 use crate::Result;
 use colored::Colorize;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Build a rollback selection from command line options.
+pub fn parse_selection(
+    seq: Option<&str>,
+    folder: Option<PathBuf>,
+    title: Option<String>,
+) -> Result<RollbackSelection> {
+    Ok(RollbackSelection {
+        seq_range: seq.map(rollback::parse_seq_range).transpose()?,
+        folder,
+        title,
+    })
+}
 
 /// Execute a rollback.
-pub async fn rollback(rollback_file: &Path, dry_run: bool) -> Result<()> {
+///
+/// With a non-empty selection only the matching operations are undone, and the
+/// remaining ones are written to a residual rollback file.
+pub async fn rollback(
+    rollback_file: &Path,
+    dry_run: bool,
+    selection: &RollbackSelection,
+    residual_path: Option<&Path>,
+    verify_checksum: bool,
+) -> Result<()> {
     println!("{}", "[ROLLBACK] Rollback command".bold().cyan());
     println!();
 
@@ -28,6 +53,36 @@ pub async fn rollback(rollback_file: &Path, dry_run: bool) -> Result<()> {
     println!("  {} {}", "Plan ID:".bold(), rb.plan_id);
     println!("  {} {}", "Executed at:".bold(), rb.executed_at);
     println!("  {} {}", "Operations:".bold(), rb.operations.len());
+
+    // Split into selected and residual operations for a partial rollback
+    let partial = if selection.is_empty() {
+        None
+    } else {
+        let partial = rollback::select_operations(&rb, selection);
+        println!(
+            "  {} {}",
+            "Selected:".bold(),
+            partial.selected.operations.len()
+        );
+        println!(
+            "  {} {}",
+            "Remaining:".bold(),
+            partial.residual.operations.len()
+        );
+        for dir in &partial.kept_dirs {
+            println!(
+                "  {} {} (still contains files that are not rolled back)",
+                "[KEEP]".yellow(),
+                dir.display()
+            );
+        }
+        if partial.selected.operations.is_empty() {
+            println!();
+            println!("{}", "No operations match the selection.".yellow());
+            return Ok(());
+        }
+        Some(partial)
+    };
     println!();
 
     if dry_run {
@@ -36,6 +91,14 @@ pub async fn rollback(rollback_file: &Path, dry_run: bool) -> Result<()> {
             "[DRY-RUN] Showing what would be done:".bold().yellow()
         );
         println!();
+    } else if partial.is_some() {
+        println!(
+            "{}",
+            "[WARNING] This will reverse the selected operations!"
+                .bold()
+                .yellow()
+        );
+        println!();
     } else {
         println!(
             "{}",
@@ -47,8 +110,71 @@ pub async fn rollback(rollback_file: &Path, dry_run: bool) -> Result<()> {
     }
 
     // Execute rollback
-    let executor = RollbackExecutor::new();
-    let result = executor.execute(&rb, dry_run).await?;
+    let executor = RollbackExecutor::with_strict_checksum(verify_checksum);
+    let to_execute = partial.as_ref().map(|p| &p.selected).unwrap_or(&rb);
+    let result = executor.execute(to_execute, dry_run).await?;
+
+    // Save the remaining operations, plus any selected ones that failed,
+    // so they can be rolled back later
+    let mut residual_file = None;
+    if let Some(ref partial) = partial {
+        if !dry_run {
+            let residual = partial.residual_with_failed(&result.failed_seqs);
+            let path = residual_path
+                .map(|p| p.to_path_buf())
+                .unwrap_or_else(|| rollback::residual_rollback_path(rollback_file));
+            rollback::save_rollback(&residual, &path)?;
+            println!(
+                "{} {}",
+                "[OK] Residual rollback saved to:".bold().green(),
+                path.display()
+            );
+            println!();
+            if !residual.operations.is_empty() {
+                residual_file = Some(path);
+            }
+        }
+    }
+
+    if !dry_run {
+        let session = match planner::sessions_dir()
+            .and_then(|dir| sessions::find_session_for_rollback(&dir, &rb))
+        {
+            Ok(session) => session,
+            Err(e) => {
+                tracing::warn!("Failed to look up session: {}", e);
+                None
+            }
+        };
+
+        // Mark the originating session undone once nothing is left to roll
+        // back, or point it to the residual rollback file
+        if let Some(ref session) = session {
+            if let Some(ref path) = residual_file {
+                if let Err(e) = sessions::record_partial_rollback(session, path) {
+                    tracing::warn!("Failed to update session manifest: {}", e);
+                }
+            } else if result.is_success() {
+                if session.rollback_path().exists() {
+                    if let Err(e) = sessions::mark_undone(session) {
+                        tracing::warn!("Failed to mark session undone: {}", e);
+                    }
+                }
+                if let Err(e) = sessions::record_rollback(session) {
+                    tracing::warn!("Failed to update session manifest: {}", e);
+                }
+            }
+        }
+
+        let plan = session.and_then(|s| planner::load_plan(&s.plan_path()).ok());
+        let summary = HookSummary::for_rollback(
+            to_execute,
+            plan.as_ref(),
+            result.error_count,
+            Some(rollback_file),
+        );
+        hooks::fire(&summary).await;
+    }
 
     // Print summary
     result.print_summary();
@@ -85,3 +211,73 @@ pub async fn rollback(rollback_file: &Path, dry_run: bool) -> Result<()> {
 
     Ok(())
 }
+
+/// Audit whether the targets of an execution are still intact.
+pub async fn verify_rollback(rollback_file: &Path) -> Result<()> {
+    println!("{}", "[VERIFY] Auditing execution targets...".bold().cyan());
+    println!();
+
+    if !rollback_file.exists() {
+        return Err(crate::Error::PathNotFound(
+            rollback_file.display().to_string(),
+        ));
+    }
+
+    let rb = rollback::load_rollback(rollback_file)?;
+    println!("  {} {}", "Plan ID:".bold(), rb.plan_id);
+    println!("  {} {}", "Executed at:".bold(), rb.executed_at);
+    println!();
+
+    let entries = rollback::audit_rollback(&rb)?;
+
+    let count = |status: AuditStatus| entries.iter().filter(|e| e.status == status).count();
+    for entry in entries.iter().filter(|e| e.status != AuditStatus::Intact) {
+        let tag = match entry.status {
+            AuditStatus::Modified => "[MODIFIED]".red(),
+            AuditStatus::Missing => "[MISSING]".red(),
+            AuditStatus::Unverified => "[UNVERIFIED]".yellow(),
+            AuditStatus::Unreadable(_) => "[UNREADABLE]".red(),
+            AuditStatus::Intact => "[OK]".green(),
+        };
+        println!(
+            "  {} #{} {:?} {}",
+            tag,
+            entry.seq,
+            entry.op_type,
+            entry.path.display()
+        );
+        if let AuditStatus::Unreadable(ref error) = entry.status {
+            println!("      {}", error.dimmed());
+        }
+    }
+
+    println!();
+    println!("{}", "[Audit Summary]".bold().green());
+    println!("  {} {}", "Intact:".bold(), count(AuditStatus::Intact));
+    println!("  {} {}", "Modified:".bold(), count(AuditStatus::Modified));
+    println!("  {} {}", "Missing:".bold(), count(AuditStatus::Missing));
+    println!(
+        "  {} {}",
+        "Unverified (no checksum):".bold(),
+        count(AuditStatus::Unverified)
+    );
+    println!(
+        "  {} {}",
+        "Unreadable:".bold(),
+        entries
+            .iter()
+            .filter(|e| matches!(e.status, AuditStatus::Unreadable(_)))
+            .count()
+    );
+
+    if count(AuditStatus::Unverified) > 0 {
+        println!();
+        println!(
+            "{}",
+            "[INFO] Moves on the same filesystem have no checksum unless executed with --checksum"
+                .dimmed()
+        );
+    }
+
+    Ok(())
+}
@@ -1,9 +1,40 @@
 //! Search command implementation.
 
 use crate::core::indexer;
+use crate::core::query::Query;
+use crate::models::index::UserData;
 use anyhow::Result;
 use colored::Colorize;
 
+/// Watched, favorite and tag flags of the search command.
+#[derive(Debug, Default)]
+pub struct UserFilter {
+    pub watched: bool,
+    pub unwatched: bool,
+    pub favorite: bool,
+    pub tag: Option<String>,
+}
+
+impl UserFilter {
+    /// The flags as query terms, added to the query expression.
+    fn with_query(&self, query: Option<String>) -> Option<String> {
+        let mut terms: Vec<String> = query.map(|q| format!("({})", q)).into_iter().collect();
+        if self.watched {
+            terms.push("watched:yes".to_string());
+        }
+        if self.unwatched {
+            terms.push("watched:no".to_string());
+        }
+        if self.favorite {
+            terms.push("favorite:yes".to_string());
+        }
+        if let Some(tag) = &self.tag {
+            terms.push(format!("tag:\"{}\"", tag.replace('"', "")));
+        }
+        (!terms.is_empty()).then(|| terms.join(" "))
+    }
+}
+
 /// Execute search command.
 #[allow(clippy::too_many_arguments)]
 pub async fn execute_search(
@@ -14,11 +45,11 @@ pub async fn execute_search(
     year: Option<String>,
     genre: Option<String>,
     country: Option<String>,
+    query: Option<String>,
+    user_filter: UserFilter,
     show_status: bool,
     format: String,
 ) -> Result<()> {
-    let index = indexer::load_central_index()?;
-
     // Parse year or year range
     let (year_single, year_range) = if let Some(ref y) = year {
         if y.contains('-') {
@@ -37,8 +68,14 @@ pub async fn execute_search(
         (None, None)
     };
 
-    let results = indexer::search(
-        &index,
+    // Parse the query first so a typo fails before loading the index
+    let query = user_filter
+        .with_query(query)
+        .as_deref()
+        .map(Query::parse)
+        .transpose()?;
+
+    let results = indexer::search_central(
         title.as_deref(),
         actor.as_deref(),
         director.as_deref(),
@@ -47,7 +84,11 @@ pub async fn execute_search(
         year_range,
         genre.as_deref(),
         country.as_deref(),
-    );
+    )?;
+    let results = match query {
+        Some(query) => query.apply(results),
+        None => results,
+    };
 
     match format.as_str() {
         "json" => print_json(&results),
@@ -75,6 +116,8 @@ fn print_json(results: &indexer::SearchResults) {
         country: Option<String>,
         tmdb_id: Option<u64>,
         imdb_id: Option<String>,
+        #[serde(skip_serializing_if = "UserData::is_empty")]
+        user_data: UserData,
     }
 
     #[derive(serde::Serialize)]
@@ -87,6 +130,8 @@ fn print_json(results: &indexer::SearchResults) {
         episodes: u32,
         tmdb_id: Option<u64>,
         imdb_id: Option<String>,
+        #[serde(skip_serializing_if = "UserData::is_empty")]
+        user_data: UserData,
     }
 
     let output = JsonOutput {
@@ -101,6 +146,7 @@ fn print_json(results: &indexer::SearchResults) {
                 country: m.country.clone(),
                 tmdb_id: m.tmdb_id,
                 imdb_id: m.imdb_id.clone(),
+                user_data: m.user_data.clone(),
             })
             .collect(),
         tvshows: results
@@ -115,6 +161,7 @@ fn print_json(results: &indexer::SearchResults) {
                 episodes: t.episodes,
                 tmdb_id: t.tmdb_id,
                 imdb_id: t.imdb_id.clone(),
+                user_data: t.user_data.clone(),
             })
             .collect(),
     };
@@ -131,7 +178,7 @@ fn print_simple(results: &indexer::SearchResults, show_status: bool) {
 
     for movie in &results.movies {
         let status = if show_status {
-            if indexer::is_disk_online(&movie.disk) {
+            if indexer::is_disk_online(&movie.disk, movie.disk_uuid.as_deref()) {
                 " (Online)"
             } else {
                 " (Offline)"
@@ -151,7 +198,7 @@ fn print_simple(results: &indexer::SearchResults, show_status: bool) {
 
     for tvshow in &results.tvshows {
         let status = if show_status {
-            if indexer::is_disk_online(&tvshow.disk) {
+            if indexer::is_disk_online(&tvshow.disk, tvshow.disk_uuid.as_deref()) {
                 " (Online)"
             } else {
                 " (Offline)"
@@ -201,7 +248,7 @@ fn print_table(results: &indexer::SearchResults, show_status: bool) {
             };
 
             let last_col = if show_status {
-                if indexer::is_disk_online(&movie.disk) {
+                if indexer::is_disk_online(&movie.disk, movie.disk_uuid.as_deref()) {
                     "Online".green().to_string()
                 } else {
                     "Offline".red().to_string()
@@ -245,7 +292,7 @@ fn print_table(results: &indexer::SearchResults, show_status: bool) {
             };
 
             let last_col = if show_status {
-                if indexer::is_disk_online(&tvshow.disk) {
+                if indexer::is_disk_online(&tvshow.disk, tvshow.disk_uuid.as_deref()) {
                     "Online".green().to_string()
                 } else {
                     "Offline".red().to_string()
@@ -0,0 +1,43 @@
+//! Serve command implementation.
+//!
+//! Runs the local HTTP/JSON API server.
+
+use crate::core::planner;
+use crate::server::{self, AppState};
+use crate::Result;
+use colored::Colorize;
+use std::net::SocketAddr;
+
+/// Run the API server until interrupted.
+pub async fn serve(bind: &str) -> Result<()> {
+    let addr: SocketAddr = bind
+        .parse()
+        .map_err(|_| crate::Error::other(format!("Invalid bind address: {}", bind)))?;
+    let sessions_dir = planner::sessions_dir()?;
+
+    println!("{}", "[SERVE] Starting API server...".bold().cyan());
+    println!();
+    println!("  {} http://{}/api", "Listening:".bold(), addr);
+    println!("  {} {}", "Sessions:".bold(), sessions_dir.display());
+    if !addr.ip().is_loopback() {
+        println!();
+        println!(
+            "{}",
+            "[WARNING] The API has no authentication and can move files. Only bind to trusted networks."
+                .bold()
+                .yellow()
+        );
+    }
+    println!();
+    println!("Press Ctrl+C to stop.");
+    println!();
+
+    tokio::select! {
+        result = server::serve(addr, AppState::new(sessions_dir)) => result?,
+        _ = tokio::signal::ctrl_c() => {
+            println!();
+            println!("{}", "[SERVE] Stopped.".bold());
+        }
+    }
+    Ok(())
+}
@@ -2,8 +2,13 @@
 //!
 //! Manages historical sessions stored in ~/.config/media_organizer/sessions/
 
+use crate::core::migration::{self, SchemaKind};
 use crate::core::planner;
+use crate::core::rollback::{self, RollbackExecutor};
+use crate::core::sessions::{self, SessionEntry};
+use crate::models::session::SessionStatus;
 use crate::Result;
+use chrono::Utc;
 use colored::Colorize;
 use std::fs;
 
@@ -19,50 +24,45 @@ pub async fn list_sessions() -> Result<()> {
         return Ok(());
     }
 
-    let mut sessions: Vec<_> = fs::read_dir(&sessions_dir)?
-        .filter_map(|e| e.ok())
-        .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
-        .collect();
+    let sessions = sessions::list(&sessions_dir)?;
 
     if sessions.is_empty() {
         println!("No sessions found.");
         return Ok(());
     }
 
-    // Sort by name (which includes timestamp)
-    sessions.sort_by_key(|e| e.file_name());
-    sessions.reverse(); // Most recent first
-
     println!(
-        "{:<25} {:<10} {:<10} {}",
+        "{:<25} {:<20} {:<10} {:<10} {}",
         "Session ID".bold(),
+        "Status".bold(),
         "Items".bold(),
         "Unknown".bold(),
         "Source".bold()
     );
-    println!("{}", "-".repeat(80));
+    println!("{}", "-".repeat(100));
 
-    for entry in sessions {
-        let session_id = entry.file_name().to_string_lossy().to_string();
-        let plan_path = entry.path().join("plan.json");
+    for session in sessions {
+        let plan_path = session.plan_path();
+        let status = status_label(session.manifest().status);
 
         if plan_path.exists() {
             match planner::load_plan(&plan_path) {
                 Ok(plan) => {
                     println!(
-                        "{:<25} {:<10} {:<10} {}",
-                        session_id,
+                        "{:<25} {:<20} {:<10} {:<10} {}",
+                        session.id,
+                        status,
                         plan.items.len(),
                         plan.unknown.len(),
                         plan.source_path.display()
                     );
                 }
                 Err(_) => {
-                    println!("{:<25} {}", session_id, "(corrupted)".red());
+                    println!("{:<25} {}", session.id, "(corrupted)".red());
                 }
             }
         } else {
-            println!("{:<25} {}", session_id, "(no plan.json)".yellow());
+            println!("{:<25} {}", session.id, "(no plan.json)".yellow());
         }
     }
 
@@ -139,6 +139,48 @@ pub async fn show_session(session_id: &str) -> Result<()> {
         println!("{}", "No plan.json found".yellow());
     }
 
+    // Show execution state
+    let entry = SessionEntry {
+        id: session_id.to_string(),
+        path: session_dir.clone(),
+        created_at: Utc::now(),
+    };
+    let manifest = entry.manifest();
+    println!("{}", "Execution:".bold());
+    println!("  {} {}", "Status:".bold(), status_label(manifest.status));
+    if let Some(ref executed_at) = manifest.executed_at {
+        println!("  {} {}", "Executed at:".bold(), executed_at);
+    }
+    if let Some(ref executed_by) = manifest.executed_by {
+        println!("  {} {}", "Executed by:".bold(), executed_by);
+    }
+    if manifest.executed_at.is_some() {
+        println!("  {} {}", "Successful ops:".bold(), manifest.success_count);
+        println!("  {} {}", "Failed ops:".bold(), manifest.error_count);
+    }
+    for failed in &manifest.failed_operations {
+        println!(
+            "    {} {:?} {} ({})",
+            "-".red(),
+            failed.op,
+            failed.path.display(),
+            failed.error
+        );
+    }
+    if let Some(ref rolled_back_at) = manifest.rolled_back_at {
+        println!("  {} {}", "Rolled back at:".bold(), rolled_back_at);
+    }
+    if let Some(ref path) = manifest.rollback_path {
+        println!("  {} {}", "Rollback file:".bold(), path.display());
+    }
+    if manifest.status == SessionStatus::PartiallyRolledBack {
+        if let Some(ref path) = manifest.residual_rollback_path {
+            println!("  {} {}", "Residual rollback:".bold(), path.display());
+        }
+    }
+    println!("  {} {}", "Tool version:".bold(), manifest.tool_version);
+    println!();
+
     // Check for rollback
     if rollback_path.exists() {
         println!("{} {}", "Rollback:".bold(), "Available".green());
@@ -150,3 +192,249 @@ pub async fn show_session(session_id: &str) -> Result<()> {
 
     Ok(())
 }
+
+/// Upgrade all session files to the current schema version.
+///
+/// Each migrated file is rewritten in place; the original is kept next to it
+/// as `<name>.v<old_version>.bak`.
+pub async fn migrate_sessions() -> Result<()> {
+    println!("{}", "[Sessions] Migrating session files...".bold().cyan());
+    println!();
+
+    let sessions_dir = planner::sessions_dir()?;
+
+    let mut migrated = 0;
+    let mut current = 0;
+    let mut failed = 0;
+
+    let mut sessions: Vec<_> = fs::read_dir(&sessions_dir)?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .collect();
+    sessions.sort_by_key(|e| e.file_name());
+
+    for entry in sessions {
+        let session_id = entry.file_name().to_string_lossy().to_string();
+
+        for (file, kind) in [
+            ("plan.json", SchemaKind::Plan),
+            ("rollback.json", SchemaKind::Rollback),
+        ] {
+            let path = entry.path().join(file);
+            if !path.exists() {
+                continue;
+            }
+
+            match migration::migrate_file(kind, &path) {
+                Ok(Some(outcome)) => {
+                    println!(
+                        "  {} {}/{}: {} -> {}",
+                        "[OK]".green(),
+                        session_id,
+                        file,
+                        outcome.from_version,
+                        outcome.to_version
+                    );
+                    migrated += 1;
+                }
+                Ok(None) => current += 1,
+                Err(e) => {
+                    println!("  {} {}/{}: {}", "[ERROR]".red(), session_id, file, e);
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    println!();
+    println!("{}", "[Migration Summary]".bold().green());
+    println!("  {} {}", "Migrated:".bold(), migrated);
+    println!("  {} {}", "Already current:".bold(), current);
+    if failed > 0 {
+        println!("  {} {}", "Failed:".bold(), failed.to_string().red());
+    }
+
+    Ok(())
+}
+
+/// Roll back the most recent executed session.
+pub async fn undo_last(dry_run: bool) -> Result<()> {
+    println!("{}", "[Sessions] Undo last execution".bold().cyan());
+    println!();
+
+    let sessions_dir = planner::sessions_dir()?;
+    let session = match sessions::latest_executed(&sessions_dir)? {
+        Some(s) => s,
+        None => {
+            println!("No executed sessions found.");
+            return Ok(());
+        }
+    };
+
+    println!("  {} {}", "Session:".bold(), session.id);
+    println!(
+        "  {} {}",
+        "Created:".bold(),
+        session.created_at.to_rfc3339()
+    );
+
+    let rb = rollback::load_rollback(&session.pending_rollback_path())?;
+    println!("  {} {}", "Executed at:".bold(), rb.executed_at);
+    println!("  {} {}", "Operations:".bold(), rb.operations.len());
+    println!();
+
+    let result = RollbackExecutor::new().execute(&rb, dry_run).await?;
+    result.print_summary();
+    println!();
+
+    if dry_run {
+        println!("{}", "[OK] Dry run complete - no changes were made".green());
+    } else if result.is_success() {
+        sessions::mark_undone(&session)?;
+        sessions::record_rollback(&session)?;
+        println!(
+            "{} {}",
+            "[OK] Session rolled back:".bold().green(),
+            session.id
+        );
+    } else {
+        println!(
+            "{}",
+            "[WARNING] Rollback completed with errors; session left as executed".yellow()
+        );
+    }
+
+    Ok(())
+}
+
+/// Delete sessions older than the given age.
+pub async fn prune_sessions(older_than: &str, keep_executed: bool, dry_run: bool) -> Result<()> {
+    println!("{}", "[Sessions] Pruning old sessions...".bold().cyan());
+    println!();
+
+    let age = sessions::parse_age(older_than)?;
+    let sessions_dir = planner::sessions_dir()?;
+    let all = sessions::list(&sessions_dir)?;
+    let candidates = sessions::prune_candidates(&all, age, keep_executed, Utc::now());
+
+    if candidates.is_empty() {
+        println!("No sessions older than {}.", older_than);
+        return Ok(());
+    }
+
+    for session in &candidates {
+        if dry_run {
+            println!("  {} {}", "[DRY RUN]".yellow(), describe(session));
+        } else {
+            sessions::delete(session)?;
+            println!("  {} {}", "[DELETED]".red(), describe(session));
+        }
+    }
+
+    println!();
+    if dry_run {
+        println!(
+            "{} sessions would be deleted ({} kept)",
+            candidates.len(),
+            all.len() - candidates.len()
+        );
+    } else {
+        println!(
+            "{} {} sessions ({} kept)",
+            "[OK] Deleted".bold().green(),
+            candidates.len(),
+            all.len() - candidates.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Delete a single session.
+pub async fn delete_session(session_id: &str) -> Result<()> {
+    let sessions_dir = planner::sessions_dir()?;
+    let path = sessions::session_path(&sessions_dir, session_id)?;
+    let session = SessionEntry {
+        id: session_id.to_string(),
+        created_at: Utc::now(),
+        path,
+    };
+
+    if session.is_executed() {
+        println!(
+            "{}",
+            "[WARNING] This session can still be rolled back; its rollback file will be deleted"
+                .yellow()
+        );
+    }
+
+    sessions::delete(&session)?;
+    println!("{} {}", "[OK] Deleted session:".bold().green(), session_id);
+    Ok(())
+}
+
+/// Compare the plans of two sessions.
+pub async fn diff_sessions(session_a: &str, session_b: &str) -> Result<()> {
+    println!(
+        "{} {} .. {}",
+        "[Sessions] Diff".bold().cyan(),
+        session_a,
+        session_b
+    );
+    println!();
+
+    let sessions_dir = planner::sessions_dir()?;
+    let plan_a =
+        planner::load_plan(&sessions::session_path(&sessions_dir, session_a)?.join("plan.json"))?;
+    let plan_b =
+        planner::load_plan(&sessions::session_path(&sessions_dir, session_b)?.join("plan.json"))?;
+
+    let diff = sessions::diff_plans(&plan_a, &plan_b);
+
+    for source in &diff.removed {
+        println!("{} {}", "-".red(), source.display());
+    }
+    for source in &diff.added {
+        println!("{} {}", "+".green(), source.display());
+    }
+    for change in &diff.changed {
+        println!("{} {}", "~".yellow(), change.source.display());
+        println!("    {} {}", "-".red(), change.before);
+        println!("    {} {}", "+".green(), change.after);
+    }
+
+    println!();
+    println!("{}", "[Diff Summary]".bold().green());
+    println!("  {} {}", "Removed:".bold(), diff.removed.len());
+    println!("  {} {}", "Added:".bold(), diff.added.len());
+    println!("  {} {}", "Changed:".bold(), diff.changed.len());
+    println!("  {} {}", "Unchanged:".bold(), diff.unchanged);
+
+    Ok(())
+}
+
+/// One-line description of a session.
+fn describe(session: &SessionEntry) -> String {
+    format!(
+        "{} (created {}{})",
+        session.id,
+        session.created_at.format("%Y-%m-%d"),
+        if session.is_executed() {
+            ", executed"
+        } else {
+            ""
+        }
+    )
+}
+
+/// Colored label for a session status.
+fn status_label(status: SessionStatus) -> colored::ColoredString {
+    let label = status.to_string();
+    match status {
+        SessionStatus::Planned => label.normal(),
+        SessionStatus::Executed => label.green(),
+        SessionStatus::PartiallyExecuted => label.yellow(),
+        SessionStatus::RolledBack => label.dimmed(),
+        SessionStatus::PartiallyRolledBack => label.yellow(),
+    }
+}
@@ -0,0 +1,256 @@
+//! Watch command implementation.
+//!
+//! Watches an incoming folder for new downloads, waits until they stop
+//! growing, and plans each new directory. Plans are saved to sessions for
+//! approval, or high-confidence items are executed immediately.
+
+use crate::core::executor::{self, Executor};
+use crate::core::hooks::{self, HookSummary};
+use crate::core::planner::{self, Planner};
+use crate::core::sessions::{self, SessionEntry};
+use crate::core::watcher::{self, GrowthTracker};
+use crate::models::media::MediaType;
+use crate::models::plan::Plan;
+use crate::Result;
+use chrono::Utc;
+use colored::Colorize;
+use notify::{RecursiveMode, Watcher};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Options for the watch command.
+#[derive(Debug, Clone)]
+pub struct WatchOptions {
+    /// Media type of the incoming folder.
+    pub media_type: MediaType,
+    /// Target directory for organized files.
+    pub target: PathBuf,
+    /// Seconds a directory must stay unchanged before it is planned.
+    pub quiet_secs: u64,
+    /// Execute confident items immediately instead of waiting for approval.
+    pub auto_execute: bool,
+    /// Minimum confidence for auto-execution.
+    pub min_confidence: f32,
+}
+
+/// Parse a media type argument.
+pub fn parse_media_type(s: &str) -> Result<MediaType> {
+    match s {
+        "movies" => Ok(MediaType::Movies),
+        "tvshows" => Ok(MediaType::TvShows),
+        _ => Err(crate::Error::other(format!(
+            "Invalid media type: {} (expected movies or tvshows)",
+            s
+        ))),
+    }
+}
+
+/// Watch an incoming folder until interrupted.
+pub async fn watch(incoming: &Path, options: WatchOptions) -> Result<()> {
+    if !incoming.exists() {
+        return Err(crate::Error::PathNotFound(incoming.display().to_string()));
+    }
+    if !incoming.is_dir() {
+        return Err(crate::Error::NotADirectory(incoming.display().to_string()));
+    }
+    let incoming = incoming.canonicalize()?;
+
+    println!("{}", "[WATCH] Watching for new downloads...".bold().cyan());
+    println!();
+    println!("  {} {}", "Incoming:".bold(), incoming.display());
+    println!("  {} {}", "Target:".bold(), options.target.display());
+    println!("  {} {}", "Type:".bold(), options.media_type);
+    println!("  {} {}s", "Quiet period:".bold(), options.quiet_secs);
+    if options.auto_execute {
+        println!(
+            "  {} confidence >= {:.2}",
+            "Auto-execute:".bold(),
+            options.min_confidence
+        );
+    }
+    println!();
+    println!("Press Ctrl+C to stop.");
+    println!();
+
+    // Forward filesystem events into the async loop
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut fs_watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if let Ok(event) = res {
+            let _ = tx.send(event);
+        }
+    })
+    .map_err(|e| crate::Error::other(format!("Failed to start watcher: {}", e)))?;
+    fs_watcher
+        .watch(&incoming, RecursiveMode::Recursive)
+        .map_err(|e| crate::Error::other(format!("Failed to watch {:?}: {}", incoming, e)))?;
+
+    let planner = Planner::new()?;
+    let quiet = Duration::from_secs(options.quiet_secs);
+    let mut tracker = GrowthTracker::new();
+    let mut processed: HashSet<PathBuf> = HashSet::new();
+    let mut tick = tokio::time::interval(Duration::from_secs(2));
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    loop {
+        tokio::select! {
+            Some(event) = rx.recv() => {
+                let now = Instant::now();
+                for path in event.paths {
+                    if let Some(entry) = watcher::top_level_entry(&incoming, &path) {
+                        if !processed.contains(&entry) {
+                            tracker.touch(&entry, now);
+                        }
+                    }
+                }
+            }
+            _ = tick.tick() => {
+                // Walking large downloads blocks, so keep it off the runtime threads
+                let tracked = tracker.tracked();
+                let sizes = tokio::task::spawn_blocking(move || {
+                    tracked
+                        .into_iter()
+                        .map(|path| {
+                            let size = path.exists().then(|| watcher::total_size(&path));
+                            (path, size)
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .await
+                .map_err(|e| crate::Error::other(format!("Size check failed: {}", e)))?;
+                let now = Instant::now();
+                for (path, size) in sizes {
+                    match size {
+                        Some(size) => tracker.observe(&path, size, now),
+                        None => tracker.remove(&path),
+                    }
+                }
+
+                for path in tracker.take_ready(now, quiet) {
+                    if !path.is_dir() {
+                        println!(
+                            "{} {} (loose files are not planned; put downloads in a folder)",
+                            "[SKIP]".yellow(),
+                            path.display()
+                        );
+                        processed.insert(path);
+                        continue;
+                    }
+
+                    processed.insert(path.clone());
+                    if let Err(e) = process_directory(&planner, &path, &options).await {
+                        println!("{} {}: {}", "[ERROR]".red(), path.display(), e);
+                    }
+                }
+            }
+            _ = &mut ctrl_c => {
+                println!();
+                println!("{}", "[WATCH] Stopped.".bold());
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Plan a newly completed directory and save or execute the result.
+async fn process_directory(planner: &Planner, dir: &Path, options: &WatchOptions) -> Result<()> {
+    println!();
+    println!("{} {}", "[NEW]".bold().green(), dir.display());
+
+    let plan = planner
+        .generate(dir, &options.target, options.media_type)
+        .await?;
+    if plan.items.is_empty() && plan.unknown.is_empty() {
+        println!("  No video files found.");
+        return Ok(());
+    }
+
+    if !options.auto_execute {
+        let session_dir = planner::save_to_sessions(&plan)?;
+        print_pending(&plan, &session_dir);
+        hooks::fire(&HookSummary::for_plan(
+            &plan,
+            Some(&session_dir.join(sessions::PLAN_FILE)),
+        ))
+        .await;
+        return Ok(());
+    }
+
+    let (auto, mut pending) = watcher::split_by_confidence(&plan, options.min_confidence);
+    // Distinct creation time so the two sessions can be told apart on execute
+    pending.created_at = Utc::now().to_rfc3339();
+
+    if !auto.items.is_empty() {
+        let session_dir = planner::save_to_sessions(&auto)?;
+        let report = Executor::new().execute_with_report(&auto).await?;
+
+        let session = SessionEntry {
+            id: session_dir
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            path: session_dir.clone(),
+            created_at: Utc::now(),
+        };
+        executor::save_rollback(&report.rollback, &session.rollback_path())?;
+        sessions::record_execution(
+            &session,
+            &report.rollback,
+            &session.rollback_path(),
+            report.success_count,
+            report.failed_operations.clone(),
+        )?;
+
+        println!(
+            "  {} {} items executed ({} failed operations)",
+            "[OK]".green(),
+            auto.items.len(),
+            report.error_count
+        );
+        println!(
+            "     Undo with: {}",
+            format!(
+                "media-organizer rollback {}",
+                session.rollback_path().display()
+            )
+            .bold()
+        );
+        hooks::fire(&HookSummary::for_execution(
+            &auto,
+            &report.rollback,
+            Some(&session.plan_path()),
+        ))
+        .await;
+    }
+
+    if !pending.items.is_empty() || !pending.unknown.is_empty() {
+        let session_dir = planner::save_to_sessions(&pending)?;
+        print_pending(&pending, &session_dir);
+        hooks::fire(&HookSummary::for_plan(
+            &pending,
+            Some(&session_dir.join(sessions::PLAN_FILE)),
+        ))
+        .await;
+    }
+
+    Ok(())
+}
+
+/// Print where a plan awaiting approval was saved.
+fn print_pending(plan: &Plan, session_dir: &Path) {
+    println!(
+        "  {} {} items, {} unknown awaiting approval",
+        "[PENDING]".yellow(),
+        plan.items.len(),
+        plan.unknown.len()
+    );
+    println!(
+        "     Review and execute: {}",
+        format!(
+            "media-organizer execute {}",
+            session_dir.join(sessions::PLAN_FILE).display()
+        )
+        .bold()
+    );
+}
@@ -0,0 +1,404 @@
+//! Content-based duplicate detection.
+//!
+//! Files are compared by content rather than TMDB ID, so untagged copies and
+//! the same file under two names are found. Candidates are narrowed by size,
+//! then by a partial hash of the head, middle and tail, and confirmed with a
+//! full SHA256.
+
+use crate::core::migration;
+use crate::core::scanner;
+use crate::models::media::VideoFile;
+use crate::models::plan::{
+    Operation, OperationType, ParsedInfo, Plan, PlanItem, PlanItemStatus, TargetInfo, UnknownItem,
+};
+use crate::utils::{fs as fs_utils, hash};
+use crate::Result;
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Files with identical content.
+#[derive(Debug, Clone, Serialize)]
+pub struct ContentDuplicates {
+    pub size_bytes: u64,
+    pub sha256: String,
+    /// Copies sorted by path; the first one is kept.
+    pub files: Vec<PathBuf>,
+}
+
+impl ContentDuplicates {
+    /// The copy to keep.
+    pub fn keep(&self) -> &Path {
+        &self.files[0]
+    }
+
+    /// The copies that can go.
+    pub fn extras(&self) -> &[PathBuf] {
+        &self.files[1..]
+    }
+
+    /// Bytes freed by removing the extra copies.
+    pub fn wasted_bytes(&self) -> u64 {
+        self.size_bytes * self.extras().len() as u64
+    }
+}
+
+/// Default quarantine folder name, created in each checked folder.
+pub const QUARANTINE_DIR: &str = ".duplicates";
+
+/// Collect the video files under the given directories.
+///
+/// Hidden directories, including quarantine folders, are skipped.
+pub fn collect_video_files(roots: &[PathBuf]) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = roots
+        .iter()
+        .flat_map(|root| {
+            WalkDir::new(root)
+                .follow_links(false)
+                .into_iter()
+                .filter_entry(|e| {
+                    e.depth() == 0
+                        || !(e.file_type().is_dir()
+                            && e.file_name().to_string_lossy().starts_with('.'))
+                })
+        })
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file() && fs_utils::is_video_file(e.path()))
+        .map(|e| e.into_path())
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Find files with identical content.
+///
+/// Files already hard-linked together count as one copy. Unreadable files
+/// are skipped with a warning.
+pub fn find_content_duplicates(files: &[PathBuf]) -> Vec<ContentDuplicates> {
+    find_duplicates(files, true)
+}
+
+/// Find files with identical content, counting hard links to one file as
+/// one copy or as separate copies.
+fn find_duplicates(files: &[PathBuf], links_as_one: bool) -> Vec<ContentDuplicates> {
+    let mut by_size: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+    let mut seen_inodes = std::collections::HashSet::new();
+    for path in files {
+        let Ok(metadata) = std::fs::metadata(path) else {
+            tracing::warn!("Cannot read {:?}, skipping", path);
+            continue;
+        };
+        if metadata.len() == 0 || (links_as_one && !seen_inodes.insert(file_id(path, &metadata))) {
+            continue;
+        }
+        by_size
+            .entry(metadata.len())
+            .or_default()
+            .push(path.clone());
+    }
+
+    let mut duplicates = Vec::new();
+    for (size, paths) in by_size {
+        if paths.len() < 2 {
+            continue;
+        }
+        for (_, candidates) in group_by_hash(&paths, hash::partial_hash) {
+            for (sha256, files) in group_by_hash(&candidates, hash::sha256_file) {
+                duplicates.push(ContentDuplicates {
+                    size_bytes: size,
+                    sha256,
+                    files,
+                });
+            }
+        }
+    }
+
+    duplicates.sort_by(|a, b| {
+        b.wasted_bytes()
+            .cmp(&a.wasted_bytes())
+            .then_with(|| a.files.cmp(&b.files))
+    });
+    duplicates
+}
+
+/// Group paths by a hash, keeping groups of two or more sorted by path.
+fn group_by_hash(
+    paths: &[PathBuf],
+    hasher: fn(&Path) -> Result<String>,
+) -> Vec<(String, Vec<PathBuf>)> {
+    let mut groups: HashMap<String, Vec<PathBuf>> = HashMap::new();
+    for path in paths {
+        match hasher(path) {
+            Ok(digest) => groups.entry(digest).or_default().push(path.clone()),
+            Err(e) => tracing::warn!("Failed to hash {:?}: {}", path, e),
+        }
+    }
+    groups
+        .into_iter()
+        .filter(|(_, g)| g.len() > 1)
+        .map(|(digest, mut g)| {
+            g.sort();
+            (digest, g)
+        })
+        .collect()
+}
+
+/// Identity of the data behind a path, shared by hard links.
+#[cfg(unix)]
+fn file_id(_path: &Path, metadata: &std::fs::Metadata) -> (u64, u64, PathBuf) {
+    use std::os::unix::fs::MetadataExt;
+    (metadata.dev(), metadata.ino(), PathBuf::new())
+}
+
+#[cfg(not(unix))]
+fn file_id(path: &Path, _metadata: &std::fs::Metadata) -> (u64, u64, PathBuf) {
+    (0, 0, path.to_path_buf())
+}
+
+/// Replace the extra copies with hard links to the kept one.
+///
+/// Copies on another filesystem than the kept one cannot be linked and are
+/// returned untouched. Returns the bytes freed and the copies skipped.
+pub fn hardlink_duplicates(duplicates: &ContentDuplicates) -> Result<(u64, Vec<PathBuf>)> {
+    let keep = duplicates.keep();
+    let mut freed = 0;
+    let mut skipped = Vec::new();
+
+    for extra in duplicates.extras() {
+        let temp = extra.with_file_name(format!(
+            ".{}.link",
+            extra.file_name().unwrap_or_default().to_string_lossy()
+        ));
+        if std::fs::hard_link(keep, &temp).is_err() {
+            tracing::warn!("Cannot hard-link {:?} to {:?}", extra, keep);
+            skipped.push(extra.clone());
+            continue;
+        }
+        // Rename over the copy so it is never missing
+        if let Err(e) = std::fs::rename(&temp, extra) {
+            let _ = std::fs::remove_file(&temp);
+            return Err(e.into());
+        }
+        freed += duplicates.size_bytes;
+    }
+
+    Ok((freed, skipped))
+}
+
+/// Set aside videos whose content duplicates another video being planned.
+///
+/// Returns the videos to plan and the extra copies as unknown items, so two
+/// copies of one film are not organized to the same target. Hard links to
+/// one file are extra copies here too.
+pub fn split_duplicate_videos(videos: &[VideoFile]) -> (Vec<VideoFile>, Vec<UnknownItem>) {
+    let paths: Vec<PathBuf> = videos.iter().map(|v| v.path.clone()).collect();
+    let mut extras: HashMap<&Path, &Path> = HashMap::new();
+    let duplicates = find_duplicates(&paths, false);
+    for set in &duplicates {
+        for extra in set.extras() {
+            extras.insert(extra, set.keep());
+        }
+    }
+
+    let mut unique = Vec::new();
+    let mut skipped = Vec::new();
+    for video in videos {
+        match extras.get(video.path.as_path()) {
+            Some(keep) => skipped.push(UnknownItem {
+                source: video.clone(),
+                reason: format!("Duplicate of {}", keep.display()),
+            }),
+            None => unique.push(video.clone()),
+        }
+    }
+    (unique, skipped)
+}
+
+/// Build a plan moving the extra copies into a quarantine directory.
+///
+/// Without `quarantine`, copies go to a [`QUARANTINE_DIR`] folder in the
+/// checked folder holding them, so nothing is copied across disks. Each set
+/// of duplicates gets a folder named after its hash, and the plan can be
+/// reviewed, executed and rolled back like any other.
+pub fn quarantine_plan(
+    duplicates: &[ContentDuplicates],
+    roots: &[PathBuf],
+    quarantine: Option<&Path>,
+) -> Result<Plan> {
+    let source = roots.first().cloned().unwrap_or_default();
+    let mut items = Vec::new();
+    for set in duplicates {
+        let hash_dir = &set.sha256[..12.min(set.sha256.len())];
+        for (n, extra) in set.extras().iter().enumerate() {
+            let base = match quarantine {
+                Some(dir) => dir.to_path_buf(),
+                None => roots
+                    .iter()
+                    .find(|r| extra.starts_with(r))
+                    .unwrap_or(&source)
+                    .join(QUARANTINE_DIR),
+            };
+            let folder = base.join(hash_dir);
+            let file = scanner::create_video_file(extra)?;
+            let filename = format!("{}_{}", n + 1, file.filename);
+            let to = folder.join(&filename);
+            items.push(PlanItem {
+                id: uuid::Uuid::new_v4().to_string(),
+                status: PlanItemStatus::Pending,
+                source: file,
+                parsed: ParsedInfo::default(),
+                movie_metadata: None,
+                tvshow_metadata: None,
+                episode_metadata: None,
+                video_metadata: Default::default(),
+                target: TargetInfo {
+                    folder: folder.display().to_string(),
+                    filename,
+                    full_path: to.clone(),
+                    ..Default::default()
+                },
+                operations: vec![
+                    Operation {
+                        op: OperationType::Mkdir,
+                        from: None,
+                        to: folder.clone(),
+                        url: None,
+                        content_ref: None,
+                    },
+                    Operation {
+                        op: OperationType::Move,
+                        from: Some(extra.clone()),
+                        to,
+                        url: None,
+                        content_ref: None,
+                    },
+                ],
+                placement: None,
+            });
+        }
+    }
+
+    Ok(Plan {
+        version: migration::PLAN_VERSION.to_string(),
+        created_at: Utc::now().to_rfc3339(),
+        media_type: None,
+        target_path: quarantine
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| source.join(QUARANTINE_DIR)),
+        source_path: source,
+        items,
+        samples: Vec::new(),
+        unknown: Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write(dir: &Path, name: &str, content: &[u8]) -> PathBuf {
+        let path = dir.join(name);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_find_content_duplicates() {
+        let temp = TempDir::new().unwrap();
+        let a = write(temp.path(), "A/movie.mkv", b"same content");
+        let b = write(temp.path(), "B/renamed.mp4", b"same content");
+        write(temp.path(), "C/other.mkv", b"diff content");
+        write(temp.path(), "C/notes.txt", b"same content");
+
+        let files = collect_video_files(&[temp.path().to_path_buf()]);
+        assert_eq!(files.len(), 3);
+        let duplicates = find_content_duplicates(&files);
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].files, vec![a, b]);
+        assert_eq!(duplicates[0].wasted_bytes(), 12);
+    }
+
+    #[test]
+    fn test_hardlink_duplicates() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "a.mkv", b"same content");
+        let b = write(temp.path(), "b.mkv", b"same content");
+
+        let files = collect_video_files(&[temp.path().to_path_buf()]);
+        let duplicates = find_content_duplicates(&files);
+        let (freed, skipped) = hardlink_duplicates(&duplicates[0]).unwrap();
+        assert_eq!((freed, skipped.len()), (12, 0));
+        assert_eq!(fs::read(&b).unwrap(), b"same content");
+
+        // Linked copies are no longer reported
+        assert!(find_content_duplicates(&files).is_empty());
+    }
+
+    #[test]
+    fn test_split_duplicate_videos() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "a/Film.mkv", b"same content");
+        write(temp.path(), "b/Film.2010.mkv", b"same content");
+        write(temp.path(), "c/Other.mkv", b"other");
+        let videos: Vec<VideoFile> = collect_video_files(&[temp.path().to_path_buf()])
+            .iter()
+            .map(|p| scanner::create_video_file(p).unwrap())
+            .collect();
+
+        let (unique, skipped) = split_duplicate_videos(&videos);
+        assert_eq!(unique.len(), 2);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].source.filename, "Film.2010.mkv");
+        assert!(skipped[0].reason.starts_with("Duplicate of"));
+    }
+
+    #[test]
+    fn test_split_duplicate_videos_with_hard_links() {
+        let temp = TempDir::new().unwrap();
+        let a = write(temp.path(), "a/Film.mkv", b"same content");
+        fs::create_dir_all(temp.path().join("b")).unwrap();
+        fs::hard_link(&a, temp.path().join("b/Film.2010.mkv")).unwrap();
+        let videos: Vec<VideoFile> = collect_video_files(&[temp.path().to_path_buf()])
+            .iter()
+            .map(|p| scanner::create_video_file(p).unwrap())
+            .collect();
+
+        // One copy on disk, but two paths to organize
+        assert!(
+            find_content_duplicates(&collect_video_files(&[temp.path().to_path_buf()])).is_empty()
+        );
+        let (unique, skipped) = split_duplicate_videos(&videos);
+        assert_eq!(unique.len(), 1);
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].source.filename, "Film.2010.mkv");
+    }
+
+    #[test]
+    fn test_quarantine_plan() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "a.mkv", b"same content");
+        let b = write(temp.path(), "b.mkv", b"same content");
+        let roots = vec![temp.path().to_path_buf()];
+
+        let files = collect_video_files(&roots);
+        let duplicates = find_content_duplicates(&files);
+        let plan = quarantine_plan(&duplicates, &roots, None).unwrap();
+
+        assert_eq!(plan.items.len(), 1);
+        let moved = &plan.items[0].operations[1];
+        assert_eq!(moved.from.as_ref(), Some(&b));
+        assert!(moved.to.starts_with(temp.path().join(QUARANTINE_DIR)));
+        assert!(moved.to.ends_with("1_b.mkv"));
+
+        // Quarantined copies are not reported again
+        fs::create_dir_all(moved.to.parent().unwrap()).unwrap();
+        fs::rename(&b, &moved.to).unwrap();
+        assert!(find_content_duplicates(&collect_video_files(&roots)).is_empty());
+    }
+}
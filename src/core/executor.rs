@@ -12,6 +12,7 @@ use crate::models::plan::{Operation, OperationType, Plan, PlanItem, PlanItemStat
 use crate::models::rollback::{
     Rollback, RollbackAction, RollbackActionType, RollbackOpType, RollbackOperation,
 };
+use crate::models::session::FailedOperation;
 use crate::utils::hash;
 use crate::Result;
 use chrono::Utc;
@@ -20,7 +21,7 @@ use futures::stream::{self, StreamExt};
 use indicatif::{ProgressBar, ProgressStyle};
 use std::fs;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use uuid::Uuid;
@@ -32,6 +33,9 @@ pub struct ExecutorConfig {
     pub verify_checksum: bool,
     /// Whether to create backup before overwriting.
     pub backup_on_overwrite: bool,
+    /// Whether to record checksums for same-filesystem renames too,
+    /// so a later rollback can verify them.
+    pub checksum_renames: bool,
 }
 
 impl Default for ExecutorConfig {
@@ -39,10 +43,24 @@ impl Default for ExecutorConfig {
         Self {
             verify_checksum: true,
             backup_on_overwrite: true,
+            checksum_renames: false,
         }
     }
 }
 
+/// Outcome of executing a plan.
+#[derive(Debug)]
+pub struct ExecutionReport {
+    /// Rollback for the executed operations.
+    pub rollback: Rollback,
+    /// Number of successful (or already completed) operations.
+    pub success_count: usize,
+    /// Number of failed operations.
+    pub error_count: usize,
+    /// Details of the failed operations.
+    pub failed_operations: Vec<FailedOperation>,
+}
+
 /// Plan executor.
 pub struct Executor {
     config: ExecutorConfig,
@@ -68,6 +86,11 @@ impl Executor {
 
     /// Execute a plan with optimized parallel downloads.
     pub async fn execute(&self, plan: &Plan) -> Result<Rollback> {
+        self.execute_with_report(plan).await.map(|r| r.rollback)
+    }
+
+    /// Execute a plan and report per-operation outcomes along with the rollback.
+    pub async fn execute_with_report(&self, plan: &Plan) -> Result<ExecutionReport> {
         println!("{}", "[EXEC] Executing plan...".bold().cyan());
         println!();
 
@@ -76,7 +99,7 @@ impl Executor {
 
         // Initialize rollback structure
         let rollback = Arc::new(Mutex::new(Rollback {
-            version: "1.0".to_string(),
+            version: crate::core::migration::ROLLBACK_VERSION.to_string(),
             plan_id: Uuid::new_v4().to_string(),
             executed_at: Utc::now().to_rfc3339(),
             operations: Vec::new(),
@@ -85,6 +108,7 @@ impl Executor {
         let seq = Arc::new(Mutex::new(0u32));
         let mut success_count = 0;
         let mut error_count = 0;
+        let mut failed_operations = Vec::new();
 
         // Collect all operations, separating downloads for parallel execution
         let mut non_download_ops: Vec<(&Operation, &PlanItem)> = Vec::new();
@@ -155,6 +179,11 @@ impl Executor {
                 Err(e) => {
                     tracing::error!("Operation failed: {} - {}", op.to.display(), e);
                     error_count += 1;
+                    failed_operations.push(FailedOperation {
+                        op: op.op,
+                        path: op.to.clone(),
+                        error: e.to_string(),
+                    });
                 }
             }
         }
@@ -165,11 +194,15 @@ impl Executor {
 
             const DOWNLOAD_CONCURRENCY: usize = 4;
 
-            let download_results: Vec<_> = stream::iter(download_ops.iter())
-                .map(|(op, _item)| {
+            // Owned inputs keep the stream future `Send` for spawned callers
+            let downloads: Vec<(Option<String>, PathBuf)> = download_ops
+                .iter()
+                .map(|(op, _item)| (op.url.clone(), op.to.clone()))
+                .collect();
+
+            let download_results: Vec<_> = stream::iter(downloads)
+                .map(|(op_url, op_to)| {
                     let client = &self.http_client;
-                    let op_to = op.to.clone();
-                    let op_url = op.url.clone();
                     async move {
                         let result = Self::execute_download_static(client, &op_url, &op_to).await;
                         (op_to, result)
@@ -205,6 +238,11 @@ impl Executor {
                     Err(e) => {
                         tracing::warn!("Download failed: {} - {}", path.display(), e);
                         error_count += 1;
+                        failed_operations.push(FailedOperation {
+                            op: OperationType::Download,
+                            path,
+                            error: e.to_string(),
+                        });
                     }
                 }
             }
@@ -224,7 +262,12 @@ impl Executor {
             .map_err(|_| crate::Error::ExecuteError("Failed to unwrap rollback".to_string()))?
             .into_inner();
 
-        Ok(final_rollback)
+        Ok(ExecutionReport {
+            rollback: final_rollback,
+            success_count,
+            error_count,
+            failed_operations,
+        })
     }
 
     /// Static download function for parallel execution.
@@ -357,6 +400,7 @@ impl Executor {
             OperationType::Move => self.execute_move(op),
             OperationType::Create => self.execute_create(op, item, plan),
             OperationType::Download => self.execute_download(op).await,
+            OperationType::Rmdir => self.execute_rmdir(op),
         }
     }
 
@@ -387,6 +431,39 @@ impl Executor {
         }))
     }
 
+    /// Execute rmdir operation.
+    ///
+    /// Directories that still hold files (e.g. after a failed move) are kept.
+    fn execute_rmdir(&self, op: &Operation) -> Result<Option<RollbackOperation>> {
+        let path = &op.to;
+
+        if !path.exists() {
+            tracing::debug!("Directory already removed: {:?}", path);
+            return Ok(None);
+        }
+        if fs::read_dir(path)?.next().is_some() {
+            tracing::warn!("Directory not empty, keeping: {:?}", path);
+            return Ok(None);
+        }
+
+        fs::remove_dir(path)?;
+        tracing::debug!("Removed directory: {:?}", path);
+
+        Ok(Some(RollbackOperation {
+            seq: 0,
+            op_type: RollbackOpType::Rmdir,
+            from: path.clone(),
+            to: path.clone(),
+            checksum: None,
+            rollback: RollbackAction {
+                op: RollbackActionType::Mkdir,
+                path: path.clone(),
+                to: None,
+            },
+            executed: false,
+        }))
+    }
+
     /// Execute move operation.
     ///
     /// Optimization: For same-filesystem moves (rename), skip checksum verification
@@ -468,13 +545,19 @@ impl Executor {
         match fs::rename(from, to) {
             Ok(()) => {
                 // Rename succeeded - same filesystem, no checksum needed
+                // unless requested for rollback verification
                 tracing::debug!("Moved (rename): {:?} -> {:?}", from, to);
+                let checksum = if self.config.checksum_renames {
+                    Some(hash::sha256_file(to)?)
+                } else {
+                    None
+                };
                 return Ok(Some(RollbackOperation {
                     seq: 0,
                     op_type: RollbackOpType::Move,
                     from: from.clone(),
                     to: to.clone(),
-                    checksum: None, // No checksum for atomic rename
+                    checksum,
                     rollback: RollbackAction {
                         op: RollbackActionType::Move,
                         path: to.clone(),
@@ -739,4 +822,30 @@ mod tests {
         let result = executor.validate(&plan);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_rmdir_keeps_non_empty_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let full = dir.path().join("full");
+        let empty = dir.path().join("empty");
+        fs::create_dir_all(&full).unwrap();
+        fs::create_dir_all(&empty).unwrap();
+        fs::write(full.join("left.mkv"), b"x").unwrap();
+        let rmdir = |path: &Path| Operation {
+            op: OperationType::Rmdir,
+            from: None,
+            to: path.to_path_buf(),
+            url: None,
+            content_ref: None,
+        };
+
+        let executor = Executor::new();
+        assert!(executor.execute_rmdir(&rmdir(&full)).unwrap().is_none());
+        assert!(full.exists());
+
+        let rollback_op = executor.execute_rmdir(&rmdir(&empty)).unwrap().unwrap();
+        assert!(!empty.exists());
+        assert_eq!(rollback_op.op_type, RollbackOpType::Rmdir);
+        assert_eq!(rollback_op.rollback.op, RollbackActionType::Mkdir);
+    }
 }
@@ -1,6 +1,8 @@
 //! Export and import functionality for configuration and indexes.
 
 use crate::core::indexer;
+use crate::core::migration::{self, SchemaKind};
+use crate::models::config::IndexBackend;
 use crate::models::index::{
     CentralIndex, ExportContents, ExportManifest, ExportStatistics, SourcePaths,
 };
@@ -134,14 +136,25 @@ pub fn export_to_file(output_path: &Path, options: &ExportOptions) -> Result<Exp
     if export_indexes && !skip_indexes {
         // Central index
         let central_index_path = config_path.join("central_index.json");
-        if central_index_path.exists() {
-            let central_content = fs::read_to_string(&central_index_path)?;
+        let central_content = match indexer::index_backend() {
+            IndexBackend::Json if central_index_path.exists() => {
+                Some(fs::read_to_string(&central_index_path)?)
+            }
+            IndexBackend::Json => None,
+            // Archives always carry the JSON form of the index
+            IndexBackend::Sqlite => Some(serde_json::to_string_pretty(
+                &indexer::load_central_index()?,
+            )?),
+        };
+        if let Some(central_content) = central_content {
             zip.start_file("indexes/central_index.json", zip_options)?;
             zip.write_all(central_content.as_bytes())?;
             contents.central_index = true;
 
             // Parse for statistics
-            if let Ok(index) = serde_json::from_str::<CentralIndex>(&central_content) {
+            if let Ok(index) =
+                migration::load_json::<CentralIndex>(SchemaKind::CentralIndex, &central_content)
+            {
                 stats.total_movies = index.movies.len();
                 stats.total_tvshows = index.tvshows.len();
                 stats.total_disks = index.disks.len();
@@ -422,7 +435,8 @@ pub fn import_from_file(backup_path: &Path, options: &ImportOptions) -> Result<I
         if path_str == "indexes/central_index.json" && options.merge {
             let mut content = String::new();
             file.read_to_string(&mut content)?;
-            let imported_index: CentralIndex = serde_json::from_str(&content)?;
+            let imported_index: CentralIndex =
+                migration::load_json(SchemaKind::CentralIndex, &content)?;
 
             let mut current_index = indexer::load_central_index()?;
             let movies_before = current_index.movies.len();
@@ -450,6 +464,13 @@ pub fn import_from_file(backup_path: &Path, options: &ImportOptions) -> Result<I
         let mut outfile = File::create(&target_path)?;
         std::io::copy(&mut file, &mut outfile)?;
 
+        // Keep the index database in sync with the imported JSON index
+        if path_str == "indexes/central_index.json"
+            && indexer::index_backend() == IndexBackend::Sqlite
+        {
+            indexer::save_central_index(&indexer::load_central_json()?)?;
+        }
+
         tracing::info!("Imported: {}", path_str);
         result.imported += 1;
     }
@@ -0,0 +1,390 @@
+//! Gap reports against TMDB: missing episodes and collection movies.
+//!
+//! The episode files recorded for each indexed TV show are compared with the
+//! episode lists TMDB has for its seasons, and owned collection movies with
+//! the collection's parts. Titles that are out but not on any disk are
+//! missing; those without a date or dated in the future are reported
+//! separately as unaired or unreleased.
+
+use super::indexer;
+use super::title_match::TitleQuery;
+use crate::models::index::{CentralIndex, CollectionInfo, TvShowEntry};
+use crate::services::tmdb::{CollectionPart, EpisodeInfo, TmdbClient};
+use crate::Result;
+use serde::Serialize;
+
+/// An episode listed by TMDB but not found on disk.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MissingEpisode {
+    pub season: u16,
+    pub episode: u16,
+    pub title: String,
+    pub air_date: Option<String>,
+}
+
+/// Comparison of one season against TMDB.
+#[derive(Debug, Clone, Serialize)]
+pub struct SeasonGaps {
+    pub season: u16,
+    /// Episodes TMDB lists for this season
+    pub total: usize,
+    /// Listed episodes found on disk
+    pub owned: usize,
+    /// Aired episodes not found on disk
+    pub missing: Vec<MissingEpisode>,
+    /// Episodes not aired yet
+    pub unaired: Vec<MissingEpisode>,
+}
+
+/// Missing episodes of a TV show across all disks holding it.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShowGaps {
+    pub title: String,
+    pub year: Option<u16>,
+    pub tmdb_id: u64,
+    pub disks: Vec<String>,
+    pub seasons: Vec<SeasonGaps>,
+}
+
+impl ShowGaps {
+    /// Number of aired episodes missing across all seasons.
+    pub fn missing_count(&self) -> usize {
+        self.seasons.iter().map(|s| s.missing.len()).sum()
+    }
+}
+
+/// A TV show that could not be checked, with the reason.
+#[derive(Debug, Clone, Serialize)]
+pub struct SkippedShow {
+    pub title: String,
+    pub reason: String,
+}
+
+/// Result of a missing-episode check.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GapReport {
+    pub shows: Vec<ShowGaps>,
+    pub skipped: Vec<SkippedShow>,
+}
+
+/// A collection movie not found in the index.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MissingMovie {
+    pub tmdb_id: u64,
+    pub title: String,
+    pub original_title: String,
+    pub year: Option<u16>,
+    pub release_date: Option<String>,
+}
+
+/// Comparison of a collection against its TMDB parts.
+#[derive(Debug, Clone, Serialize)]
+pub struct CollectionGaps {
+    /// Movies of the collection that are released or owned
+    pub total: usize,
+    /// Movies owned
+    pub owned: usize,
+    /// Released movies not owned
+    pub missing: Vec<MissingMovie>,
+    /// Movies not released yet
+    pub unreleased: Vec<MissingMovie>,
+}
+
+impl CollectionGaps {
+    /// Whether every released movie is owned.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// Today's date as `YYYY-MM-DD`.
+pub fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+/// Compare the owned movies of a collection with its TMDB parts.
+///
+/// Unreleased movies are left out of the total, so a collection is complete
+/// once every released movie is owned.
+pub fn compare_collection(
+    collection: &CollectionInfo,
+    parts: &[CollectionPart],
+    today: &str,
+) -> CollectionGaps {
+    let mut gaps = CollectionGaps {
+        total: 0,
+        owned: 0,
+        missing: Vec::new(),
+        unreleased: Vec::new(),
+    };
+
+    for part in parts {
+        let owned = collection
+            .movies
+            .iter()
+            .any(|m| m.owned && m.tmdb_id == part.id);
+        let release_date = part.release_date.clone().filter(|d| !d.is_empty());
+        let released = release_date.as_deref().is_some_and(|d| d <= today);
+        if owned {
+            gaps.total += 1;
+            gaps.owned += 1;
+            continue;
+        }
+
+        let movie = MissingMovie {
+            tmdb_id: part.id,
+            title: part.title.clone(),
+            original_title: part.original_title.clone(),
+            year: release_date
+                .as_deref()
+                .and_then(|d| d.get(..4)?.parse().ok()),
+            release_date,
+        };
+        if released {
+            gaps.total += 1;
+            gaps.missing.push(movie);
+        } else {
+            gaps.unreleased.push(movie);
+        }
+    }
+
+    gaps.missing
+        .sort_by(|a, b| a.release_date.cmp(&b.release_date));
+    gaps.unreleased
+        .sort_by(|a, b| a.release_date.cmp(&b.release_date));
+    gaps
+}
+
+/// Fetch a collection from TMDB and compare it with the owned movies.
+pub async fn check_collection(
+    collection: &CollectionInfo,
+    client: &TmdbClient,
+    today: &str,
+) -> Result<CollectionGaps> {
+    let details = client.get_collection_details(collection.id).await?;
+    Ok(compare_collection(collection, &details.parts, today))
+}
+
+/// Compare the episodes owned of a season with the TMDB episode list.
+///
+/// `today` is a `YYYY-MM-DD` date; episodes airing after it are unaired.
+pub fn compare_season(
+    season: u16,
+    owned: &[u16],
+    episodes: &[EpisodeInfo],
+    today: &str,
+) -> SeasonGaps {
+    let mut gaps = SeasonGaps {
+        season,
+        total: episodes.len(),
+        owned: 0,
+        missing: Vec::new(),
+        unaired: Vec::new(),
+    };
+
+    for info in episodes {
+        if owned.contains(&info.episode_number) {
+            gaps.owned += 1;
+            continue;
+        }
+        let episode = MissingEpisode {
+            season,
+            episode: info.episode_number,
+            title: info.name.clone(),
+            air_date: info.air_date.clone().filter(|d| !d.is_empty()),
+        };
+        // ISO dates compare correctly as strings
+        match episode.air_date.as_deref() {
+            Some(date) if date <= today => gaps.missing.push(episode),
+            _ => gaps.unaired.push(episode),
+        }
+    }
+    gaps
+}
+
+/// Check indexed TV shows for missing episodes.
+///
+/// `show` limits the check to shows matching a title. Specials (season 0)
+/// are only checked with `include_specials`.
+pub async fn find_missing_episodes(
+    index: &CentralIndex,
+    client: &TmdbClient,
+    show: Option<&str>,
+    include_specials: bool,
+) -> Result<GapReport> {
+    let query = show.map(TitleQuery::new);
+    let groups = indexer::group_tvshows(index.tvshows.iter().filter(|t| {
+        query.as_ref().is_none_or(|q| {
+            q.score_entry(&t.title, t.original_title.as_deref())
+                .is_some()
+        })
+    }));
+    let today = today();
+
+    let mut report = GapReport::default();
+    for group in groups {
+        let first = group[0];
+        let skip = |reason: &str| SkippedShow {
+            title: first.title.clone(),
+            reason: reason.to_string(),
+        };
+        let Some(tmdb_id) = first.tmdb_id else {
+            report.skipped.push(skip("no TMDB ID"));
+            continue;
+        };
+        if group.iter().all(|t| t.episode_files.is_empty()) {
+            report
+                .skipped
+                .push(skip("no episode files indexed; re-run 'index scan'"));
+            continue;
+        }
+
+        match check_show(&group, tmdb_id, client, include_specials, &today).await {
+            Ok(gaps) => report.shows.push(gaps),
+            Err(e) => {
+                tracing::warn!("Failed to check '{}' on TMDB: {}", first.title, e);
+                report
+                    .skipped
+                    .push(skip(&format!("TMDB lookup failed: {}", e)));
+            }
+        }
+    }
+
+    report
+        .shows
+        .sort_by_key(|s| std::cmp::Reverse(s.missing_count()));
+    Ok(report)
+}
+
+/// Compare every season of one show with TMDB.
+async fn check_show(
+    group: &[&TvShowEntry],
+    tmdb_id: u64,
+    client: &TmdbClient,
+    include_specials: bool,
+    today: &str,
+) -> Result<ShowGaps> {
+    let details = client.get_tv_details(tmdb_id).await?;
+    let first_season = if include_specials { 0 } else { 1 };
+
+    let mut seasons = Vec::new();
+    for season in first_season..=details.number_of_seasons {
+        let owned: Vec<u16> = group
+            .iter()
+            .flat_map(|t| &t.episode_files)
+            .filter(|f| f.season == season)
+            .map(|f| f.episode)
+            .collect();
+        let episodes = match client.get_season_details(tmdb_id, season).await {
+            Ok(details) => details.episodes,
+            // Many shows have no specials season
+            Err(_) if season == 0 => continue,
+            Err(e) => return Err(e),
+        };
+        seasons.push(compare_season(season, &owned, &episodes, today));
+    }
+
+    let mut disks: Vec<String> = Vec::new();
+    for show in group {
+        if !disks.contains(&show.disk) {
+            disks.push(show.disk.clone());
+        }
+    }
+
+    Ok(ShowGaps {
+        title: group[0].title.clone(),
+        year: group[0].year,
+        tmdb_id,
+        disks,
+        seasons,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::index::CollectionMovie;
+
+    fn episode(number: u16, air_date: Option<&str>) -> EpisodeInfo {
+        EpisodeInfo {
+            id: number as u64,
+            name: format!("Episode {}", number),
+            overview: None,
+            episode_number: number,
+            season_number: 1,
+            air_date: air_date.map(|d| d.to_string()),
+            still_path: None,
+        }
+    }
+
+    #[test]
+    fn test_compare_season() {
+        let episodes = vec![
+            episode(1, Some("2015-09-19")),
+            episode(2, Some("2015-09-20")),
+            episode(3, Some("2015-09-26")),
+            episode(4, Some("2030-01-01")),
+            episode(5, None),
+        ];
+        let gaps = compare_season(1, &[1, 3, 9], &episodes, "2024-06-01");
+
+        assert_eq!(gaps.total, 5);
+        assert_eq!(gaps.owned, 2);
+        let missing: Vec<u16> = gaps.missing.iter().map(|e| e.episode).collect();
+        assert_eq!(missing, vec![2]);
+        assert_eq!(gaps.missing[0].title, "Episode 2");
+        let unaired: Vec<u16> = gaps.unaired.iter().map(|e| e.episode).collect();
+        assert_eq!(unaired, vec![4, 5]);
+    }
+
+    #[test]
+    fn test_compare_collection() {
+        let part = |id: u64, title: &str, date: Option<&str>| CollectionPart {
+            id,
+            title: title.to_string(),
+            original_title: title.to_string(),
+            release_date: date.map(|d| d.to_string()),
+            poster_path: None,
+        };
+        let collection = CollectionInfo {
+            id: 10,
+            name: "Saga".to_string(),
+            poster_url: None,
+            movies: vec![CollectionMovie {
+                tmdb_id: 1,
+                title: "Part One".to_string(),
+                year: Some(2001),
+                disk: Some("D1".to_string()),
+                owned: true,
+            }],
+            total_in_collection: 0,
+            owned_count: 1,
+        };
+        let parts = vec![
+            part(3, "Part Three", Some("2005-05-01")),
+            part(1, "Part One", Some("2001-05-01")),
+            part(2, "Part Two", Some("2003-05-01")),
+            part(4, "Part Four", Some("2030-01-01")),
+            part(5, "Part Five", Some("")),
+        ];
+
+        let gaps = compare_collection(&collection, &parts, "2024-06-01");
+        assert_eq!((gaps.owned, gaps.total), (1, 3));
+        assert!(!gaps.is_complete());
+        let missing: Vec<&str> = gaps.missing.iter().map(|m| m.title.as_str()).collect();
+        assert_eq!(missing, vec!["Part Two", "Part Three"]);
+        assert_eq!(gaps.missing[0].year, Some(2003));
+        assert_eq!(gaps.unreleased.len(), 2);
+
+        // Unreleased parts do not make a collection incomplete
+        let gaps = compare_collection(&collection, &parts[1..2], "2024-06-01");
+        assert!(gaps.is_complete());
+    }
+
+    #[test]
+    fn test_aired_today_is_missing() {
+        let gaps = compare_season(2, &[], &[episode(1, Some("2024-06-01"))], "2024-06-01");
+        assert_eq!(gaps.missing.len(), 1);
+        assert_eq!(gaps.missing[0].season, 2);
+    }
+}
@@ -0,0 +1,240 @@
+//! Post-command hooks.
+//!
+//! Runs the hooks configured in `config.toml` after plan generation,
+//! execution and rollback. Each hook receives a JSON summary: shell commands
+//! on stdin (`sh -c`), webhooks as a POST body. Hook failures are reported
+//! but never fail the command that triggered them.
+
+use crate::generators::report::{self, ItemOutcome};
+use crate::models::config::{self, HookConfig, HookEvent};
+use crate::models::media::MediaType;
+use crate::models::plan::{Plan, PlanItem, PlanItemStatus};
+use crate::models::rollback::Rollback;
+use crate::Result;
+use chrono::Utc;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+/// JSON summary passed to hooks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HookSummary {
+    /// Event that triggered the hook.
+    pub event: HookEvent,
+    /// When the event happened.
+    pub timestamp: String,
+    /// Media type of the plan.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<MediaType>,
+    /// Plan source directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_path: Option<PathBuf>,
+    /// Plan target directory.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_path: Option<PathBuf>,
+    /// Plan or rollback file the event refers to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
+    /// Items to be organized (plan event).
+    pub planned: usize,
+    /// Items moved into the library (execute event).
+    pub moved: usize,
+    /// Items restored to their original location (rollback event).
+    pub restored: usize,
+    /// Items or operations that failed.
+    pub failed: usize,
+    /// Files that could not be identified (plan event).
+    pub unknown: usize,
+    /// Titles affected by the event, e.g. "Inception (2010)".
+    pub titles: Vec<String>,
+}
+
+impl HookSummary {
+    fn new(event: HookEvent, plan: Option<&Plan>, file: Option<&Path>) -> Self {
+        Self {
+            event,
+            timestamp: Utc::now().to_rfc3339(),
+            media_type: plan.and_then(|p| p.media_type),
+            source_path: plan.map(|p| p.source_path.clone()),
+            target_path: plan.map(|p| p.target_path.clone()),
+            file: file.map(|f| f.to_path_buf()),
+            planned: 0,
+            moved: 0,
+            restored: 0,
+            failed: 0,
+            unknown: 0,
+            titles: Vec::new(),
+        }
+    }
+
+    /// Summary of a generated plan.
+    pub fn for_plan(plan: &Plan, plan_file: Option<&Path>) -> Self {
+        let pending: Vec<&PlanItem> = plan
+            .items
+            .iter()
+            .filter(|i| i.status == PlanItemStatus::Pending)
+            .collect();
+        Self {
+            planned: pending.len(),
+            unknown: plan.unknown.len(),
+            titles: titles(pending),
+            ..Self::new(HookEvent::Plan, Some(plan), plan_file)
+        }
+    }
+
+    /// Summary of an executed plan.
+    pub fn for_execution(plan: &Plan, rollback: &Rollback, plan_file: Option<&Path>) -> Self {
+        let moved = report::executed_moves(rollback);
+        let outcomes: Vec<(&PlanItem, ItemOutcome)> = plan
+            .items
+            .iter()
+            .map(|item| (item, report::item_outcome(item, Some(&moved))))
+            .collect();
+        let succeeded: Vec<&PlanItem> = outcomes
+            .iter()
+            .filter(|(_, o)| *o == ItemOutcome::Succeeded)
+            .map(|(item, _)| *item)
+            .collect();
+        Self {
+            moved: succeeded.len(),
+            failed: outcomes
+                .iter()
+                .filter(|(_, o)| *o == ItemOutcome::Failed)
+                .count(),
+            titles: titles(succeeded),
+            ..Self::new(HookEvent::Execute, Some(plan), plan_file)
+        }
+    }
+
+    /// Summary of a rollback.
+    ///
+    /// `plan` is the executed plan, if known; it is used to name the titles
+    /// whose files were moved back. Files count as restored once they are
+    /// back at their original location.
+    pub fn for_rollback(
+        rollback: &Rollback,
+        plan: Option<&Plan>,
+        failed: usize,
+        rollback_file: Option<&Path>,
+    ) -> Self {
+        let moved = report::executed_moves(rollback);
+        let restored = moved.iter().filter(|(from, _)| from.exists()).count();
+        let restored_items = plan
+            .map(|p| {
+                p.items
+                    .iter()
+                    .filter(|item| {
+                        item.operations.iter().any(|op| {
+                            op.from
+                                .as_ref()
+                                .is_some_and(|from| moved.contains(&(from.clone(), op.to.clone())))
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            restored,
+            failed,
+            titles: titles(restored_items),
+            ..Self::new(HookEvent::Rollback, plan, rollback_file)
+        }
+    }
+}
+
+/// Display titles of plan items, deduplicated in plan order.
+fn titles(items: Vec<&PlanItem>) -> Vec<String> {
+    let mut titles: Vec<String> = Vec::new();
+    for item in items {
+        let title = if let Some(ref movie) = item.movie_metadata {
+            format!("{} ({})", movie.title, movie.year)
+        } else if let Some(ref show) = item.tvshow_metadata {
+            format!("{} ({})", show.name, show.year)
+        } else {
+            continue;
+        };
+        if !titles.contains(&title) {
+            titles.push(title);
+        }
+    }
+    titles
+}
+
+/// Run the hooks that handle the summary's event.
+///
+/// Returns one error message per failed hook.
+pub async fn run_hooks(hooks: &[HookConfig], summary: &HookSummary) -> Vec<String> {
+    let mut errors = Vec::new();
+    for hook in hooks.iter().filter(|h| h.handles(summary.event)) {
+        let timeout = Duration::from_secs(hook.timeout);
+        if let Some(ref command) = hook.command {
+            if let Err(e) = run_command(command, summary, timeout).await {
+                errors.push(format!("{}: {}", command, e));
+            }
+        }
+        if let Some(ref url) = hook.url {
+            if let Err(e) = post_webhook(url, summary, timeout).await {
+                errors.push(format!("{}: {}", url, e));
+            }
+        }
+    }
+    errors
+}
+
+/// Run the hooks from the user configuration, printing any failures.
+pub async fn fire(summary: &HookSummary) {
+    let hooks = config::load_config().hooks;
+    if hooks.is_empty() {
+        return;
+    }
+    for error in run_hooks(&hooks, summary).await {
+        println!("{} {}", "[HOOK FAILED]".yellow(), error);
+    }
+}
+
+/// Run a shell command with the summary as JSON on stdin.
+async fn run_command(command: &str, summary: &HookSummary, timeout: Duration) -> Result<()> {
+    let json = serde_json::to_vec(summary)?;
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .env("MEDIA_ORGANIZER_EVENT", summary.event.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A command that ignores stdin may exit before reading it
+        let _ = stdin.write_all(&json).await;
+    }
+
+    let output = tokio::time::timeout(timeout, child.wait_with_output())
+        .await
+        .map_err(|_| crate::Error::other(format!("timed out after {}s", timeout.as_secs())))??;
+    if !output.status.success() {
+        return Err(crate::Error::other(format!(
+            "exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(())
+}
+
+/// POST the summary as JSON to a webhook URL.
+async fn post_webhook(url: &str, summary: &HookSummary, timeout: Duration) -> Result<()> {
+    let client = reqwest::Client::builder().timeout(timeout).build()?;
+    client
+        .post(url)
+        .json(summary)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
@@ -0,0 +1,662 @@
+//! SQLite storage for the central index.
+//!
+//! Stores the same data model as `central_index.json` in `central_index.db`.
+//! Entries are kept as JSON documents next to the columns used for lookups,
+//! so adding a field to `MovieEntry` or `TvShowEntry` does not need a schema
+//! change. Actors, directors and genres live in term tables to let searches
+//! run in SQL instead of rebuilding `SearchIndexes` in memory.
+
+use crate::core::indexer::SearchResults;
+use crate::core::title_match::TitleQuery;
+use crate::models::index::{
+    CentralIndex, CollectionInfo, DiskIndex, DiskInfo, MovieEntry, TvShowEntry,
+};
+use anyhow::{Context, Result};
+use rusqlite::functions::FunctionFlags;
+use rusqlite::types::Value;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Transaction};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+/// Schema migrations, applied in order. `PRAGMA user_version` records how
+/// many have been applied.
+const MIGRATIONS: &[&str] = &[
+    // 1: initial schema
+    "CREATE TABLE meta (
+        key TEXT PRIMARY KEY,
+        value TEXT NOT NULL
+    );
+    CREATE TABLE disks (
+        label TEXT PRIMARY KEY,
+        data TEXT NOT NULL
+    );
+    CREATE TABLE movies (
+        pk INTEGER PRIMARY KEY,
+        id TEXT NOT NULL,
+        disk TEXT NOT NULL,
+        title TEXT NOT NULL,
+        original_title TEXT,
+        year INTEGER,
+        tmdb_id INTEGER,
+        collection_id INTEGER,
+        country TEXT,
+        size_bytes INTEGER NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX movies_disk ON movies(disk);
+    CREATE INDEX movies_year ON movies(year);
+    CREATE INDEX movies_tmdb ON movies(tmdb_id);
+    CREATE INDEX movies_country ON movies(country);
+    CREATE TABLE tvshows (
+        pk INTEGER PRIMARY KEY,
+        id TEXT NOT NULL,
+        disk TEXT NOT NULL,
+        title TEXT NOT NULL,
+        original_title TEXT,
+        year INTEGER,
+        tmdb_id INTEGER,
+        country TEXT,
+        size_bytes INTEGER NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX tvshows_disk ON tvshows(disk);
+    CREATE INDEX tvshows_year ON tvshows(year);
+    CREATE INDEX tvshows_tmdb ON tvshows(tmdb_id);
+    CREATE INDEX tvshows_country ON tvshows(country);
+    CREATE TABLE movie_terms (
+        entry INTEGER NOT NULL REFERENCES movies(pk) ON DELETE CASCADE,
+        kind TEXT NOT NULL,
+        name TEXT NOT NULL
+    );
+    CREATE INDEX movie_terms_entry ON movie_terms(entry);
+    CREATE INDEX movie_terms_kind ON movie_terms(kind, name);
+    CREATE TABLE tvshow_terms (
+        entry INTEGER NOT NULL REFERENCES tvshows(pk) ON DELETE CASCADE,
+        kind TEXT NOT NULL,
+        name TEXT NOT NULL
+    );
+    CREATE INDEX tvshow_terms_entry ON tvshow_terms(entry);
+    CREATE INDEX tvshow_terms_kind ON tvshow_terms(kind, name);
+    CREATE TABLE collections (
+        id INTEGER PRIMARY KEY,
+        name TEXT NOT NULL,
+        data TEXT NOT NULL
+    );",
+];
+
+/// Entry counts of the stored index.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IndexTotals {
+    pub movies: usize,
+    pub tvshows: usize,
+    pub disks: usize,
+}
+
+/// Entries and disks changed in place, without a rescan.
+///
+/// Entries are matched by ID. Used to write back edits such as user data or
+/// a migration to another disk without rewriting the whole index.
+#[derive(Debug, Clone, Default)]
+pub struct IndexChanges {
+    pub movies: Vec<MovieEntry>,
+    pub tvshows: Vec<TvShowEntry>,
+    pub disks: Vec<DiskInfo>,
+    pub collections: Vec<CollectionInfo>,
+}
+
+/// Central index stored in a SQLite database.
+pub struct SqliteIndex {
+    conn: Connection,
+}
+
+impl SqliteIndex {
+    /// Open (or create) a database and apply pending migrations.
+    pub fn open(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let conn = Connection::open(path)
+            .with_context(|| format!("Failed to open index database: {}", path.display()))?;
+        Self::init(conn)
+    }
+
+    /// Open a temporary in-memory database.
+    pub fn open_in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(mut conn: Connection) -> Result<Self> {
+        conn.pragma_update(None, "foreign_keys", true)?;
+        // Unicode-aware lowercase; SQLite's lower() only folds ASCII
+        conn.create_scalar_function(
+            "casefold",
+            1,
+            FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+            |ctx| Ok(ctx.get::<Option<String>>(0)?.map(|s| s.to_lowercase())),
+        )?;
+        // Relevance of a title to a query, NULL if it does not match
+        conn.create_scalar_function(
+            "title_score",
+            3,
+            FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+            |ctx| {
+                let query = ctx.get_or_create_aux(0, |query| -> Result<TitleQuery> {
+                    Ok(TitleQuery::new(query.as_str()?))
+                })?;
+                let title: String = ctx.get(1)?;
+                let original: Option<String> = ctx.get(2)?;
+                Ok(query.score_entry(&title, original.as_deref()))
+            },
+        )?;
+        migrate(&mut conn)?;
+        Ok(Self { conn })
+    }
+
+    /// Schema version of the database.
+    pub fn schema_version(&self) -> Result<usize> {
+        let version: i64 = self
+            .conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))?;
+        Ok(version as usize)
+    }
+
+    /// Whether the database holds no disks and no entries.
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.totals()? == IndexTotals::default())
+    }
+
+    /// Number of stored movies, TV shows and disks.
+    pub fn totals(&self) -> Result<IndexTotals> {
+        let count = |table: &str| -> Result<usize> {
+            let n: i64 =
+                self.conn
+                    .query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+                        row.get(0)
+                    })?;
+            Ok(n as usize)
+        };
+        Ok(IndexTotals {
+            movies: count("movies")?,
+            tvshows: count("tvshows")?,
+            disks: count("disks")?,
+        })
+    }
+
+    /// Load the whole index, with search indexes and statistics rebuilt.
+    pub fn load(&self) -> Result<CentralIndex> {
+        let mut index = CentralIndex::default();
+        if let Some(version) = self.meta("version")? {
+            index.version = version;
+        }
+        if let Some(created_at) = self.meta("created_at")? {
+            index.created_at = created_at;
+        }
+        if let Some(updated_at) = self.meta("updated_at")? {
+            index.updated_at = updated_at;
+        }
+
+        index.disks = self
+            .documents::<DiskInfo>("SELECT data FROM disks ORDER BY label", [])?
+            .into_iter()
+            .map(|d| (d.label.clone(), d))
+            .collect();
+        index.movies = self.documents("SELECT data FROM movies ORDER BY pk", [])?;
+        index.tvshows = self.documents("SELECT data FROM tvshows ORDER BY pk", [])?;
+        index.collections = self
+            .documents::<CollectionInfo>("SELECT data FROM collections", [])?
+            .into_iter()
+            .map(|c| (c.id, c))
+            .collect();
+
+        index.rebuild_indexes();
+        index.update_statistics();
+        Ok(index)
+    }
+
+    /// Replace the whole stored index in one transaction.
+    pub fn replace_all(&mut self, index: &CentralIndex) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        tx.execute_batch(
+            "DELETE FROM movies; DELETE FROM tvshows; DELETE FROM disks; DELETE FROM collections;",
+        )?;
+        for disk in index.disks.values() {
+            put_disk(&tx, disk)?;
+        }
+        for movie in &index.movies {
+            insert_movie(&tx, movie)?;
+        }
+        for tvshow in &index.tvshows {
+            insert_tvshow(&tx, tvshow)?;
+        }
+        for collection in index.collections.values() {
+            put_collection(&tx, collection)?;
+        }
+        set_meta(&tx, "version", &index.version)?;
+        set_meta(&tx, "created_at", &index.created_at)?;
+        set_meta(&tx, "updated_at", &index.updated_at)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Merge a disk scan in one transaction.
+    ///
+    /// Same semantics as `indexer::merge_disk_into_central`: entries of the
+    /// disk are replaced only for the media types present in the scan.
+    pub fn merge_disk(&mut self, disk: &DiskIndex) -> Result<()> {
+        let label = &disk.disk.label;
+
+        // Keep watched state and personal data of the entries being replaced
+        let mut disk = disk.clone();
+        let old_movies: Vec<MovieEntry> = if disk.movies.is_empty() {
+            Vec::new()
+        } else {
+            self.documents("SELECT data FROM movies WHERE disk = ?1", params![label])?
+        };
+        let old_tvshows: Vec<TvShowEntry> = if disk.tvshows.is_empty() {
+            Vec::new()
+        } else {
+            self.documents("SELECT data FROM tvshows WHERE disk = ?1", params![label])?
+        };
+        disk.carry_user_data(&old_movies, &old_tvshows);
+
+        let tx = self.conn.transaction()?;
+
+        let existing: Option<String> = tx
+            .query_row(
+                "SELECT data FROM disks WHERE label = ?1",
+                params![label],
+                |row| row.get(0),
+            )
+            .optional()?;
+        let mut info = match existing {
+            Some(data) => {
+                let mut info: DiskInfo = serde_json::from_str(&data)?;
+                // Rebase the stored paths first; the scanned ones are already current
+                info.update_mount(&disk.disk);
+                for (media_type, path) in &disk.disk.paths {
+                    info.paths.insert(media_type.clone(), path.clone());
+                }
+                info.last_indexed = disk.disk.last_indexed.clone();
+                if disk.disk.uuid.is_some() {
+                    info.uuid = disk.disk.uuid.clone();
+                }
+                info
+            }
+            None => disk.disk.clone(),
+        };
+
+        if !disk.movies.is_empty() {
+            tx.execute("DELETE FROM movies WHERE disk = ?1", params![label])?;
+            for movie in &disk.movies {
+                insert_movie(&tx, movie)?;
+            }
+            update_collections(&tx, &disk.movies)?;
+        }
+        if !disk.tvshows.is_empty() {
+            tx.execute("DELETE FROM tvshows WHERE disk = ?1", params![label])?;
+            for tvshow in &disk.tvshows {
+                insert_tvshow(&tx, tvshow)?;
+            }
+        }
+
+        // Update disk counts in the disk info
+        let (movie_count, movie_size): (i64, i64) = tx.query_row(
+            "SELECT COUNT(*), COALESCE(SUM(size_bytes), 0) FROM movies WHERE disk = ?1",
+            params![label],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        let (tvshow_count, tvshow_size): (i64, i64) = tx.query_row(
+            "SELECT COUNT(*), COALESCE(SUM(size_bytes), 0) FROM tvshows WHERE disk = ?1",
+            params![label],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        info.movie_count = movie_count as usize;
+        info.tvshow_count = tvshow_count as usize;
+        info.total_size_bytes = (movie_size + tvshow_size) as u64;
+        put_disk(&tx, &info)?;
+
+        if get_meta(&tx, "created_at")?.is_none() {
+            let defaults = CentralIndex::default();
+            set_meta(&tx, "version", &defaults.version)?;
+            set_meta(&tx, "created_at", &defaults.created_at)?;
+        }
+        set_meta(&tx, "updated_at", &chrono::Utc::now().to_rfc3339())?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Replace the stored info of a disk.
+    pub fn update_disk(&mut self, disk: &DiskInfo) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        put_disk(&tx, disk)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Remove a disk and all of its entries in one transaction.
+    pub fn remove_disk(&mut self, label: &str) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM movies WHERE disk = ?1", params![label])?;
+        tx.execute("DELETE FROM tvshows WHERE disk = ?1", params![label])?;
+        tx.execute("DELETE FROM disks WHERE label = ?1", params![label])?;
+        set_meta(&tx, "updated_at", &chrono::Utc::now().to_rfc3339())?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Write changed entries, disks and collections in one transaction.
+    ///
+    /// Entries keep their terms; only their disk and document are updated.
+    pub fn apply_changes(&mut self, changes: &IndexChanges) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        for movie in &changes.movies {
+            tx.execute(
+                "UPDATE movies SET disk = ?1, data = ?2 WHERE id = ?3",
+                params![movie.disk, serde_json::to_string(movie)?, movie.id],
+            )?;
+        }
+        for tvshow in &changes.tvshows {
+            tx.execute(
+                "UPDATE tvshows SET disk = ?1, data = ?2 WHERE id = ?3",
+                params![tvshow.disk, serde_json::to_string(tvshow)?, tvshow.id],
+            )?;
+        }
+        for disk in &changes.disks {
+            put_disk(&tx, disk)?;
+        }
+        for collection in &changes.collections {
+            put_collection(&tx, collection)?;
+        }
+        set_meta(&tx, "updated_at", &chrono::Utc::now().to_rfc3339())?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Search the stored index. Arguments match `indexer::search`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn search(
+        &self,
+        title: Option<&str>,
+        actor: Option<&str>,
+        director: Option<&str>,
+        collection: Option<&str>,
+        year: Option<u16>,
+        year_range: Option<(u16, u16)>,
+        genre: Option<&str>,
+        country: Option<&str>,
+    ) -> Result<SearchResults> {
+        let mut movies = Filter::default();
+        let mut tvshows = Filter::default();
+
+        if let Some(actor) = actor {
+            movies.term("movie_terms", "actor", actor);
+            tvshows.term("tvshow_terms", "actor", actor);
+        }
+        if let Some(director) = director {
+            movies.term("movie_terms", "director", director);
+        }
+        if let Some(genre) = genre {
+            movies.term("movie_terms", "genre", genre);
+            tvshows.term("tvshow_terms", "genre", genre);
+        }
+        if let Some(country) = country {
+            let code = Value::Text(country.to_uppercase());
+            movies.push("country = ?", vec![code.clone()]);
+            tvshows.push("country = ?", vec![code]);
+        }
+        if let Some(y) = year {
+            movies.push("year = ?", vec![Value::Integer(y as i64)]);
+            tvshows.push("year = ?", vec![Value::Integer(y as i64)]);
+        } else if let Some((start, end)) = year_range {
+            let range = vec![Value::Integer(start as i64), Value::Integer(end as i64)];
+            movies.push("year BETWEEN ? AND ?", range.clone());
+            tvshows.push("year BETWEEN ? AND ?", range);
+        }
+        movies.title = title.map(str::to_string);
+        tvshows.title = title.map(str::to_string);
+
+        let collections = match collection {
+            Some(name) => self.documents(
+                "SELECT data FROM collections WHERE instr(casefold(name), ?1) > 0 ORDER BY id",
+                params![name.to_lowercase()],
+            )?,
+            None => Vec::new(),
+        };
+
+        Ok(SearchResults {
+            movies: self.documents(&movies.sql("movies"), params_from_iter(movies.params()))?,
+            tvshows: self.documents(&tvshows.sql("tvshows"), params_from_iter(tvshows.params()))?,
+            collections,
+        })
+    }
+
+    fn meta(&self, key: &str) -> Result<Option<String>> {
+        get_meta(&self.conn, key)
+    }
+
+    /// Run a query selecting one JSON `data` column and decode each row.
+    fn documents<T: serde::de::DeserializeOwned>(
+        &self,
+        sql: &str,
+        params: impl rusqlite::Params,
+    ) -> Result<Vec<T>> {
+        let mut stmt = self.conn.prepare(sql)?;
+        let rows = stmt.query_map(params, |row| row.get::<_, String>(0))?;
+        let mut documents = Vec::new();
+        for data in rows {
+            documents.push(serde_json::from_str(&data?).context("Corrupt index entry")?);
+        }
+        Ok(documents)
+    }
+}
+
+/// WHERE clauses for an entry search.
+#[derive(Default)]
+struct Filter {
+    clauses: Vec<String>,
+    params: Vec<Value>,
+    /// Title query; matches are ranked by relevance.
+    title: Option<String>,
+}
+
+impl Filter {
+    fn push(&mut self, clause: &str, params: Vec<Value>) {
+        self.clauses.push(clause.to_string());
+        self.params.extend(params);
+    }
+
+    /// Match entries having an actor, director or genre containing `name`.
+    fn term(&mut self, table: &str, kind: &str, name: &str) {
+        self.push(
+            &format!(
+                "pk IN (SELECT entry FROM {} WHERE kind = ? AND instr(casefold(name), ?) > 0)",
+                table
+            ),
+            vec![
+                Value::Text(kind.to_string()),
+                Value::Text(name.to_lowercase()),
+            ],
+        );
+    }
+
+    /// Query for the matching entries: most relevant first for a title
+    /// query, otherwise newest first.
+    fn sql(&self, table: &str) -> String {
+        let mut sql = match self.title {
+            Some(_) => format!(
+                "SELECT data FROM (SELECT data, pk, year, \
+                 title_score(?, title, original_title) AS score FROM {}",
+                table
+            ),
+            None => format!("SELECT data FROM {}", table),
+        };
+        if !self.clauses.is_empty() {
+            sql.push_str(" WHERE ");
+            sql.push_str(&self.clauses.join(" AND "));
+        }
+        match self.title {
+            Some(_) => sql.push_str(") WHERE score IS NOT NULL ORDER BY score DESC, year DESC, pk"),
+            None => sql.push_str(" ORDER BY year DESC, pk"),
+        }
+        sql
+    }
+
+    fn params(self) -> Vec<Value> {
+        self.title
+            .map(Value::Text)
+            .into_iter()
+            .chain(self.params)
+            .collect()
+    }
+}
+
+fn migrate(conn: &mut Connection) -> Result<()> {
+    let version: i64 = conn.query_row("PRAGMA user_version", [], |row| row.get(0))?;
+    for (i, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        let tx = conn.transaction()?;
+        tx.execute_batch(migration)
+            .with_context(|| format!("Index database migration {} failed", i + 1))?;
+        tx.pragma_update(None, "user_version", (i + 1) as i64)?;
+        tx.commit()?;
+        tracing::info!("Index database migrated to schema {}", i + 1);
+    }
+    Ok(())
+}
+
+fn get_meta(conn: &Connection, key: &str) -> Result<Option<String>> {
+    Ok(conn
+        .query_row(
+            "SELECT value FROM meta WHERE key = ?1",
+            params![key],
+            |row| row.get(0),
+        )
+        .optional()?)
+}
+
+fn set_meta(tx: &Transaction, key: &str, value: &str) -> Result<()> {
+    tx.execute(
+        "INSERT INTO meta (key, value) VALUES (?1, ?2)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        params![key, value],
+    )?;
+    Ok(())
+}
+
+fn put_disk(tx: &Transaction, disk: &DiskInfo) -> Result<()> {
+    tx.execute(
+        "INSERT INTO disks (label, data) VALUES (?1, ?2)
+         ON CONFLICT(label) DO UPDATE SET data = excluded.data",
+        params![disk.label, serde_json::to_string(disk)?],
+    )?;
+    Ok(())
+}
+
+fn put_collection(tx: &Transaction, collection: &CollectionInfo) -> Result<()> {
+    tx.execute(
+        "INSERT INTO collections (id, name, data) VALUES (?1, ?2, ?3)
+         ON CONFLICT(id) DO UPDATE SET name = excluded.name, data = excluded.data",
+        params![
+            collection.id as i64,
+            collection.name,
+            serde_json::to_string(collection)?
+        ],
+    )?;
+    Ok(())
+}
+
+fn insert_terms(
+    tx: &Transaction,
+    table: &str,
+    entry: i64,
+    kind: &str,
+    names: &[String],
+) -> Result<()> {
+    let mut stmt = tx.prepare_cached(&format!(
+        "INSERT INTO {} (entry, kind, name) VALUES (?1, ?2, ?3)",
+        table
+    ))?;
+    for name in names.iter().collect::<BTreeSet<_>>() {
+        stmt.execute(params![entry, kind, name])?;
+    }
+    Ok(())
+}
+
+fn insert_movie(tx: &Transaction, movie: &MovieEntry) -> Result<()> {
+    tx.execute(
+        "INSERT INTO movies (id, disk, title, original_title, year, tmdb_id, collection_id,
+                             country, size_bytes, data)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+        params![
+            movie.id,
+            movie.disk,
+            movie.title,
+            movie.original_title,
+            movie.year,
+            movie.tmdb_id.map(|id| id as i64),
+            movie.collection_id.map(|id| id as i64),
+            movie.country,
+            movie.size_bytes as i64,
+            serde_json::to_string(movie)?,
+        ],
+    )?;
+    let pk = tx.last_insert_rowid();
+    insert_terms(tx, "movie_terms", pk, "actor", &movie.actors)?;
+    insert_terms(tx, "movie_terms", pk, "director", &movie.directors)?;
+    insert_terms(tx, "movie_terms", pk, "genre", &movie.genres)?;
+    Ok(())
+}
+
+fn insert_tvshow(tx: &Transaction, tvshow: &TvShowEntry) -> Result<()> {
+    tx.execute(
+        "INSERT INTO tvshows (id, disk, title, original_title, year, tmdb_id, country,
+                              size_bytes, data)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            tvshow.id,
+            tvshow.disk,
+            tvshow.title,
+            tvshow.original_title,
+            tvshow.year,
+            tvshow.tmdb_id.map(|id| id as i64),
+            tvshow.country,
+            tvshow.size_bytes as i64,
+            serde_json::to_string(tvshow)?,
+        ],
+    )?;
+    let pk = tx.last_insert_rowid();
+    insert_terms(tx, "tvshow_terms", pk, "actor", &tvshow.actors)?;
+    insert_terms(tx, "tvshow_terms", pk, "genre", &tvshow.genres)?;
+    Ok(())
+}
+
+/// Record newly indexed movies in their collections.
+fn update_collections(tx: &Transaction, movies: &[MovieEntry]) -> Result<()> {
+    let mut touched: HashMap<u64, CollectionInfo> = HashMap::new();
+    for movie in movies {
+        let Some(id) = movie.collection_id else {
+            continue;
+        };
+        let collection = match touched.entry(id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let existing: Option<String> = tx
+                    .query_row(
+                        "SELECT data FROM collections WHERE id = ?1",
+                        params![id as i64],
+                        |row| row.get(0),
+                    )
+                    .optional()?;
+                entry.insert(match existing {
+                    Some(data) => serde_json::from_str(&data)?,
+                    None => CollectionInfo::for_movie(id, movie),
+                })
+            }
+        };
+        collection.add_owned(movie);
+    }
+    for collection in touched.values() {
+        put_collection(tx, collection)?;
+    }
+    Ok(())
+}
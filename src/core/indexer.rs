@@ -1,7 +1,21 @@
 //! Central index management - scanning, building, and searching.
 
-use crate::models::index::{CentralIndex, CollectionInfo, DiskIndex, MovieEntry, TvShowEntry};
+use crate::core::index_store::{IndexChanges, IndexTotals, SqliteIndex};
+use crate::core::migration::{self, SchemaKind};
+use crate::core::parser;
+use crate::core::title_match::{rank_by_relevance, TitleQuery};
+use crate::models::config::{self, IndexBackend};
+use crate::models::index::{
+    CentralIndex, CollectionInfo, DiskIndex, DiskInfo, EpisodeFile, MovieEntry, ScanStamp,
+    TvShowEntry, UserData,
+};
+use crate::models::media::MediaType;
+use crate::services::ffprobe;
+use crate::services::tmdb::{MovieDetails, TmdbClient, TvDetails};
 use anyhow::{Context, Result};
+use schemars::JsonSchema;
+use serde::Serialize;
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
@@ -24,22 +38,48 @@ pub fn disk_indexes_dir() -> Result<PathBuf> {
     Ok(config_dir()?.join("disk_indexes"))
 }
 
-/// Load central index from disk.
+/// Path to the SQLite central index.
+pub fn central_db_path() -> Result<PathBuf> {
+    Ok(config_dir()?.join("central_index.db"))
+}
+
+/// Central index backend selected in the configuration.
+pub fn index_backend() -> IndexBackend {
+    config::load_config().index.backend
+}
+
+/// Load central index from the configured backend.
 pub fn load_central_index() -> Result<CentralIndex> {
+    match index_backend() {
+        IndexBackend::Json => load_central_json(),
+        IndexBackend::Sqlite => open_central_db()?.load(),
+    }
+}
+
+/// Save central index to the configured backend.
+pub fn save_central_index(index: &CentralIndex) -> Result<()> {
+    match index_backend() {
+        IndexBackend::Json => save_central_json(index),
+        IndexBackend::Sqlite => open_central_db()?.replace_all(index),
+    }
+}
+
+/// Load central index from `central_index.json`.
+pub fn load_central_json() -> Result<CentralIndex> {
     let path = central_index_path()?;
     if path.exists() {
         let content = fs::read_to_string(&path)
             .with_context(|| format!("Failed to read central index: {}", path.display()))?;
-        let index: CentralIndex =
-            serde_json::from_str(&content).with_context(|| "Failed to parse central index")?;
+        let index: CentralIndex = migration::load_json(SchemaKind::CentralIndex, &content)
+            .with_context(|| "Failed to parse central index")?;
         Ok(index)
     } else {
         Ok(CentralIndex::default())
     }
 }
 
-/// Save central index to disk.
-pub fn save_central_index(index: &CentralIndex) -> Result<()> {
+/// Save central index to `central_index.json`.
+pub fn save_central_json(index: &CentralIndex) -> Result<()> {
     let path = central_index_path()?;
     if let Some(parent) = path.parent() {
         fs::create_dir_all(parent)?;
@@ -58,12 +98,103 @@ pub fn save_central_index(index: &CentralIndex) -> Result<()> {
     Ok(())
 }
 
+/// Open the SQLite central index.
+///
+/// A new database is populated from the JSON indexes, if any.
+pub fn open_central_db() -> Result<SqliteIndex> {
+    let path = central_db_path()?;
+    let fresh = !path.exists();
+    let mut store = SqliteIndex::open(&path)?;
+    if fresh {
+        import_json_indexes(&mut store)?;
+    }
+    Ok(store)
+}
+
+/// Import the JSON indexes into a database.
+///
+/// Uses `central_index.json` if present, otherwise merges the per-disk
+/// indexes one disk at a time. Returns the number of disks imported.
+pub fn import_json_indexes(store: &mut SqliteIndex) -> Result<usize> {
+    if central_index_path()?.exists() {
+        let central = load_central_json()?;
+        store.replace_all(&central)?;
+        tracing::info!("Imported central_index.json into the index database");
+        return Ok(central.disks.len());
+    }
+
+    let dir = disk_indexes_dir()?;
+    if !dir.exists() {
+        return Ok(0);
+    }
+    let mut imported = 0;
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let content = fs::read_to_string(&path)?;
+        let disk: DiskIndex = migration::load_json(SchemaKind::DiskIndex, &content)
+            .with_context(|| format!("Failed to parse disk index: {}", path.display()))?;
+        store.merge_disk(&disk)?;
+        imported += 1;
+    }
+    tracing::info!("Imported {} disk indexes into the index database", imported);
+    Ok(imported)
+}
+
+/// Merge a disk scan into the central index of the configured backend.
+pub fn store_disk_in_central(disk: DiskIndex) -> Result<IndexTotals> {
+    match index_backend() {
+        IndexBackend::Json => {
+            let mut central = load_central_json()?;
+            merge_disk_into_central(&mut central, disk);
+            save_central_json(&central)?;
+            Ok(IndexTotals {
+                movies: central.statistics.total_movies,
+                tvshows: central.statistics.total_tvshows,
+                disks: central.statistics.total_disks,
+            })
+        }
+        IndexBackend::Sqlite => {
+            let mut store = open_central_db()?;
+            store.merge_disk(&disk)?;
+            store.totals()
+        }
+    }
+}
+
+/// Copy the central index to another backend.
+///
+/// Converting to SQLite replaces the database contents; converting to JSON
+/// rewrites `central_index.json` (keeping a backup).
+pub fn convert_central_index(to: IndexBackend) -> Result<IndexTotals> {
+    let index = match to {
+        IndexBackend::Sqlite => {
+            let index = load_central_json()?;
+            SqliteIndex::open(&central_db_path()?)?.replace_all(&index)?;
+            index
+        }
+        IndexBackend::Json => {
+            let index = SqliteIndex::open(&central_db_path()?)?.load()?;
+            save_central_json(&index)?;
+            index
+        }
+    };
+    Ok(IndexTotals {
+        movies: index.movies.len(),
+        tvshows: index.tvshows.len(),
+        disks: index.disks.len(),
+    })
+}
+
 /// Load disk index for a specific disk.
 pub fn load_disk_index(disk_label: &str) -> Result<Option<DiskIndex>> {
     let path = disk_indexes_dir()?.join(format!("{}.json", disk_label));
     if path.exists() {
         let content = fs::read_to_string(&path)?;
-        let index: DiskIndex = serde_json::from_str(&content)?;
+        let index: DiskIndex = migration::load_json(SchemaKind::DiskIndex, &content)
+            .with_context(|| format!("Failed to parse disk index: {}", path.display()))?;
         Ok(Some(index))
     } else {
         Ok(None)
@@ -119,25 +250,16 @@ pub fn detect_disk_label(path: &Path) -> Option<String> {
         .map(|s| s.to_string())
 }
 
-/// Get disk UUID using lsblk command.
+/// Get disk UUID using blkid on the device holding `path`.
 pub fn get_disk_uuid(path: &Path) -> Option<String> {
-    // Try to get UUID using df and blkid
-    let output = std::process::Command::new("df").arg(path).output().ok()?;
-
-    let df_output = String::from_utf8_lossy(&output.stdout);
-    let lines: Vec<&str> = df_output.lines().collect();
-    if lines.len() < 2 {
-        return None;
-    }
-
-    let device = lines[1].split_whitespace().next()?;
+    let device = mount_info(path)?.device;
 
     let blkid_output = std::process::Command::new("blkid")
         .arg("-s")
         .arg("UUID")
         .arg("-o")
         .arg("value")
-        .arg(device)
+        .arg(&device)
         .output()
         .ok()?;
 
@@ -152,22 +274,169 @@ pub fn get_disk_uuid(path: &Path) -> Option<String> {
     }
 }
 
-/// Check if a disk is currently mounted/online.
-pub fn is_disk_online(disk_label: &str) -> bool {
+/// Device, filesystem and space of a mounted filesystem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MountInfo {
+    /// Device (e.g., "/dev/sdb1")
+    pub device: String,
+    /// Filesystem type (e.g., "ext4")
+    pub filesystem: String,
+    /// Capacity in bytes
+    pub capacity_bytes: u64,
+    /// Free space in bytes
+    pub free_bytes: u64,
+    /// Mount point
+    pub mount_point: PathBuf,
+}
+
+/// Mount details of the filesystem holding `path`, from `df`.
+pub fn mount_info(path: &Path) -> Option<MountInfo> {
+    let output = std::process::Command::new("df")
+        .args(["-P", "-T", "-B1"])
+        .arg(path)
+        .output()
+        .ok()?;
+    parse_df_output(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse the output of `df -P -T -B1` for a single path.
+pub fn parse_df_output(output: &str) -> Option<MountInfo> {
+    // Filesystem Type 1-blocks Used Available Capacity Mounted on
+    let line = output.lines().nth(1)?;
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() < 7 {
+        return None;
+    }
+    Some(MountInfo {
+        device: fields[0].to_string(),
+        filesystem: fields[1].to_string(),
+        capacity_bytes: fields[2].parse().ok()?,
+        free_bytes: fields[4].parse().ok()?,
+        mount_point: PathBuf::from(fields[6..].join(" ")),
+    })
+}
+
+/// Record a sighting of a disk: its space, filesystem, mount point and time.
+///
+/// Indexed paths under the previous mount point are moved to the new one.
+pub fn record_mount(disk: &mut DiskInfo, mount: &MountInfo) {
+    let mount_point = mount.mount_point.to_string_lossy().to_string();
+    disk.rebase_paths(&mount_point);
+    disk.capacity_bytes = Some(mount.capacity_bytes);
+    disk.free_bytes = Some(mount.free_bytes);
+    disk.filesystem = Some(mount.filesystem.clone());
+    disk.mount_point = Some(mount_point);
+    disk.last_seen = Some(chrono::Utc::now().to_rfc3339());
+}
+
+/// Find where a disk is mounted.
+///
+/// The disk is looked up by UUID first, wherever it is mounted, then by
+/// label under the common mount points.
+pub fn locate_disk(disk_label: &str, disk_uuid: Option<&str>) -> Option<PathBuf> {
+    if let Some(uuid) = disk_uuid {
+        let mount = fs::canonicalize(Path::new("/dev/disk/by-uuid").join(uuid))
+            .ok()
+            .and_then(|device| {
+                let mounts = fs::read_to_string("/proc/mounts").ok()?;
+                mount_point_of_device(&mounts, &device)
+            });
+        if mount.is_some() {
+            return mount;
+        }
+    }
+
     // Check common mount points
     let paths = [
         format!("/run/media/{}/{}", whoami::username(), disk_label),
         format!("/media/{}/{}", whoami::username(), disk_label),
         format!("/mnt/{}", disk_label),
     ];
+    paths.into_iter().map(PathBuf::from).find(|p| p.exists())
+}
 
-    for path in &paths {
-        if Path::new(path).exists() {
-            return true;
+/// Mount point of a device, from the contents of `/proc/mounts`.
+pub fn mount_point_of_device(mounts: &str, device: &Path) -> Option<PathBuf> {
+    mounts.lines().find_map(|line| {
+        let mut fields = line.split_whitespace();
+        let source = fields.next()?;
+        let target = fields.next()?;
+        // Sources may be symlinks such as /dev/disk/by-uuid/...
+        let source = fs::canonicalize(source).unwrap_or_else(|_| PathBuf::from(source));
+        (source == device).then(|| PathBuf::from(unescape_mount_path(target)))
+    })
+}
+
+/// Undo the octal escapes `/proc/mounts` uses for whitespace and backslashes.
+fn unescape_mount_path(path: &str) -> String {
+    path.replace("\\040", " ")
+        .replace("\\011", "\t")
+        .replace("\\012", "\n")
+        .replace("\\134", "\\")
+}
+
+/// Check if a disk is currently mounted/online.
+pub fn is_disk_online(disk_label: &str, disk_uuid: Option<&str>) -> bool {
+    locate_disk(disk_label, disk_uuid).is_some()
+}
+
+/// Indexed paths of a disk, moved under the mount point it has now.
+pub fn current_disk_paths(disk: &DiskInfo, mount: &Path) -> Vec<PathBuf> {
+    disk.paths
+        .values()
+        .chain(std::iter::once(&disk.base_path))
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            let path = Path::new(p);
+            disk.mount_point
+                .as_deref()
+                .and_then(|old| path.strip_prefix(old).ok())
+                .map(|relative| mount.join(relative))
+                .unwrap_or_else(|| path.to_path_buf())
+        })
+        .collect()
+}
+
+/// Save updated disk infos in the central index of the configured backend.
+pub fn update_disks_in_central(disks: &[DiskInfo]) -> Result<()> {
+    match index_backend() {
+        IndexBackend::Json => {
+            let mut central = load_central_json()?;
+            for disk in disks {
+                central.disks.insert(disk.label.clone(), disk.clone());
+            }
+            save_central_json(&central)
         }
+        IndexBackend::Sqlite => {
+            let mut store = open_central_db()?;
+            for disk in disks {
+                store.update_disk(disk)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Save in-place changes to the central index of the configured backend.
+///
+/// `index` must already contain the changes; the JSON backend saves it
+/// whole, while the SQLite backend only writes what changed.
+pub fn save_central_changes(index: &CentralIndex, changes: &IndexChanges) -> Result<()> {
+    match index_backend() {
+        IndexBackend::Json => save_central_json(index),
+        IndexBackend::Sqlite => open_central_db()?.apply_changes(changes),
     }
+}
 
-    false
+/// Remove a disk from the central index of the configured backend.
+///
+/// `index` must already have the disk removed; the JSON backend saves it
+/// whole, while the SQLite backend deletes the disk's rows.
+pub fn remove_disk_from_central(index: &CentralIndex, label: &str) -> Result<()> {
+    match index_backend() {
+        IndexBackend::Json => save_central_json(index),
+        IndexBackend::Sqlite => open_central_db()?.remove_disk(label),
+    }
 }
 
 /// Scan a directory for NFO files and build index entries.
@@ -177,6 +446,74 @@ pub fn scan_directory(
     disk_uuid: Option<String>,
     media_type: &str,
 ) -> Result<DiskIndex> {
+    scan_directory_incremental(path, disk_label, disk_uuid, media_type, None)
+        .map(|(index, _)| index)
+}
+
+/// What an incremental scan found compared with the previous scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ScanChanges {
+    /// Entries found for the first time
+    pub added: usize,
+    /// Entries whose NFO or video files changed
+    pub updated: usize,
+    /// Entries found under a new path (same TMDB ID)
+    pub moved: usize,
+    /// Entries whose folder is gone
+    pub removed: usize,
+    /// Entries reused without parsing
+    pub unchanged: usize,
+}
+
+/// The entries of a previous scan of `path`, taken from the central index.
+///
+/// Returns None when the disk's `media_type` was indexed from another path,
+/// since relative paths would not match.
+pub fn previous_scan(
+    central: &CentralIndex,
+    disk_label: &str,
+    media_type: &str,
+    path: &Path,
+) -> Option<DiskIndex> {
+    let disk = central.disks.get(disk_label)?;
+    if disk.paths.get(media_type).map(PathBuf::from).as_deref() != Some(path) {
+        return None;
+    }
+    let mut previous = DiskIndex {
+        disk: disk.clone(),
+        ..Default::default()
+    };
+    if media_type == "movies" {
+        previous.movies = central
+            .movies
+            .iter()
+            .filter(|m| m.disk == disk_label)
+            .cloned()
+            .collect();
+    } else {
+        previous.tvshows = central
+            .tvshows
+            .iter()
+            .filter(|t| t.disk == disk_label)
+            .cloned()
+            .collect();
+    }
+    Some(previous)
+}
+
+/// Scan a directory, reusing entries of a previous scan that have not changed.
+///
+/// An entry is reused when its NFO and video files have the same
+/// modification times and sizes as when it was indexed. Entries under a new
+/// path with the TMDB ID of a vanished one are counted as moved and keep
+/// their ID. Without `previous`, every NFO is parsed.
+pub fn scan_directory_incremental(
+    path: &Path,
+    disk_label: &str,
+    disk_uuid: Option<String>,
+    media_type: &str,
+    previous: Option<&DiskIndex>,
+) -> Result<(DiskIndex, ScanChanges)> {
     tracing::info!("Scanning directory: {}", path.display());
 
     let mut index = DiskIndex::default();
@@ -197,39 +534,152 @@ pub fn scan_directory(
         "tvshow.nfo"
     };
 
-    let mut total_size: u64 = 0;
+    let mut previous_movies: HashMap<&str, &MovieEntry> = previous
+        .map(|p| {
+            p.movies
+                .iter()
+                .map(|m| (m.relative_path.as_str(), m))
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut previous_tvshows: HashMap<&str, &TvShowEntry> = previous
+        .map(|p| {
+            p.tvshows
+                .iter()
+                .map(|t| (t.relative_path.as_str(), t))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut changes = ScanChanges::default();
+    let mut new_movies = Vec::new();
+    let mut new_tvshows = Vec::new();
 
-    for entry in WalkDir::new(path)
+    // NFO files first, then media folders recognized by their names
+    let library_type = if media_type == "movies" {
+        MediaType::Movies
+    } else {
+        MediaType::TvShows
+    };
+    let mut sources: Vec<EntrySource> = WalkDir::new(path)
         .follow_links(true)
         .into_iter()
         .filter_map(|e| e.ok())
-    {
-        let entry_path = entry.path();
-
-        if entry_path.is_file() {
-            if let Some(filename) = entry_path.file_name() {
-                if filename == nfo_pattern {
-                    match parse_nfo_file(entry_path, disk_label, &index.disk.uuid, path) {
-                        Ok(ParsedNfo::Movie(movie)) => {
-                            total_size += movie.size_bytes;
-                            index.movies.push(movie);
-                        }
-                        Ok(ParsedNfo::TvShow(tvshow)) => {
-                            total_size += tvshow.size_bytes;
-                            index.tvshows.push(tvshow);
-                        }
-                        Err(e) => {
-                            tracing::warn!("Failed to parse NFO {}: {}", entry_path.display(), e);
-                        }
+        .filter(|e| e.path().is_file() && e.file_name() == nfo_pattern)
+        .map(|e| EntrySource::Nfo(e.into_path()))
+        .collect();
+    sources.extend(
+        find_folders_without_nfo(path, nfo_pattern, library_type)
+            .into_iter()
+            .map(|(dir, info)| EntrySource::Folder(dir, info)),
+    );
+
+    for source in &sources {
+        let stamp = scan_stamp(source.dir(), source.nfo());
+        let relative_path = relative_dir(source.dir(), path);
+        let old_movie = previous_movies.remove(relative_path.as_str());
+        let old_tvshow = previous_tvshows.remove(relative_path.as_str());
+
+        // Reuse entries whose files have not changed
+        if stamp.is_some() {
+            if let Some(old) = old_movie.filter(|m| m.scan_stamp == stamp) {
+                changes.unchanged += 1;
+                index.movies.push(old.clone());
+                continue;
+            }
+            if let Some(old) = old_tvshow.filter(|t| t.scan_stamp == stamp) {
+                changes.unchanged += 1;
+                index.tvshows.push(old.clone());
+                continue;
+            }
+        }
+
+        let parsed = match source {
+            EntrySource::Nfo(nfo_path) => {
+                parse_nfo_file(nfo_path, disk_label, &index.disk.uuid, path)
+            }
+            EntrySource::Folder(dir, info) => Ok(parse_library_folder_entry(
+                dir,
+                info,
+                library_type,
+                disk_label,
+                &index.disk.uuid,
+                path,
+            )),
+        };
+        match parsed {
+            Ok(ParsedNfo::Movie(mut movie)) => {
+                movie.scan_stamp = stamp;
+                match old_movie {
+                    Some(old) => {
+                        movie.id = old.id.clone();
+                        changes.updated += 1;
                     }
+                    None => new_movies.push(index.movies.len()),
                 }
+                index.movies.push(movie);
             }
+            Ok(ParsedNfo::TvShow(mut tvshow)) => {
+                tvshow.scan_stamp = stamp;
+                match old_tvshow {
+                    Some(old) => {
+                        tvshow.id = old.id.clone();
+                        changes.updated += 1;
+                    }
+                    None => new_tvshows.push(index.tvshows.len()),
+                }
+                index.tvshows.push(tvshow);
+            }
+            Err(e) => {
+                tracing::warn!("Failed to parse NFO in {}: {}", source.dir().display(), e);
+                // Keep the previous entry rather than dropping it; its stale
+                // stamp makes the next scan try the NFO again
+                if let Some(old) = old_movie {
+                    changes.unchanged += 1;
+                    index.movies.push(old.clone());
+                } else if let Some(old) = old_tvshow {
+                    changes.unchanged += 1;
+                    index.tvshows.push(old.clone());
+                }
+            }
+        }
+    }
+
+    // Entries left over are gone, unless they reappear under a new path
+    let mut gone_movies: Vec<&MovieEntry> = previous_movies.into_values().collect();
+    for &i in &new_movies {
+        let movie = &mut index.movies[i];
+        let moved = gone_movies
+            .iter()
+            .position(|old| old.tmdb_id.is_some() && old.tmdb_id == movie.tmdb_id);
+        match moved {
+            Some(pos) => {
+                movie.id = gone_movies.swap_remove(pos).id.clone();
+                changes.moved += 1;
+            }
+            None => changes.added += 1,
+        }
+    }
+    let mut gone_tvshows: Vec<&TvShowEntry> = previous_tvshows.into_values().collect();
+    for &i in &new_tvshows {
+        let tvshow = &mut index.tvshows[i];
+        let moved = gone_tvshows
+            .iter()
+            .position(|old| old.tmdb_id.is_some() && old.tmdb_id == tvshow.tmdb_id);
+        match moved {
+            Some(pos) => {
+                tvshow.id = gone_tvshows.swap_remove(pos).id.clone();
+                changes.moved += 1;
+            }
+            None => changes.added += 1,
         }
     }
+    changes.removed = gone_movies.len() + gone_tvshows.len();
 
     index.disk.movie_count = index.movies.len();
     index.disk.tvshow_count = index.tvshows.len();
-    index.disk.total_size_bytes = total_size;
+    index.disk.total_size_bytes = index.movies.iter().map(|m| m.size_bytes).sum::<u64>()
+        + index.tvshows.iter().map(|t| t.size_bytes).sum::<u64>();
 
     tracing::info!(
         "Scan complete: {} movies, {} TV shows",
@@ -237,7 +687,382 @@ pub fn scan_directory(
         index.tvshows.len()
     );
 
-    Ok(index)
+    Ok((index, changes))
+}
+
+/// Path of a directory relative to the scanned path.
+fn relative_dir(dir: &Path, base_path: &Path) -> String {
+    dir.strip_prefix(base_path)
+        .unwrap_or(dir)
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Modification times and sizes of an entry's NFO and video files.
+fn scan_stamp(dir: &Path, nfo_path: Option<&Path>) -> Option<ScanStamp> {
+    let mut stamp = ScanStamp::default();
+    if let Some(nfo_path) = nfo_path {
+        let nfo = fs::metadata(nfo_path).ok()?;
+        stamp.nfo_modified = unix_mtime(&nfo);
+        stamp.nfo_size = nfo.len();
+    }
+    for entry in WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file() && is_video_file(e.path()))
+    {
+        let Ok(metadata) = fs::metadata(entry.path()) else {
+            continue;
+        };
+        stamp.video_count += 1;
+        stamp.videos_size += metadata.len();
+        stamp.videos_modified = stamp.videos_modified.max(unix_mtime(&metadata));
+    }
+    Some(stamp)
+}
+
+fn unix_mtime(metadata: &fs::Metadata) -> i64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|d| d.as_nanos() as i64)
+        .unwrap_or(0)
+}
+
+/// Where the metadata of an entry comes from.
+enum EntrySource {
+    /// A `movie.nfo` or `tvshow.nfo` file
+    Nfo(PathBuf),
+    /// A folder without NFO, recognized by its name
+    Folder(PathBuf, parser::LibraryFolderInfo),
+}
+
+impl EntrySource {
+    fn dir(&self) -> &Path {
+        match self {
+            EntrySource::Nfo(nfo_path) => nfo_path.parent().unwrap_or(nfo_path),
+            EntrySource::Folder(dir, _) => dir,
+        }
+    }
+
+    fn nfo(&self) -> Option<&Path> {
+        match self {
+            EntrySource::Nfo(nfo_path) => Some(nfo_path),
+            EntrySource::Folder(..) => None,
+        }
+    }
+}
+
+/// Find media folders without an NFO, recognized by their names.
+///
+/// Movie folders must hold a video file directly, TV show folders anywhere
+/// below. Folders with an NFO, and everything below a recognized folder,
+/// are left out.
+fn find_folders_without_nfo(
+    path: &Path,
+    nfo_pattern: &str,
+    media_type: MediaType,
+) -> Vec<(PathBuf, parser::LibraryFolderInfo)> {
+    let mut found = Vec::new();
+    let mut walker = WalkDir::new(path)
+        .follow_links(true)
+        .min_depth(1)
+        .into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        let dir = entry.path();
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+        if dir.join(nfo_pattern).is_file() {
+            walker.skip_current_dir();
+            continue;
+        }
+        let Some(info) = dir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| parser::parse_library_folder(n, media_type))
+        else {
+            continue;
+        };
+
+        let max_depth = if media_type == MediaType::Movies {
+            1
+        } else {
+            usize::MAX
+        };
+        let has_videos = WalkDir::new(dir)
+            .max_depth(max_depth)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .any(|e| e.path().is_file() && is_video_file(e.path()));
+        if has_videos {
+            found.push((dir.to_path_buf(), info));
+            walker.skip_current_dir();
+        }
+    }
+    found
+}
+
+/// Build an entry for a folder without NFO from its name and video files.
+fn parse_library_folder_entry(
+    dir: &Path,
+    info: &parser::LibraryFolderInfo,
+    media_type: MediaType,
+    disk_label: &str,
+    disk_uuid: &Option<String>,
+    base_path: &Path,
+) -> ParsedNfo {
+    let relative_path = relative_dir(dir, base_path);
+    let size_bytes = calculate_directory_video_size(dir);
+
+    match media_type {
+        MediaType::Movies => {
+            let mut movie = MovieEntry {
+                id: uuid::Uuid::new_v4().to_string(),
+                disk: disk_label.to_string(),
+                disk_uuid: disk_uuid.clone(),
+                relative_path,
+                title: info.title.clone(),
+                original_title: info.original_title.clone(),
+                year: info.year,
+                tmdb_id: info.tmdb_id,
+                imdb_id: info.imdb_id.clone(),
+                collection_id: None,
+                collection_name: None,
+                collection_total_movies: None,
+                country: None,
+                genres: Vec::new(),
+                actors: Vec::new(),
+                directors: Vec::new(),
+                runtime: None,
+                rating: None,
+                size_bytes,
+                resolution: None,
+                video_codec: None,
+                hdr: None,
+                bitrate_kbps: None,
+                indexed_at: chrono::Utc::now().to_rfc3339(),
+                scan_stamp: None,
+                user_data: Default::default(),
+            };
+            detect_video_quality(&mut movie, dir, "");
+            ParsedNfo::Movie(movie)
+        }
+        MediaType::TvShows => {
+            let episode_files = scan_episode_files(dir, base_path);
+            let mut seasons: Vec<u16> = episode_files.iter().map(|e| e.season).collect();
+            seasons.dedup();
+            ParsedNfo::TvShow(TvShowEntry {
+                id: uuid::Uuid::new_v4().to_string(),
+                disk: disk_label.to_string(),
+                disk_uuid: disk_uuid.clone(),
+                relative_path,
+                title: info.title.clone(),
+                original_title: info.original_title.clone(),
+                year: info.year,
+                tmdb_id: info.tmdb_id,
+                imdb_id: info.imdb_id.clone(),
+                country: None,
+                genres: Vec::new(),
+                actors: Vec::new(),
+                seasons: seasons.len() as u16,
+                episodes: episode_files.len() as u32,
+                size_bytes,
+                indexed_at: chrono::Utc::now().to_rfc3339(),
+                episode_files,
+                scan_stamp: None,
+                user_data: Default::default(),
+            })
+        }
+    }
+}
+
+/// Outcome of filling index entries from TMDB.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TmdbLookupSummary {
+    /// Entries filled from TMDB
+    pub matched: usize,
+    /// Entries TMDB had no match for
+    pub not_found: usize,
+    /// Entries whose lookup failed
+    pub failed: usize,
+}
+
+/// Fill entries that lack a TMDB ID or genres from TMDB.
+///
+/// Entries without an ID are searched by title and year. Only empty fields
+/// are filled, so values read from NFO files are never overwritten.
+pub async fn lookup_tmdb_metadata(index: &mut DiskIndex, client: &TmdbClient) -> TmdbLookupSummary {
+    let mut summary = TmdbLookupSummary::default();
+
+    for movie in index
+        .movies
+        .iter_mut()
+        .filter(|m| m.tmdb_id.is_none() || m.genres.is_empty())
+    {
+        let id = match movie.tmdb_id {
+            Some(id) => Some(id),
+            None => match client.search_movie(&movie.title, movie.year).await {
+                Ok(results) => results.first().map(|r| r.id),
+                Err(e) => {
+                    tracing::warn!("TMDB search failed for {}: {}", movie.title, e);
+                    summary.failed += 1;
+                    continue;
+                }
+            },
+        };
+        let Some(id) = id else {
+            summary.not_found += 1;
+            continue;
+        };
+        match client.get_movie_details(id).await {
+            Ok(details) => {
+                apply_movie_details(movie, &details);
+                summary.matched += 1;
+            }
+            Err(e) => {
+                tracing::warn!("TMDB lookup failed for {}: {}", movie.title, e);
+                summary.failed += 1;
+            }
+        }
+    }
+
+    for show in index
+        .tvshows
+        .iter_mut()
+        .filter(|s| s.tmdb_id.is_none() || s.genres.is_empty())
+    {
+        let id = match show.tmdb_id {
+            Some(id) => Some(id),
+            None => match client.search_tv(&show.title, show.year).await {
+                Ok(results) => results.first().map(|r| r.id),
+                Err(e) => {
+                    tracing::warn!("TMDB search failed for {}: {}", show.title, e);
+                    summary.failed += 1;
+                    continue;
+                }
+            },
+        };
+        let Some(id) = id else {
+            summary.not_found += 1;
+            continue;
+        };
+        match client.get_tv_details(id).await {
+            Ok(details) => {
+                apply_tv_details(show, &details);
+                summary.matched += 1;
+            }
+            Err(e) => {
+                tracing::warn!("TMDB lookup failed for {}: {}", show.title, e);
+                summary.failed += 1;
+            }
+        }
+    }
+
+    summary
+}
+
+/// Fill the empty fields of a movie entry from TMDB details.
+pub fn apply_movie_details(movie: &mut MovieEntry, details: &MovieDetails) {
+    movie.tmdb_id.get_or_insert(details.id);
+    if movie.imdb_id.is_none() {
+        movie.imdb_id = details.imdb_id.clone().filter(|id| !id.is_empty());
+    }
+    if movie.original_title.is_none() && details.original_title != movie.title {
+        movie.original_title = Some(details.original_title.clone());
+    }
+    if movie.year.is_none() {
+        movie.year = release_year(details.release_date.as_deref());
+    }
+    if movie.country.is_none() {
+        movie.country = details
+            .production_countries
+            .iter()
+            .flatten()
+            .next()
+            .map(|c| c.iso_3166_1.clone());
+    }
+    if movie.genres.is_empty() {
+        movie.genres = details
+            .genres
+            .iter()
+            .flatten()
+            .map(|g| g.name.clone())
+            .collect();
+    }
+    if let Some(credits) = &details.credits {
+        if movie.actors.is_empty() {
+            movie.actors = credits
+                .cast
+                .iter()
+                .take(10)
+                .map(|c| c.name.clone())
+                .collect();
+        }
+        if movie.directors.is_empty() {
+            movie.directors = credits
+                .crew
+                .iter()
+                .filter(|c| c.job == "Director")
+                .map(|c| c.name.clone())
+                .collect();
+        }
+    }
+    if movie.collection_id.is_none() {
+        if let Some(collection) = &details.belongs_to_collection {
+            movie.collection_id = Some(collection.id);
+            movie.collection_name = Some(collection.name.clone());
+        }
+    }
+    movie.runtime = movie.runtime.or(details.runtime);
+    movie.rating = movie.rating.or(details.vote_average);
+}
+
+/// Fill the empty fields of a TV show entry from TMDB details.
+pub fn apply_tv_details(show: &mut TvShowEntry, details: &TvDetails) {
+    show.tmdb_id.get_or_insert(details.id);
+    if show.imdb_id.is_none() {
+        show.imdb_id = details
+            .external_ids
+            .as_ref()
+            .and_then(|ids| ids.imdb_id.clone())
+            .filter(|id| !id.is_empty());
+    }
+    if show.original_title.is_none() && details.original_name != show.title {
+        show.original_title = Some(details.original_name.clone());
+    }
+    if show.year.is_none() {
+        show.year = release_year(details.first_air_date.as_deref());
+    }
+    if show.country.is_none() {
+        show.country = details.origin_country.iter().flatten().next().cloned();
+    }
+    if show.genres.is_empty() {
+        show.genres = details
+            .genres
+            .iter()
+            .flatten()
+            .map(|g| g.name.clone())
+            .collect();
+    }
+    if show.actors.is_empty() {
+        show.actors = details
+            .credits
+            .iter()
+            .flat_map(|c| c.cast.iter().flatten())
+            .take(10)
+            .map(|c| c.name.clone())
+            .collect();
+    }
+}
+
+/// Year of a TMDB date ("2010-07-16").
+fn release_year(date: Option<&str>) -> Option<u16> {
+    date?.get(..4)?.parse().ok()
 }
 
 /// Parsed NFO result.
@@ -257,21 +1082,21 @@ fn parse_nfo_file(
     let nfo_dir = nfo_path.parent().context("NFO has no parent directory")?;
 
     // Calculate relative path
-    let relative_path = nfo_dir
-        .strip_prefix(base_path)
-        .unwrap_or(nfo_dir)
-        .to_string_lossy()
-        .to_string();
+    let relative_path = relative_dir(nfo_dir, base_path);
 
     // Calculate total size of video files in directory
     let size_bytes = calculate_directory_video_size(nfo_dir);
 
     // Determine if movie or tvshow based on root element
     if content.contains("<movie>") {
-        let movie = parse_movie_nfo(&content, disk_label, disk_uuid, &relative_path, size_bytes)?;
+        let mut movie =
+            parse_movie_nfo(&content, disk_label, disk_uuid, &relative_path, size_bytes)?;
+        detect_video_quality(&mut movie, nfo_dir, &content);
         Ok(ParsedNfo::Movie(movie))
     } else if content.contains("<tvshow>") {
-        let tvshow = parse_tvshow_nfo(&content, disk_label, disk_uuid, &relative_path, size_bytes)?;
+        let mut tvshow =
+            parse_tvshow_nfo(&content, disk_label, disk_uuid, &relative_path, size_bytes)?;
+        tvshow.episode_files = scan_episode_files(nfo_dir, base_path);
         Ok(ParsedNfo::TvShow(tvshow))
     } else {
         anyhow::bail!("Unknown NFO format");
@@ -412,10 +1237,119 @@ fn parse_movie_nfo(
         rating,
         size_bytes,
         resolution,
+        video_codec: None,
+        hdr: None,
+        bitrate_kbps: None,
         indexed_at: chrono::Utc::now().to_rfc3339(),
+        scan_stamp: None,
+        user_data: parse_nfo_user_data(content),
     })
 }
 
+/// Read the watch history Kodi or Jellyfin wrote to an NFO.
+///
+/// `<playcount>` and `<watched>` mark the title watched, `<lastplayed>`
+/// gives the date, `<userrating>` (0 = unrated) and `<isuserfavorite>` the
+/// personal rating and favorite flag.
+pub fn parse_nfo_user_data(content: &str) -> UserData {
+    let get_tag = |tag: &str| -> Option<String> {
+        let pattern = format!(r"<{}>(.*?)</{}>", tag, tag);
+        regex::Regex::new(&pattern)
+            .ok()?
+            .captures(content)
+            .and_then(|c| c.get(1))
+            .map(|m| m.as_str().trim().to_string())
+            .filter(|s| !s.is_empty())
+    };
+    let is_true = |tag: &str| get_tag(tag).is_some_and(|v| v.eq_ignore_ascii_case("true"));
+
+    let play_count = get_tag("playcount")
+        .and_then(|c| c.parse().ok())
+        .unwrap_or(0);
+    UserData {
+        watched: play_count > 0 || is_true("watched"),
+        watched_at: get_tag("lastplayed"),
+        play_count,
+        favorite: is_true("isuserfavorite"),
+        rating: get_tag("userrating")
+            .and_then(|r| r.parse().ok())
+            .filter(|&r: &f32| r > 0.0),
+        ..Default::default()
+    }
+}
+
+/// Fill in video quality from the NFO stream details and the video file name.
+///
+/// Stream details (written by media centers after probing) take precedence;
+/// the name of the largest video file in the directory fills the gaps. The
+/// bitrate is estimated from the directory's video size and the runtime.
+fn detect_video_quality(movie: &mut MovieEntry, dir: &Path, content: &str) {
+    let stream_tag = |tag: &str| -> Option<String> {
+        let pattern = format!(r"(?s)<streamdetails>.*?<video>.*?<{}>(.*?)</{}>", tag, tag);
+        regex::Regex::new(&pattern)
+            .ok()?
+            .captures(content)
+            .and_then(|c| c.get(1))
+            .map(|m| m.as_str().trim().to_string())
+            .filter(|s| !s.is_empty())
+    };
+
+    let main_video = WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file() && is_video_file(e.path()))
+        .filter_map(|e| Some((e.metadata().ok()?.len(), e.file_name().to_owned())))
+        .max_by_key(|(size, _)| *size)
+        .map(|(_, name)| name.to_string_lossy().to_string());
+    let from_name = main_video
+        .as_deref()
+        .map(ffprobe::parse_metadata_from_filename);
+
+    let stream_resolution = stream_tag("width")
+        .zip(stream_tag("height"))
+        .and_then(|(w, h)| {
+            Some(ffprobe::resolution_to_string(
+                w.parse().ok()?,
+                h.parse().ok()?,
+            ))
+        });
+    let known = |v: &String| v != "unknown";
+    movie.resolution = movie.resolution.take().or(stream_resolution).or_else(|| {
+        from_name
+            .as_ref()
+            .map(|m| m.resolution.clone())
+            .filter(known)
+    });
+    movie.video_codec = stream_tag("codec")
+        .map(|c| normalize_codec(&c))
+        .or_else(|| {
+            from_name
+                .as_ref()
+                .map(|m| m.video_codec.clone())
+                .filter(known)
+        });
+    movie.hdr = stream_tag("hdrtype")
+        .map(|h| ffprobe::parse_hdr_from_filename(&h).unwrap_or(h))
+        .or_else(|| {
+            main_video
+                .as_deref()
+                .and_then(ffprobe::parse_hdr_from_filename)
+        });
+    movie.bitrate_kbps = movie
+        .runtime
+        .filter(|&minutes| minutes > 0 && movie.size_bytes > 0)
+        .map(|minutes| (movie.size_bytes * 8 / 1000 / (u64::from(minutes) * 60)) as u32);
+}
+
+/// Normalize a codec name from NFO stream details ("x265", "HEVC" -> "hevc").
+fn normalize_codec(codec: &str) -> String {
+    match codec.to_lowercase().as_str() {
+        "hevc" | "h265" | "h.265" | "x265" => "hevc".to_string(),
+        "avc" | "avc1" | "h264" | "h.264" | "x264" => "h264".to_string(),
+        other => other.to_string(),
+    }
+}
+
 /// Parse tvshow NFO content.
 fn parse_tvshow_nfo(
     content: &str,
@@ -508,31 +1442,201 @@ fn parse_tvshow_nfo(
         episodes,
         size_bytes,
         indexed_at: chrono::Utc::now().to_rfc3339(),
+        episode_files: Vec::new(),
+        scan_stamp: None,
+        user_data: parse_nfo_user_data(content),
     })
 }
 
+/// Video file extensions counted by the indexer.
+const VIDEO_EXTENSIONS: [&str; 9] = [
+    "mp4", "mkv", "avi", "mov", "wmv", "flv", "webm", "m4v", "ts",
+];
+
+fn is_video_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| VIDEO_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
 /// Calculate total size of video files in a directory (recursive).
 fn calculate_directory_video_size(dir: &Path) -> u64 {
-    let video_extensions = [
-        "mp4", "mkv", "avi", "mov", "wmv", "flv", "webm", "m4v", "ts",
-    ];
-
     WalkDir::new(dir)
         .into_iter()
         .filter_map(|e| e.ok())
-        .filter(|e| e.path().is_file())
-        .filter(|e| {
-            e.path()
-                .extension()
-                .and_then(|ext| ext.to_str())
-                .map(|ext| video_extensions.contains(&ext.to_lowercase().as_str()))
-                .unwrap_or(false)
-        })
+        .filter(|e| e.path().is_file() && is_video_file(e.path()))
         .filter_map(|e| e.metadata().ok())
         .map(|m| m.len())
         .sum()
 }
 
+/// Find the episode files of a TV show directory (recursive).
+///
+/// Files whose season and episode cannot be determined (extras, samples)
+/// are skipped.
+pub fn scan_episode_files(show_dir: &Path, base_path: &Path) -> Vec<EpisodeFile> {
+    let mut episodes: Vec<EpisodeFile> = WalkDir::new(show_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_file() && is_video_file(e.path()))
+        .filter_map(|e| parse_episode_file(e.path(), base_path))
+        .collect();
+    episodes.sort_by(|a, b| {
+        (a.season, a.episode, &a.relative_path).cmp(&(b.season, b.episode, &b.relative_path))
+    });
+    episodes
+}
+
+/// Build an episode entry from a video file and its episode NFO, if any.
+fn parse_episode_file(path: &Path, base_path: &Path) -> Option<EpisodeFile> {
+    let file_name = path.file_name()?.to_string_lossy().to_string();
+    let parent = path
+        .parent()
+        .and_then(|p| p.file_name())
+        .map(|n| n.to_string_lossy().to_string());
+
+    // <episodedetails> written next to the video takes precedence
+    let nfo = fs::read_to_string(path.with_extension("nfo"))
+        .ok()
+        .filter(|c| c.contains("<episodedetails>"));
+    let nfo_tag = |tag: &str| -> Option<String> {
+        let pattern = format!(r"<{}>(.*?)</{}>", tag, tag);
+        regex::Regex::new(&pattern)
+            .ok()?
+            .captures(nfo.as_deref()?)
+            .and_then(|c| c.get(1))
+            .map(|m| m.as_str().trim().to_string())
+            .filter(|s| !s.is_empty())
+    };
+    let organized = parser::parse_organized_tvshow_filename(&file_name);
+
+    let numbers = nfo_tag("season")
+        .zip(nfo_tag("episode"))
+        .and_then(|(s, e)| Some((s.parse().ok()?, e.parse().ok()?)));
+    let (season, episode) = numbers
+        .or_else(|| organized.as_ref().map(|o| (o.season, o.episode)))
+        .or_else(|| parse_episode_numbers(&file_name, parent.as_deref()))?;
+
+    let title = nfo_tag("title").or_else(|| organized.map(|o| o.episode_name));
+    let resolution = Some(ffprobe::parse_metadata_from_filename(&file_name).resolution)
+        .filter(|r| r != "unknown");
+
+    Some(EpisodeFile {
+        season,
+        episode,
+        title,
+        resolution,
+        size_bytes: fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+        relative_path: path
+            .strip_prefix(base_path)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string(),
+    })
+}
+
+/// Parse season and episode numbers from an episode file name.
+///
+/// Supports `S01E02`, `1x02`, and episode-only names (`E02`, `EP02`,
+/// `第2集`) inside a season directory.
+fn parse_episode_numbers(file_name: &str, season_dir: Option<&str>) -> Option<(u16, u16)> {
+    let patterns = [
+        r"(?i)(?:^|[^a-z0-9])s(\d{1,2})[ ._-]?e(\d{1,3})(?:[^0-9]|$)",
+        r"(?i)(?:^|[^a-z0-9])(\d{1,2})x(\d{2,3})(?:[^0-9]|$)",
+    ];
+    for pattern in patterns {
+        let caps = regex::Regex::new(pattern).ok()?.captures(file_name);
+        if let Some(caps) = caps {
+            return Some((caps[1].parse().ok()?, caps[2].parse().ok()?));
+        }
+    }
+
+    let season = season_dir.and_then(parser::extract_season_from_dirname)?;
+    let caps = regex::Regex::new(r"(?i)(?:^|[^a-z0-9])ep?(\d{1,3})(?:[^0-9]|$)|第(\d{1,3})集")
+        .ok()?
+        .captures(file_name)?;
+    let episode = caps.get(1).or_else(|| caps.get(2))?.as_str().parse().ok()?;
+    Some((season, episode))
+}
+
+/// Episodes owned of one season of a TV show, possibly spread over disks.
+#[derive(Debug, Clone, Serialize)]
+pub struct SeasonSummary {
+    pub season: u16,
+    /// Episode numbers owned
+    pub episodes: Vec<u16>,
+    /// Episode numbers missing below the highest owned one
+    pub missing: Vec<u16>,
+    /// Disks holding episodes of this season
+    pub disks: Vec<String>,
+    pub size_bytes: u64,
+}
+
+impl SeasonSummary {
+    /// Whether no episode is missing up to the last owned one.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// Group TV show entries of the same show on different disks.
+///
+/// Entries are the same show when their TMDB IDs match, or their titles when
+/// either has no TMDB ID.
+pub fn group_tvshows<'a>(
+    shows: impl IntoIterator<Item = &'a TvShowEntry>,
+) -> Vec<Vec<&'a TvShowEntry>> {
+    let mut groups: Vec<Vec<&TvShowEntry>> = Vec::new();
+    for show in shows {
+        let same_show = |other: &TvShowEntry| match (show.tmdb_id, other.tmdb_id) {
+            (Some(a), Some(b)) => a == b,
+            _ => show.title == other.title,
+        };
+        match groups.iter_mut().find(|g| same_show(g[0])) {
+            Some(group) => group.push(show),
+            None => groups.push(vec![show]),
+        }
+    }
+    groups
+}
+
+/// Summarize the episode files of one or more entries of the same show.
+pub fn summarize_seasons(shows: &[&TvShowEntry]) -> Vec<SeasonSummary> {
+    let mut seasons: std::collections::BTreeMap<u16, SeasonSummary> =
+        std::collections::BTreeMap::new();
+
+    for show in shows {
+        for file in &show.episode_files {
+            let summary = seasons.entry(file.season).or_insert_with(|| SeasonSummary {
+                season: file.season,
+                episodes: Vec::new(),
+                missing: Vec::new(),
+                disks: Vec::new(),
+                size_bytes: 0,
+            });
+            summary.episodes.push(file.episode);
+            summary.size_bytes += file.size_bytes;
+            if !summary.disks.contains(&show.disk) {
+                summary.disks.push(show.disk.clone());
+            }
+        }
+    }
+
+    seasons
+        .into_values()
+        .map(|mut summary| {
+            summary.episodes.sort_unstable();
+            summary.episodes.dedup();
+            let last = summary.episodes.last().copied().unwrap_or(0);
+            summary.missing = (1..=last)
+                .filter(|e| summary.episodes.binary_search(e).is_err())
+                .collect();
+            summary
+        })
+        .collect()
+}
+
 /// Convert country name to ISO 3166-1 alpha-2 code.
 fn country_name_to_code(name: &str) -> String {
     let name_lower = name.to_lowercase();
@@ -562,7 +1666,7 @@ fn country_name_to_code(name: &str) -> String {
 /// Supports composite storage: if a disk already exists in the central index,
 /// the new scan is merged by media type instead of completely replacing it.
 /// This allows one disk label to have both movies and tvshows with different paths.
-pub fn merge_disk_into_central(central: &mut CentralIndex, disk: DiskIndex) {
+pub fn merge_disk_into_central(central: &mut CentralIndex, mut disk: DiskIndex) {
     let label = disk.disk.label.clone();
 
     // Determine what media types are being added in this scan
@@ -571,6 +1675,8 @@ pub fn merge_disk_into_central(central: &mut CentralIndex, disk: DiskIndex) {
 
     // Update or merge disk info
     if let Some(existing_disk) = central.disks.get_mut(&label) {
+        // Rebase the stored paths first; the scanned ones are already current
+        existing_disk.update_mount(&disk.disk);
         // Merge: keep existing paths, add new ones
         for (media_type, path) in &disk.disk.paths {
             existing_disk.paths.insert(media_type.clone(), path.clone());
@@ -599,6 +1705,18 @@ pub fn merge_disk_into_central(central: &mut CentralIndex, disk: DiskIndex) {
         );
     }
 
+    // Keep watched state and personal data of the entries being replaced
+    disk.carry_user_data(
+        central
+            .movies
+            .iter()
+            .filter(|m| has_movies && m.disk == label),
+        central
+            .tvshows
+            .iter()
+            .filter(|t| has_tvshows && t.disk == label),
+    );
+
     // Remove old entries ONLY for the media types being updated
     // This is the key change: we don't remove all entries, just the ones being replaced
     if has_movies {
@@ -612,22 +1730,7 @@ pub fn merge_disk_into_central(central: &mut CentralIndex, disk: DiskIndex) {
     }
 
     // Update disk counts in the disk info
-    if let Some(disk_info) = central.disks.get_mut(&label) {
-        disk_info.movie_count = central.movies.iter().filter(|m| m.disk == label).count();
-        disk_info.tvshow_count = central.tvshows.iter().filter(|t| t.disk == label).count();
-        disk_info.total_size_bytes = central
-            .movies
-            .iter()
-            .filter(|m| m.disk == label)
-            .map(|m| m.size_bytes)
-            .sum::<u64>()
-            + central
-                .tvshows
-                .iter()
-                .filter(|t| t.disk == label)
-                .map(|t| t.size_bytes)
-                .sum::<u64>();
-    }
+    central.update_disk_counts(&label);
 
     // Rebuild indexes and update statistics
     central.rebuild_indexes();
@@ -636,13 +1739,46 @@ pub fn merge_disk_into_central(central: &mut CentralIndex, disk: DiskIndex) {
 }
 
 /// Search results container.
-#[derive(Debug)]
+#[derive(Debug, Serialize, JsonSchema)]
 pub struct SearchResults {
     pub movies: Vec<MovieEntry>,
     pub tvshows: Vec<TvShowEntry>,
     pub collections: Vec<CollectionInfo>,
 }
 
+/// Search the central index of the configured backend.
+///
+/// With the SQLite backend the query runs in the database instead of
+/// loading the whole index.
+#[allow(clippy::too_many_arguments)]
+pub fn search_central(
+    title: Option<&str>,
+    actor: Option<&str>,
+    director: Option<&str>,
+    collection: Option<&str>,
+    year: Option<u16>,
+    year_range: Option<(u16, u16)>,
+    genre: Option<&str>,
+    country: Option<&str>,
+) -> Result<SearchResults> {
+    match index_backend() {
+        IndexBackend::Json => Ok(search(
+            &load_central_json()?,
+            title,
+            actor,
+            director,
+            collection,
+            year,
+            year_range,
+            genre,
+            country,
+        )),
+        IndexBackend::Sqlite => open_central_db()?.search(
+            title, actor, director, collection, year, year_range, genre, country,
+        ),
+    }
+}
+
 /// Search the central index.
 #[allow(clippy::too_many_arguments)]
 pub fn search(
@@ -770,29 +1906,25 @@ pub fn search(
         index.tvshows.clone()
     };
 
-    // Filter by title
+    // Filter by title, most relevant first
     if let Some(title_query) = title {
-        let query_lower = title_query.to_lowercase();
-        movies.retain(|m| {
-            m.title.to_lowercase().contains(&query_lower)
-                || m.original_title
-                    .as_ref()
-                    .map(|t| t.to_lowercase().contains(&query_lower))
-                    .unwrap_or(false)
-        });
-        tvshows.retain(|t| {
-            t.title.to_lowercase().contains(&query_lower)
-                || t.original_title
-                    .as_ref()
-                    .map(|title| title.to_lowercase().contains(&query_lower))
-                    .unwrap_or(false)
-        });
+        let query = TitleQuery::new(title_query);
+        movies = rank_by_relevance(
+            movies,
+            |m| query.score_entry(&m.title, m.original_title.as_deref()),
+            |m| m.year,
+        );
+        tvshows = rank_by_relevance(
+            tvshows,
+            |t| query.score_entry(&t.title, t.original_title.as_deref()),
+            |t| t.year,
+        );
+    } else {
+        // Sort by year descending
+        movies.sort_by_key(|m| std::cmp::Reverse(m.year));
+        tvshows.sort_by_key(|t| std::cmp::Reverse(t.year));
     }
 
-    // Sort by year descending
-    movies.sort_by(|a, b| b.year.cmp(&a.year));
-    tvshows.sort_by(|a, b| b.year.cmp(&a.year));
-
     // Search collections
     let collections: Vec<CollectionInfo> = if let Some(collection_query) = collection {
         let query_lower = collection_query.to_lowercase();
@@ -0,0 +1,369 @@
+//! Schema versioning and migration for plan, rollback and index files.
+//!
+//! Files are upgraded as raw JSON before deserialization, so older session
+//! files keep loading after the data models change. Each schema has a chain
+//! of migration steps; a step upgrades a file from one version to the next.
+//! Files written by a newer version than this build supports are rejected.
+//!
+//! New optional fields that deserialize with `#[serde(default)]` do not bump
+//! the version: a file without them still loads as the same version, and
+//! older builds ignore them. Bump the version and add a step only when an
+//! existing field changes meaning or a new field has no safe default.
+
+use crate::Result;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::fs;
+use std::path::Path;
+
+/// Current plan schema version.
+pub const PLAN_VERSION: &str = "1.1";
+/// Current rollback schema version.
+pub const ROLLBACK_VERSION: &str = "1.1";
+/// Current central/disk index schema version.
+pub const INDEX_VERSION: &str = "1.1";
+
+/// Kind of versioned file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaKind {
+    Plan,
+    Rollback,
+    CentralIndex,
+    DiskIndex,
+}
+
+impl SchemaKind {
+    /// Current version for this schema.
+    pub fn current_version(&self) -> &'static str {
+        match self {
+            SchemaKind::Plan => PLAN_VERSION,
+            SchemaKind::Rollback => ROLLBACK_VERSION,
+            SchemaKind::CentralIndex | SchemaKind::DiskIndex => INDEX_VERSION,
+        }
+    }
+
+    fn invalid(&self, msg: String) -> crate::Error {
+        match self {
+            SchemaKind::Rollback => crate::Error::InvalidRollbackFile(msg),
+            _ => crate::Error::InvalidPlanFile(msg),
+        }
+    }
+
+    /// Migration steps as (from_version, step).
+    fn steps(&self) -> &'static [(&'static str, MigrationStep)] {
+        match self {
+            SchemaKind::Plan => &[("1.0", plan_1_0_to_1_1)],
+            SchemaKind::Rollback => &[("1.0", rollback_1_0_to_1_1)],
+            SchemaKind::CentralIndex => &[("1.0", central_index_1_0_to_1_1)],
+            SchemaKind::DiskIndex => &[("1.0", disk_index_1_0_to_1_1)],
+        }
+    }
+}
+
+/// A single migration step, upgrading a JSON document in place.
+type MigrationStep = fn(&mut Map<String, Value>);
+
+/// Result of migrating a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationOutcome {
+    /// Version the document had before migration.
+    pub from_version: String,
+    /// Version the document has after migration.
+    pub to_version: String,
+}
+
+impl MigrationOutcome {
+    /// Whether the document was changed.
+    pub fn migrated(&self) -> bool {
+        self.from_version != self.to_version
+    }
+}
+
+/// Parse a "major.minor" version string.
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().unwrap_or("0").parse().ok()?;
+    Some((major, minor))
+}
+
+/// Upgrade a JSON document to the current schema version.
+pub fn migrate(kind: SchemaKind, value: &mut Value) -> Result<MigrationOutcome> {
+    let obj = value
+        .as_object_mut()
+        .ok_or_else(|| kind.invalid("expected a JSON object".to_string()))?;
+
+    // Files without a version predate versioning and are treated as 1.0
+    let from_version = obj
+        .get("version")
+        .and_then(|v| v.as_str())
+        .filter(|v| !v.is_empty())
+        .unwrap_or("1.0")
+        .to_string();
+
+    let current = kind.current_version();
+    let parsed = parse_version(&from_version)
+        .ok_or_else(|| kind.invalid(format!("unrecognized schema version '{}'", from_version)))?;
+    if parsed > parse_version(current).unwrap_or((0, 0)) {
+        return Err(kind.invalid(format!(
+            "schema version {} is newer than supported version {}; upgrade media-organizer",
+            from_version, current
+        )));
+    }
+
+    let mut version = from_version.clone();
+    let steps = kind.steps();
+    while version != current {
+        let idx = steps
+            .iter()
+            .position(|(from, _)| *from == version)
+            .ok_or_else(|| {
+                kind.invalid(format!("no migration path from schema version {}", version))
+            })?;
+        (steps[idx].1)(obj);
+        version = steps
+            .get(idx + 1)
+            .map(|(from, _)| from.to_string())
+            .unwrap_or_else(|| current.to_string());
+        obj.insert("version".to_string(), Value::String(version.clone()));
+    }
+
+    Ok(MigrationOutcome {
+        from_version,
+        to_version: version,
+    })
+}
+
+/// Parse JSON content, migrate it, and deserialize into the target type.
+pub fn load_json<T: DeserializeOwned>(kind: SchemaKind, content: &str) -> Result<T> {
+    let mut value: Value = serde_json::from_str(content)?;
+    let outcome = migrate(kind, &mut value)?;
+    if outcome.migrated() {
+        tracing::info!(
+            "Migrated {:?} from schema {} to {}",
+            kind,
+            outcome.from_version,
+            outcome.to_version
+        );
+    }
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Migrate a JSON file in place, keeping a backup of the original.
+///
+/// The backup is written next to the file as `<name>.v<old_version>.bak`.
+/// Returns `None` if the file is already at the current version.
+pub fn migrate_file(kind: SchemaKind, path: &Path) -> Result<Option<MigrationOutcome>> {
+    let content = fs::read_to_string(path)?;
+    let mut value: Value = serde_json::from_str(&content)?;
+    let outcome = migrate(kind, &mut value)?;
+    if !outcome.migrated() {
+        return Ok(None);
+    }
+
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let backup = path.with_file_name(format!("{}.v{}.bak", file_name, outcome.from_version));
+    fs::copy(path, &backup)?;
+    fs::write(path, serde_json::to_string_pretty(&value)?)?;
+
+    Ok(Some(outcome))
+}
+
+/// Insert a default value for a missing key.
+fn ensure(obj: &mut Map<String, Value>, key: &str, default: Value) {
+    if !obj.contains_key(key) || obj[key].is_null() {
+        obj.insert(key.to_string(), default);
+    }
+}
+
+/// Plan 1.0 -> 1.1: early plans omitted `samples` and `unknown` when empty.
+fn plan_1_0_to_1_1(obj: &mut Map<String, Value>) {
+    ensure(obj, "items", Value::Array(vec![]));
+    ensure(obj, "samples", Value::Array(vec![]));
+    ensure(obj, "unknown", Value::Array(vec![]));
+}
+
+/// Rollback 1.0 -> 1.1: operations without an `executed` flag were executed.
+fn rollback_1_0_to_1_1(obj: &mut Map<String, Value>) {
+    ensure(obj, "operations", Value::Array(vec![]));
+    if let Some(Value::Array(ops)) = obj.get_mut("operations") {
+        for op in ops.iter_mut().filter_map(|o| o.as_object_mut()) {
+            ensure(op, "executed", Value::Bool(true));
+        }
+    }
+}
+
+/// Disk info 1.0 -> 1.1: single-path disks predate composite storage.
+fn disk_info_1_0_to_1_1(disk: &mut Map<String, Value>) {
+    ensure(disk, "total_size_bytes", Value::from(0u64));
+    ensure(disk, "base_path", Value::String(String::new()));
+    ensure(disk, "paths", Value::Object(Map::new()));
+}
+
+/// Central index 1.0 -> 1.1: fill in collections/indexes/statistics and disk paths.
+fn central_index_1_0_to_1_1(obj: &mut Map<String, Value>) {
+    for key in ["disks", "collections"] {
+        ensure(obj, key, Value::Object(Map::new()));
+    }
+    for key in ["movies", "tvshows"] {
+        ensure(obj, key, Value::Array(vec![]));
+    }
+    if let Some(Value::Object(disks)) = obj.get_mut("disks") {
+        for disk in disks.values_mut().filter_map(|d| d.as_object_mut()) {
+            disk_info_1_0_to_1_1(disk);
+        }
+    }
+    if !obj.contains_key("indexes") {
+        let indexes = serde_json::to_value(crate::models::index::SearchIndexes::default())
+            .unwrap_or(Value::Object(Map::new()));
+        obj.insert("indexes".to_string(), indexes);
+    }
+    if !obj.contains_key("statistics") {
+        let stats = serde_json::to_value(crate::models::index::IndexStatistics::default())
+            .unwrap_or(Value::Object(Map::new()));
+        obj.insert("statistics".to_string(), stats);
+    }
+}
+
+/// Disk index 1.0 -> 1.1: fill in disk paths.
+fn disk_index_1_0_to_1_1(obj: &mut Map<String, Value>) {
+    for key in ["movies", "tvshows"] {
+        ensure(obj, key, Value::Array(vec![]));
+    }
+    if let Some(Value::Object(disk)) = obj.get_mut("disk") {
+        disk_info_1_0_to_1_1(disk);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::plan::Plan;
+    use crate::models::rollback::Rollback;
+    use serde_json::json;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_migrate_old_plan() {
+        let content = json!({
+            "version": "1.0",
+            "created_at": "2024-01-01T00:00:00Z",
+            "media_type": "movies",
+            "source_path": "/src",
+            "target_path": "/dst",
+            "items": []
+        })
+        .to_string();
+
+        let plan: Plan = load_json(SchemaKind::Plan, &content).unwrap();
+        assert_eq!(plan.version, PLAN_VERSION);
+        assert!(plan.samples.is_empty());
+        assert!(plan.unknown.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_old_rollback() {
+        let mut value = json!({
+            "plan_id": "abc",
+            "executed_at": "2024-01-01T00:00:00Z",
+            "operations": [{
+                "seq": 1, "op_type": "mkdir", "from": "", "to": "/dst/a",
+                "rollback": {"op": "rmdir", "path": "/dst/a"}
+            }]
+        });
+
+        let outcome = migrate(SchemaKind::Rollback, &mut value).unwrap();
+        assert!(outcome.migrated());
+        assert_eq!(outcome.from_version, "1.0");
+
+        let rollback: Rollback = serde_json::from_value(value).unwrap();
+        assert_eq!(rollback.version, ROLLBACK_VERSION);
+        assert!(rollback.operations[0].executed);
+    }
+
+    #[test]
+    fn test_current_version_is_untouched() {
+        let mut value = json!({"version": PLAN_VERSION, "items": []});
+        let outcome = migrate(SchemaKind::Plan, &mut value).unwrap();
+        assert!(!outcome.migrated());
+        assert!(value.get("samples").is_none());
+    }
+
+    #[test]
+    fn test_additive_fields_load_from_1_1() {
+        use crate::models::index::DiskIndex;
+
+        // Written before checksums, disk capacity, codecs and user data existed
+        let content = json!({
+            "version": "1.1",
+            "disk": {
+                "label": "Disk1", "uuid": null, "last_indexed": "2024-01-01T00:00:00Z",
+                "movie_count": 1, "tvshow_count": 0, "total_size_bytes": 100
+            },
+            "movies": [{
+                "id": "m1", "disk": "Disk1", "disk_uuid": null,
+                "relative_path": "Movies/A (2001)", "title": "A", "original_title": null,
+                "year": 2001, "tmdb_id": null, "imdb_id": null, "collection_id": null,
+                "collection_name": null, "collection_total_movies": null, "country": null,
+                "genres": [], "actors": [], "directors": [], "runtime": null, "rating": null,
+                "size_bytes": 100, "resolution": "1080p",
+                "indexed_at": "2024-01-01T00:00:00Z"
+            }],
+            "tvshows": []
+        })
+        .to_string();
+        let index: DiskIndex = load_json(SchemaKind::DiskIndex, &content).unwrap();
+        assert_eq!(index.version, INDEX_VERSION);
+        assert!(index.disk.capacity_bytes.is_none());
+        assert!(index.movies[0].user_data.is_empty());
+
+        // Round trip stays at the same version
+        let saved = serde_json::to_string(&index).unwrap();
+        let reloaded: DiskIndex = load_json(SchemaKind::DiskIndex, &saved).unwrap();
+        assert_eq!(reloaded.version, "1.1");
+        assert_eq!(reloaded.movies[0].title, "A");
+
+        let content = json!({
+            "version": "1.1",
+            "plan_id": "abc",
+            "executed_at": "2024-01-01T00:00:00Z",
+            "operations": [{
+                "seq": 1, "op_type": "move", "from": "/src/a.mkv", "to": "/dst/a.mkv",
+                "rollback": {"op": "move", "path": "/dst/a.mkv", "to": "/src/a.mkv"},
+                "executed": true
+            }]
+        })
+        .to_string();
+        let rollback: Rollback = load_json(SchemaKind::Rollback, &content).unwrap();
+        assert!(rollback.operations[0].checksum.is_none());
+        let saved = serde_json::to_string(&rollback).unwrap();
+        let reloaded: Rollback = load_json(SchemaKind::Rollback, &saved).unwrap();
+        assert_eq!(reloaded.version, ROLLBACK_VERSION);
+        assert_eq!(reloaded.operations[0].to, PathBuf::from("/dst/a.mkv"));
+    }
+
+    #[test]
+    fn test_migrate_file_keeps_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("plan.json");
+        fs::write(&path, r#"{"version": "1.0", "items": []}"#).unwrap();
+
+        let outcome = migrate_file(SchemaKind::Plan, &path).unwrap().unwrap();
+        assert_eq!(outcome.to_version, PLAN_VERSION);
+        assert!(dir.path().join("plan.json.v1.0.bak").exists());
+
+        // Second run is a no-op
+        assert!(migrate_file(SchemaKind::Plan, &path).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_reject_newer_version() {
+        let mut value = json!({"version": "9.0"});
+        assert!(migrate(SchemaKind::Plan, &mut value).is_err());
+        let mut value = json!({"version": "garbage"});
+        assert!(migrate(SchemaKind::Rollback, &mut value).is_err());
+    }
+}
@@ -1,10 +1,23 @@
 //! Core business logic modules.
 
+pub mod dedup;
 pub mod executor;
 pub mod exporter;
+pub mod gaps;
+pub mod hooks;
+pub mod index_store;
 pub mod indexer;
 pub mod metadata;
+pub mod migration;
 pub mod parser;
+pub mod placement;
+pub mod plan_csv;
 pub mod planner;
+pub mod quality;
+pub mod query;
 pub mod rollback;
 pub mod scanner;
+pub mod sessions;
+pub mod title_match;
+pub mod transfer;
+pub mod watcher;
@@ -483,6 +483,38 @@ mod tests {
         };
         assert!(!parser.is_valid(&parsed));
     }
+
+    #[test]
+    fn test_parse_library_folder() {
+        let info = parse_library_folder("The Matrix (1999) {tmdb-603}", MediaType::Movies).unwrap();
+        assert_eq!(info.title, "The Matrix");
+        assert_eq!(info.year, Some(1999));
+        assert_eq!(info.tmdb_id, Some(603));
+
+        let info = parse_library_folder(
+            "Dark (2017) [tmdbid-70523] [imdbid-tt5753856]",
+            MediaType::TvShows,
+        )
+        .unwrap();
+        assert_eq!(info.title, "Dark");
+        assert_eq!(info.tmdb_id, Some(70523));
+        assert_eq!(info.imdb_id.as_deref(), Some("tt5753856"));
+
+        let info = parse_library_folder(
+            "[Upgrade][升级](2018)-tt6499752-tmdb500664",
+            MediaType::Movies,
+        )
+        .unwrap();
+        assert_eq!(info.title, "升级");
+        assert_eq!(info.original_title.as_deref(), Some("Upgrade"));
+        assert_eq!(info.tmdb_id, Some(500664));
+
+        let info = parse_library_folder("Heat (1995)", MediaType::Movies).unwrap();
+        assert_eq!((info.title.as_str(), info.tmdb_id), ("Heat", None));
+
+        assert!(parse_library_folder("Season 01", MediaType::TvShows).is_none());
+        assert!(parse_library_folder("Extras", MediaType::Movies).is_none());
+    }
 }
 
 /// Extract season and episode numbers from filename using regex.
@@ -1285,6 +1317,85 @@ pub fn parse_organized_tvshow_folder(dirname: &str) -> Option<OrganizedTvShowFol
     None
 }
 
+/// Title information recognized from the name of a media folder.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LibraryFolderInfo {
+    pub title: String,
+    pub original_title: Option<String>,
+    pub year: Option<u16>,
+    pub imdb_id: Option<String>,
+    pub tmdb_id: Option<u64>,
+}
+
+/// Recognize a movie or TV show folder by its name, for folders without an NFO.
+///
+/// Supported formats, in order:
+/// - Our organized folders (see [`parse_organized_movie_folder`] and
+///   [`parse_organized_tvshow_folder`])
+/// - Plex/Jellyfin: `Title (Year) {tmdb-123}`, `Title (Year) [tmdbid-123]`,
+///   optionally with `{imdb-tt123}`
+/// - Plain `Title (Year)`
+pub fn parse_library_folder(dirname: &str, media_type: MediaType) -> Option<LibraryFolderInfo> {
+    match media_type {
+        MediaType::Movies => {
+            if let Some(info) = parse_organized_movie_folder(dirname) {
+                let original = info.original_title.clone();
+                return Some(LibraryFolderInfo {
+                    title: info.title.or_else(|| original.clone())?,
+                    original_title: original,
+                    year: Some(info.year),
+                    imdb_id: info.imdb_id,
+                    tmdb_id: Some(info.tmdb_id),
+                });
+            }
+        }
+        MediaType::TvShows => {
+            if let Some(info) = parse_organized_tvshow_folder(dirname) {
+                return Some(LibraryFolderInfo {
+                    title: info.title,
+                    original_title: None,
+                    year: info.year,
+                    imdb_id: info.imdb_id,
+                    tmdb_id: Some(info.tmdb_id),
+                });
+            }
+        }
+    }
+
+    let id_tag = |name: &str| -> Option<String> {
+        let pattern = format!(r"(?i)[\[{{]{}-(\w+)[\]}}]", name);
+        regex::Regex::new(&pattern)
+            .ok()?
+            .captures(dirname)
+            .map(|c| c[1].to_string())
+    };
+    let tmdb_id = id_tag("tmdb(?:id)?").and_then(|id| id.parse().ok());
+    let imdb_id = id_tag("imdb(?:id)?").filter(|id| id.starts_with("tt"));
+
+    // Title and year before any ID tags
+    let name = regex::Regex::new(r"\s*[\[{][a-zA-Z]+-\w+[\]}]")
+        .ok()?
+        .replace_all(dirname, "");
+    let re_year = regex::Regex::new(r"^(.+?)\s*\((\d{4})\)$").ok()?;
+    let (title, year) = match re_year.captures(name.trim()) {
+        Some(caps) => (caps[1].trim().to_string(), caps[2].parse().ok()),
+        // A bare name is only trusted with an ID
+        None if tmdb_id.is_some() => (name.trim().to_string(), None),
+        None => return None,
+    };
+    if title.is_empty() {
+        return None;
+    }
+
+    Some(LibraryFolderInfo {
+        title,
+        original_title: None,
+        year,
+        imdb_id,
+        tmdb_id,
+    })
+}
+
 /// Convert OrganizedTvShowInfo to ParsedFilename for consistent processing.
 impl From<OrganizedTvShowInfo> for ParsedFilename {
     fn from(info: OrganizedTvShowInfo) -> Self {
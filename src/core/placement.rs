@@ -0,0 +1,458 @@
+//! Target disk selection for `plan --target auto`.
+//!
+//! A movie goes next to other movies of its collection and a TV show to the
+//! disk already holding earlier seasons, as long as that disk is online and
+//! has room. Everything else goes to the online disk with the most free
+//! space. Items fitting on no disk are moved to the plan's unknown list.
+
+use crate::core::indexer;
+use crate::models::index::CentralIndex;
+use crate::models::media::MediaType;
+use crate::models::plan::{Placement, Plan, PlanItem, UnknownItem};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Space left free on every disk, in bytes.
+pub const RESERVED_BYTES: u64 = 10 * 1_073_741_824;
+
+/// An online disk that can receive new media.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetDisk {
+    /// Disk label
+    pub label: String,
+    /// Media folder on the disk, as mounted now
+    pub root: PathBuf,
+    /// Free space in bytes
+    pub free_bytes: u64,
+}
+
+/// Outcome of placing a plan.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlacementSummary {
+    /// Items placed on a disk
+    pub placed: usize,
+    /// Items that fit on no disk
+    pub unplaced: usize,
+}
+
+/// Online disks holding a folder for this media type, with their free space.
+pub fn online_target_disks(index: &CentralIndex, media_type: MediaType) -> Vec<TargetDisk> {
+    let key = match media_type {
+        MediaType::Movies => "movies",
+        MediaType::TvShows => "tvshows",
+    };
+
+    let mut disks: Vec<TargetDisk> = index
+        .disks
+        .values()
+        .filter_map(|disk| {
+            let mount = indexer::locate_disk(&disk.label, disk.uuid.as_deref())?;
+            let mut disk = disk.clone();
+            disk.rebase_paths(&mount.to_string_lossy());
+            let root = PathBuf::from(disk.paths.get(key)?);
+            if !root.is_dir() {
+                return None;
+            }
+            let free_bytes = indexer::mount_info(&root)?.free_bytes;
+            Some(TargetDisk {
+                label: disk.label,
+                root,
+                free_bytes,
+            })
+        })
+        .collect();
+    disks.sort_by(|a, b| a.label.cmp(&b.label));
+    disks
+}
+
+/// Place the items of a plan generated for `plan.target_path` on disks.
+///
+/// Items of the same movie or show are placed together. Their target paths
+/// are moved under the chosen disk's media folder and the choice is recorded
+/// in `placement`. Free space is counted down as items are placed.
+pub fn place_plan(
+    plan: &mut Plan,
+    index: &CentralIndex,
+    disks: &mut [TargetDisk],
+) -> PlacementSummary {
+    let mut summary = PlacementSummary::default();
+
+    // Group items of the same title, in plan order
+    let mut groups: Vec<(String, Vec<PlanItem>)> = Vec::new();
+    for item in plan.items.drain(..) {
+        let key = title_key(&item);
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, items)) => items.push(item),
+            None => groups.push((key, vec![item])),
+        }
+    }
+
+    let mut moved_folders: Vec<(PathBuf, PathBuf)> = Vec::new();
+    for (_, mut items) in groups {
+        let needed: u64 = items.iter().map(|i| i.source.size).sum();
+        let Some((disk_index, reason)) = choose_disk(&items[0], needed, index, disks) else {
+            let reason = format!(
+                "No online disk has {} free for this title",
+                format_gb(needed + RESERVED_BYTES)
+            );
+            summary.unplaced += items.len();
+            plan.unknown
+                .extend(items.into_iter().map(|item| UnknownItem {
+                    source: item.source,
+                    reason: reason.clone(),
+                }));
+            continue;
+        };
+
+        let disk = &mut disks[disk_index];
+        disk.free_bytes = disk.free_bytes.saturating_sub(needed);
+        for item in &mut items {
+            if let Some(folder) = item.target.full_path.parent() {
+                let folder = folder.to_path_buf();
+                let moved = rebase(&folder, &plan.target_path, &disk.root);
+                moved_folders.push((folder, moved));
+            }
+            relocate_item(item, &plan.target_path, &disk.root);
+            item.placement = Some(Placement {
+                disk: disk.label.clone(),
+                root: disk.root.clone(),
+                reason: reason.clone(),
+            });
+        }
+        summary.placed += items.len();
+        plan.items.extend(items);
+    }
+
+    // Samples follow the item whose folder they are in
+    for sample in &mut plan.samples {
+        let moved = moved_folders
+            .iter()
+            .find(|(folder, _)| sample.target.starts_with(folder));
+        if let Some((folder, moved)) = moved {
+            sample.target = rebase(&sample.target, folder, moved);
+        }
+    }
+
+    // The plan's target is the folder receiving the most items
+    let mut counts: HashMap<&Path, usize> = HashMap::new();
+    for placement in plan.items.iter().filter_map(|i| i.placement.as_ref()) {
+        *counts.entry(placement.root.as_path()).or_default() += 1;
+    }
+    if let Some((root, _)) = counts.into_iter().max_by_key(|(root, n)| (*n, *root)) {
+        plan.target_path = root.to_path_buf();
+    }
+
+    summary
+}
+
+/// Key grouping the items of one title.
+fn title_key(item: &PlanItem) -> String {
+    if let Some(movie) = &item.movie_metadata {
+        format!("movie:{}", movie.tmdb_id)
+    } else if let Some(show) = &item.tvshow_metadata {
+        format!("tv:{}", show.tmdb_id)
+    } else {
+        format!("item:{}", item.id)
+    }
+}
+
+/// Pick a disk for a title needing `needed` bytes and explain the choice.
+fn choose_disk(
+    item: &PlanItem,
+    needed: u64,
+    index: &CentralIndex,
+    disks: &[TargetDisk],
+) -> Option<(usize, String)> {
+    let fits = |i: usize| disks[i].free_bytes >= needed + RESERVED_BYTES;
+
+    // Disk already holding the most of the collection or show
+    let mut note = None;
+    if let Some((label, reason)) = related_disk(item, index) {
+        match disks.iter().position(|d| d.label == label) {
+            Some(i) if fits(i) => return Some((i, reason)),
+            Some(_) => note = Some(format!("{} ({} lacks space)", reason, label)),
+            None => note = Some(format!("{} ({} is offline)", reason, label)),
+        }
+    }
+
+    let best = (0..disks.len())
+        .filter(|&i| fits(i))
+        .max_by_key(|&i| disks[i].free_bytes)?;
+    let reason = format!(
+        "Most free space ({} free)",
+        format_gb(disks[best].free_bytes)
+    );
+    Some(match note {
+        Some(note) => (best, format!("{}; {}", note, reason)),
+        None => (best, reason),
+    })
+}
+
+/// Disk holding other movies of the item's collection, or earlier episodes
+/// of its show, with the reason to prefer it.
+fn related_disk(item: &PlanItem, index: &CentralIndex) -> Option<(String, String)> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    let name;
+    if let Some(movie) = &item.movie_metadata {
+        let collection_id = movie.collection_id?;
+        for m in index
+            .movies
+            .iter()
+            .filter(|m| m.collection_id == Some(collection_id))
+        {
+            *counts.entry(m.disk.as_str()).or_default() += 1;
+        }
+        name = movie
+            .collection_name
+            .clone()
+            .unwrap_or_else(|| "the collection".to_string());
+    } else if let Some(show) = &item.tvshow_metadata {
+        for s in index
+            .tvshows
+            .iter()
+            .filter(|s| s.tmdb_id == Some(show.tmdb_id))
+        {
+            *counts.entry(s.disk.as_str()).or_default() += s.episodes.max(1) as usize;
+        }
+        name = show.name.clone();
+    } else {
+        return None;
+    }
+
+    let (label, count) = counts
+        .into_iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))?;
+    let what = if item.movie_metadata.is_some() {
+        "movie(s) of"
+    } else {
+        "episode(s) of"
+    };
+    Some((
+        label.to_string(),
+        format!("{} {} {} already on {}", count, what, name, label),
+    ))
+}
+
+/// Move an item's target paths from one root folder to another.
+pub fn relocate_item(item: &mut PlanItem, from: &Path, to: &Path) {
+    item.target.full_path = rebase(&item.target.full_path, from, to);
+    for op in &mut item.operations {
+        op.to = rebase(&op.to, from, to);
+    }
+}
+
+fn rebase(path: &Path, from: &Path, to: &Path) -> PathBuf {
+    match path.strip_prefix(from) {
+        Ok(relative) => to.join(relative),
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+fn format_gb(bytes: u64) -> String {
+    format!("{:.1} GB", bytes as f64 / 1_073_741_824.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::index::{MovieEntry, TvShowEntry};
+    use crate::models::media::{MovieMetadata, TvShowMetadata, VideoFile, VideoMetadata};
+    use crate::models::plan::{
+        Operation, OperationType, ParsedInfo, PlanItemStatus, SampleItem, TargetInfo,
+    };
+
+    const GB: u64 = 1_073_741_824;
+
+    fn item(id: &str, folder: &str, size: u64) -> PlanItem {
+        let full_path = PathBuf::from(format!("/staging/{}/{}.mkv", folder, folder));
+        PlanItem {
+            id: id.to_string(),
+            status: PlanItemStatus::Pending,
+            source: VideoFile {
+                path: PathBuf::from(format!("/in/{}.mkv", id)),
+                filename: format!("{}.mkv", id),
+                size,
+                modified: chrono::Utc::now(),
+                is_sample: false,
+                parent_dir: PathBuf::from("/in"),
+            },
+            parsed: ParsedInfo::default(),
+            movie_metadata: None,
+            tvshow_metadata: None,
+            episode_metadata: None,
+            video_metadata: VideoMetadata::default(),
+            target: TargetInfo {
+                folder: folder.to_string(),
+                filename: format!("{}.mkv", folder),
+                full_path: full_path.clone(),
+                nfo: "movie.nfo".to_string(),
+                poster: None,
+            },
+            operations: vec![Operation {
+                op: OperationType::Move,
+                from: Some(PathBuf::from(format!("/in/{}.mkv", id))),
+                to: full_path,
+                url: None,
+                content_ref: None,
+            }],
+            placement: None,
+        }
+    }
+
+    fn movie(tmdb_id: u64, folder: &str, size: u64, collection: Option<u64>) -> PlanItem {
+        let mut item = item(&tmdb_id.to_string(), folder, size);
+        item.movie_metadata = Some(MovieMetadata {
+            tmdb_id,
+            collection_id: collection,
+            collection_name: collection.map(|_| "Saga".to_string()),
+            ..Default::default()
+        });
+        item
+    }
+
+    fn owned_movie(disk: &str, collection: u64) -> MovieEntry {
+        MovieEntry {
+            id: format!("owned-{}", collection),
+            disk: disk.to_string(),
+            relative_path: "Owned".to_string(),
+            title: "Owned".to_string(),
+            year: Some(2000),
+            tmdb_id: Some(1),
+            collection_id: Some(collection),
+            collection_name: Some("Saga".to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn disk(label: &str, free_gb: u64) -> TargetDisk {
+        TargetDisk {
+            label: label.to_string(),
+            root: PathBuf::from(format!("/mnt/{}/Movies", label)),
+            free_bytes: free_gb * GB,
+        }
+    }
+
+    fn plan(items: Vec<PlanItem>) -> Plan {
+        Plan {
+            version: "1.0".to_string(),
+            created_at: String::new(),
+            media_type: Some(MediaType::Movies),
+            source_path: PathBuf::from("/in"),
+            target_path: PathBuf::from("/staging"),
+            items,
+            samples: vec![SampleItem {
+                source: PathBuf::from("/in/sample.mkv"),
+                target: PathBuf::from("/staging/Sequel/sample/sample.mkv"),
+            }],
+            unknown: vec![],
+        }
+    }
+
+    #[test]
+    fn test_collection_movie_goes_next_to_its_collection() {
+        let mut index = CentralIndex::default();
+        index.movies.push(owned_movie("D1", 10));
+        let mut disks = vec![disk("D1", 50), disk("D2", 500)];
+        let mut plan = plan(vec![
+            movie(101, "Sequel", 5 * GB, Some(10)),
+            movie(102, "Other", 5 * GB, None),
+        ]);
+
+        let summary = place_plan(&mut plan, &index, &mut disks);
+        assert_eq!(
+            summary,
+            PlacementSummary {
+                placed: 2,
+                unplaced: 0
+            }
+        );
+
+        let sequel = &plan.items[0];
+        let placement = sequel.placement.as_ref().unwrap();
+        assert_eq!(placement.disk, "D1");
+        assert_eq!(placement.reason, "1 movie(s) of Saga already on D1");
+        assert_eq!(
+            sequel.target.full_path,
+            PathBuf::from("/mnt/D1/Movies/Sequel/Sequel.mkv")
+        );
+        assert_eq!(sequel.operations[0].to, sequel.target.full_path);
+        assert_eq!(
+            plan.samples[0].target,
+            PathBuf::from("/mnt/D1/Movies/Sequel/sample/sample.mkv")
+        );
+
+        let other = plan.items[1].placement.as_ref().unwrap();
+        assert_eq!(other.disk, "D2");
+        assert!(other.reason.starts_with("Most free space"));
+        assert_eq!(disks[0].free_bytes, 45 * GB);
+    }
+
+    #[test]
+    fn test_full_or_offline_collection_disk_falls_back() {
+        let mut index = CentralIndex::default();
+        index.movies.push(owned_movie("D1", 10));
+        index.movies.push(owned_movie("D3", 20));
+        let mut disks = vec![disk("D1", 12), disk("D2", 100)];
+        let mut plan = plan(vec![
+            movie(101, "Sequel", 5 * GB, Some(10)),
+            movie(103, "Prequel", 5 * GB, Some(20)),
+            movie(104, "Huge", 500 * GB, None),
+        ]);
+
+        let summary = place_plan(&mut plan, &index, &mut disks);
+        assert_eq!(
+            summary,
+            PlacementSummary {
+                placed: 2,
+                unplaced: 1
+            }
+        );
+        let reasons: Vec<&str> = plan
+            .items
+            .iter()
+            .map(|i| i.placement.as_ref().unwrap().reason.as_str())
+            .collect();
+        assert!(reasons[0].starts_with("1 movie(s) of Saga already on D1 (D1 lacks space); Most"));
+        assert!(reasons[1].contains("(D3 is offline)"));
+        assert_eq!(plan.unknown.len(), 1);
+        assert!(plan.unknown[0].reason.starts_with("No online disk has"));
+        assert_eq!(plan.target_path, PathBuf::from("/mnt/D2/Movies"));
+    }
+
+    #[test]
+    fn test_show_episodes_stay_together_on_show_disk() {
+        let mut index = CentralIndex::default();
+        let show = TvShowEntry {
+            id: "dark".to_string(),
+            disk: "D2".to_string(),
+            relative_path: "Dark".to_string(),
+            title: "Dark".to_string(),
+            year: Some(2017),
+            tmdb_id: Some(70523),
+            seasons: 1,
+            episodes: 10,
+            ..Default::default()
+        };
+        index.tvshows.push(show);
+
+        let episode = |id: &str| {
+            let mut item = item(id, "Dark", GB);
+            item.tvshow_metadata = Some(TvShowMetadata {
+                tmdb_id: 70523,
+                name: "Dark".to_string(),
+                ..Default::default()
+            });
+            item
+        };
+        let mut disks = vec![disk("D1", 900), disk("D2", 30)];
+        let mut plan = plan(vec![episode("e1"), episode("e2")]);
+
+        place_plan(&mut plan, &index, &mut disks);
+        for item in &plan.items {
+            let placement = item.placement.as_ref().unwrap();
+            assert_eq!(placement.disk, "D2");
+            assert_eq!(placement.reason, "10 episode(s) of Dark already on D2");
+        }
+        assert_eq!(disks[1].free_bytes, 28 * GB);
+    }
+}
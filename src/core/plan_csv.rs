@@ -0,0 +1,288 @@
+//! CSV export/import of plans.
+//!
+//! Exports one row per plan item and unknown file so they can be triaged in a
+//! spreadsheet, and reads the edited CSV back into a list of edits (status
+//! changes and corrected TMDB IDs) to apply to the plan.
+
+use crate::models::plan::{Plan, PlanItemStatus};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Status value used for unknown items in the CSV.
+pub const UNKNOWN_STATUS: &str = "unknown";
+
+/// A single CSV row.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanCsvRow {
+    /// Source video path (used as the row key on import).
+    pub source: PathBuf,
+    /// pending / skip / error, or "unknown" for unmatched files.
+    pub status: String,
+    /// TMDB ID of the match (empty if unmatched).
+    pub tmdb_id: Option<u64>,
+    /// Matched (or parsed) title.
+    pub title: String,
+    /// Matched (or parsed) year.
+    pub year: Option<u16>,
+    /// Target path of the video file.
+    pub target: String,
+    /// Reason the file could not be matched.
+    pub reason: String,
+}
+
+/// An edit read back from a CSV file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CsvEdit {
+    /// Change the status of an existing plan item.
+    Status {
+        source: PathBuf,
+        status: PlanItemStatus,
+    },
+    /// Re-match an item or unknown file against a TMDB ID.
+    Rematch { source: PathBuf, tmdb_id: u64 },
+}
+
+/// Convert a plan into CSV rows (items first, then unknown files).
+pub fn plan_to_rows(plan: &Plan) -> Vec<PlanCsvRow> {
+    let mut rows = Vec::with_capacity(plan.items.len() + plan.unknown.len());
+
+    for item in &plan.items {
+        let (tmdb_id, title, year) = if let Some(ref movie) = item.movie_metadata {
+            (Some(movie.tmdb_id), movie.title.clone(), Some(movie.year))
+        } else if let Some(ref show) = item.tvshow_metadata {
+            (Some(show.tmdb_id), show.name.clone(), Some(show.year))
+        } else {
+            (
+                None,
+                item.parsed.title.clone().unwrap_or_default(),
+                item.parsed.year,
+            )
+        };
+
+        rows.push(PlanCsvRow {
+            source: item.source.path.clone(),
+            status: status_to_str(item.status).to_string(),
+            tmdb_id,
+            title,
+            year,
+            target: item.target.full_path.display().to_string(),
+            reason: String::new(),
+        });
+    }
+
+    for item in &plan.unknown {
+        rows.push(PlanCsvRow {
+            source: item.source.path.clone(),
+            status: UNKNOWN_STATUS.to_string(),
+            tmdb_id: None,
+            title: String::new(),
+            year: None,
+            target: String::new(),
+            reason: item.reason.clone(),
+        });
+    }
+
+    rows
+}
+
+/// Write a plan as CSV. Returns the number of rows written.
+pub fn write_plan_csv<W: std::io::Write>(plan: &Plan, writer: W) -> Result<usize> {
+    let rows = plan_to_rows(plan);
+    let mut wtr = csv::Writer::from_writer(writer);
+    for row in &rows {
+        wtr.serialize(row)?;
+    }
+    wtr.flush()?;
+    Ok(rows.len())
+}
+
+/// Read CSV rows from a file.
+pub fn read_plan_csv(path: &Path) -> Result<Vec<PlanCsvRow>> {
+    let mut rdr = csv::Reader::from_path(path)?;
+    let mut rows = Vec::new();
+    for row in rdr.deserialize() {
+        rows.push(row?);
+    }
+    Ok(rows)
+}
+
+/// Compare edited CSV rows against a plan and collect the edits to apply.
+///
+/// Rows whose source is not in the plan are reported as errors. A changed or
+/// newly filled TMDB ID produces a rematch; otherwise a changed status of an
+/// existing item produces a status edit.
+pub fn diff_rows(plan: &Plan, rows: &[PlanCsvRow]) -> Result<Vec<CsvEdit>> {
+    let original: HashMap<PathBuf, PlanCsvRow> = plan_to_rows(plan)
+        .into_iter()
+        .map(|r| (r.source.clone(), r))
+        .collect();
+
+    let mut edits = Vec::new();
+    for row in rows {
+        let before = original.get(&row.source).ok_or_else(|| {
+            crate::Error::InvalidPlanFile(format!(
+                "CSV row not found in plan: {}",
+                row.source.display()
+            ))
+        })?;
+
+        if let Some(tmdb_id) = row.tmdb_id {
+            if before.tmdb_id != Some(tmdb_id) {
+                edits.push(CsvEdit::Rematch {
+                    source: row.source.clone(),
+                    tmdb_id,
+                });
+                continue;
+            }
+        }
+
+        let status = row.status.trim().to_lowercase();
+        if status != before.status && before.status != UNKNOWN_STATUS {
+            let status = parse_status(&status).ok_or_else(|| {
+                crate::Error::InvalidPlanFile(format!(
+                    "Invalid status '{}' for {}",
+                    row.status,
+                    row.source.display()
+                ))
+            })?;
+            edits.push(CsvEdit::Status {
+                source: row.source.clone(),
+                status,
+            });
+        }
+    }
+
+    Ok(edits)
+}
+
+/// Apply status edits to a plan. Returns the number of items changed.
+pub fn apply_status_edits(plan: &mut Plan, edits: &[CsvEdit]) -> usize {
+    let mut changed = 0;
+    for edit in edits {
+        if let CsvEdit::Status { source, status } = edit {
+            if let Some(item) = plan.items.iter_mut().find(|i| &i.source.path == source) {
+                item.status = *status;
+                changed += 1;
+            }
+        }
+    }
+    changed
+}
+
+fn status_to_str(status: PlanItemStatus) -> &'static str {
+    match status {
+        PlanItemStatus::Pending => "pending",
+        PlanItemStatus::Skip => "skip",
+        PlanItemStatus::Error => "error",
+    }
+}
+
+fn parse_status(s: &str) -> Option<PlanItemStatus> {
+    match s {
+        "pending" => Some(PlanItemStatus::Pending),
+        "skip" => Some(PlanItemStatus::Skip),
+        "error" => Some(PlanItemStatus::Error),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::media::{MovieMetadata, VideoFile, VideoMetadata};
+    use crate::models::plan::{ParsedInfo, PlanItem, TargetInfo, UnknownItem};
+
+    fn video(path: &str) -> VideoFile {
+        let path = PathBuf::from(path);
+        VideoFile {
+            filename: path.file_name().unwrap().to_string_lossy().to_string(),
+            parent_dir: path.parent().unwrap().to_path_buf(),
+            path,
+            size: 0,
+            modified: chrono::Utc::now(),
+            is_sample: false,
+        }
+    }
+
+    fn sample_plan() -> Plan {
+        Plan {
+            items: vec![PlanItem {
+                id: "1".to_string(),
+                status: PlanItemStatus::Pending,
+                source: video("/src/avatar.mkv"),
+                parsed: ParsedInfo::default(),
+                movie_metadata: Some(MovieMetadata {
+                    tmdb_id: 19995,
+                    title: "Avatar, the \"movie\"".to_string(),
+                    year: 2009,
+                    ..Default::default()
+                }),
+                tvshow_metadata: None,
+                episode_metadata: None,
+                video_metadata: VideoMetadata::default(),
+                target: TargetInfo {
+                    full_path: PathBuf::from("/dst/Avatar/avatar.mkv"),
+                    ..Default::default()
+                },
+                operations: vec![],
+                placement: None,
+            }],
+            unknown: vec![UnknownItem {
+                source: video("/src/random.mkv"),
+                reason: "Failed to find TMDB match".to_string(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_csv_roundtrip_has_no_edits() {
+        let plan = sample_plan();
+        let mut buf = Vec::new();
+        assert_eq!(write_plan_csv(&plan, &mut buf).unwrap(), 2);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("plan.csv");
+        std::fs::write(&path, &buf).unwrap();
+
+        let rows = read_plan_csv(&path).unwrap();
+        assert_eq!(rows, plan_to_rows(&plan));
+        assert!(diff_rows(&plan, &rows).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_diff_rows_detects_edits() {
+        let mut plan = sample_plan();
+        let mut rows = plan_to_rows(&plan);
+        rows[0].status = "Skip".to_string();
+        rows[1].tmdb_id = Some(603);
+
+        let edits = diff_rows(&plan, &rows).unwrap();
+        assert_eq!(
+            edits,
+            vec![
+                CsvEdit::Status {
+                    source: PathBuf::from("/src/avatar.mkv"),
+                    status: PlanItemStatus::Skip,
+                },
+                CsvEdit::Rematch {
+                    source: PathBuf::from("/src/random.mkv"),
+                    tmdb_id: 603,
+                },
+            ]
+        );
+
+        assert_eq!(apply_status_edits(&mut plan, &edits), 1);
+        assert_eq!(plan.items[0].status, PlanItemStatus::Skip);
+    }
+
+    #[test]
+    fn test_diff_rows_rejects_invalid_status() {
+        let plan = sample_plan();
+        let mut rows = plan_to_rows(&plan);
+        rows[0].status = "done".to_string();
+        assert!(diff_rows(&plan, &rows).is_err());
+    }
+}
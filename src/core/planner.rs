@@ -8,7 +8,9 @@
 //! 5. Generate target paths and operations
 //! 6. Output plan.json
 
+use crate::core::dedup;
 use crate::core::metadata::{self, CandidateMetadata, DirectoryType};
+use crate::core::migration::{self, SchemaKind};
 use crate::core::parser::{self, FilenameParser, ParsedFilename};
 use crate::core::scanner::scan_directory;
 use crate::generators::{filename as gen_filename, folder as gen_folder};
@@ -19,6 +21,7 @@ use crate::models::plan::{
     Operation, OperationType, ParsedInfo, Plan, PlanItem, PlanItemStatus, SampleItem, TargetInfo,
     UnknownItem,
 };
+use crate::models::session::SessionManifest;
 use crate::services::ffprobe;
 use crate::services::tmdb::{Credits, MovieDetails, TmdbClient};
 use crate::Result;
@@ -32,6 +35,9 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+/// Callback receiving (processed, total) video counts during planning.
+pub type ProgressCallback = Arc<dyn Fn(u64, u64) + Send + Sync>;
+
 /// Cache for season episodes: (tmdb_id, season_number) -> Vec<EpisodeInfo>
 type SeasonEpisodesCache =
     Arc<RwLock<HashMap<(u64, u16), Vec<crate::services::tmdb::EpisodeInfo>>>>;
@@ -165,6 +171,7 @@ pub struct Planner {
     config: PlannerConfig,
     parser: FilenameParser,
     tmdb_client: Option<TmdbClient>,
+    progress: Option<ProgressCallback>,
 }
 
 impl Planner {
@@ -175,6 +182,7 @@ impl Planner {
             config: PlannerConfig::default(),
             parser: FilenameParser::new(),
             tmdb_client,
+            progress: None,
         })
     }
 
@@ -185,9 +193,24 @@ impl Planner {
             config,
             parser: FilenameParser::new(),
             tmdb_client,
+            progress: None,
         })
     }
 
+    /// Report planning progress to a callback in addition to the progress bar.
+    pub fn with_progress(mut self, progress: ProgressCallback) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Advance the progress bar and notify the progress callback.
+    fn advance(&self, pb: &ProgressBar) {
+        pb.inc(1);
+        if let Some(ref progress) = self.progress {
+            progress(pb.position(), pb.length().unwrap_or(0));
+        }
+    }
+
     /// Generate a plan for organizing videos.
     pub async fn generate(
         &self,
@@ -212,10 +235,17 @@ impl Planner {
             tracing::warn!("No video files found in {:?}", source);
         }
 
+        // Step 1.5: Set aside copies with the same content as another video
+        let (videos, mut duplicates) = dedup::split_duplicate_videos(&scan_result.videos);
+        if !duplicates.is_empty() {
+            println!("   Skipping {} duplicate copies", duplicates.len());
+        }
+
         // Step 2: Process videos (pass source for correct cache key calculation)
-        let (mut items, unknown) = self
-            .process_videos(&scan_result.videos, source, target, media_type)
+        let (mut items, mut unknown) = self
+            .process_videos(&videos, source, target, media_type)
             .await?;
+        unknown.append(&mut duplicates);
 
         // Step 3: Process samples
         let samples = self.process_samples(&scan_result.samples, &items, target);
@@ -230,7 +260,7 @@ impl Planner {
 
         // Step 4: Create plan
         let plan = Plan {
-            version: "1.0".to_string(),
+            version: migration::PLAN_VERSION.to_string(),
             created_at: Utc::now().to_rfc3339(),
             media_type: Some(media_type),
             source_path: source.to_path_buf(),
@@ -336,7 +366,7 @@ impl Planner {
                         }
                     }
                     items.push(item);
-                    pb.inc(1);
+                    self.advance(&pb);
 
                     // Process remaining files using cached metadata
                     // Skip the representative video (already processed above)
@@ -441,7 +471,7 @@ impl Planner {
                                 }
                             }
                         }
-                        pb.inc(1);
+                        self.advance(&pb);
                     }
                 }
                 Ok(None) => {
@@ -450,7 +480,7 @@ impl Planner {
                             source: video.clone(),
                             reason: "Failed to parse or find metadata for directory".to_string(),
                         });
-                        pb.inc(1);
+                        self.advance(&pb);
                     }
                 }
                 Err(e) => {
@@ -460,7 +490,7 @@ impl Planner {
                             source: video.clone(),
                             reason: e.to_string(),
                         });
-                        pb.inc(1);
+                        self.advance(&pb);
                     }
                 }
             }
@@ -479,10 +509,14 @@ impl Planner {
 
         const CONCURRENT_LIMIT: usize = 8;
 
-        let results: Vec<_> = stream::iter(videos)
-            .map(|video| {
-                let path = video.path.clone();
-                let filename = video.filename.clone();
+        // Owned inputs keep the stream future `Send` for spawned callers
+        let inputs: Vec<(PathBuf, String)> = videos
+            .iter()
+            .map(|video| (video.path.clone(), video.filename.clone()))
+            .collect();
+
+        let results: Vec<_> = stream::iter(inputs)
+            .map(|(path, filename)| {
                 async move {
                     let ffprobe_result = ffprobe::extract_metadata(&path);
                     let filename_meta = ffprobe::parse_metadata_from_filename(&filename);
@@ -531,9 +565,11 @@ impl Planner {
             );
 
             // Create metadata with extracted IDs
-            let mut path_meta = metadata::CandidateMetadata::default();
-            path_meta.tmdb_id = path_tmdb_id;
-            path_meta.imdb_id = path_imdb_id.clone();
+            let path_meta = metadata::CandidateMetadata {
+                tmdb_id: path_tmdb_id,
+                imdb_id: path_imdb_id.clone(),
+                ..Default::default()
+            };
 
             if media_type == MediaType::Movies {
                 if let Some(movie_metadata) = self.try_direct_id_lookup(&path_meta).await? {
@@ -597,6 +633,7 @@ impl Planner {
                             video_metadata,
                             target: target_info,
                             operations,
+                            placement: None,
                         },
                         None,
                     )));
@@ -759,6 +796,7 @@ impl Planner {
                                     video_metadata,
                                     target: target_info,
                                     operations,
+                                    placement: None,
                                 },
                                 Some(show_metadata),
                             )));
@@ -856,6 +894,7 @@ impl Planner {
                     video_metadata: video_metadata.clone(),
                     target: target_info,
                     operations,
+                    placement: None,
                     status: PlanItemStatus::Pending,
                 };
 
@@ -1010,6 +1049,7 @@ impl Planner {
             video_metadata: video_metadata.clone(),
             target: target_info,
             operations,
+            placement: None,
             status: PlanItemStatus::Pending,
         };
 
@@ -1271,6 +1311,7 @@ impl Planner {
             video_metadata: video_metadata.clone(),
             target: target_info,
             operations,
+            placement: None,
             status: PlanItemStatus::Pending,
         };
 
@@ -1534,6 +1575,7 @@ impl Planner {
             video_metadata: video_metadata.clone(),
             target: target_info,
             operations,
+            placement: None,
             status: PlanItemStatus::Pending,
         };
 
@@ -2147,6 +2189,7 @@ impl Planner {
             video_metadata,
             target: target_info,
             operations,
+            placement: None,
         };
 
         // Return item and tvshow metadata for caching
@@ -2481,7 +2524,7 @@ impl Planner {
 
     /// SAFETY CHECK: Validate that no two items have the same target path.
     /// This prevents data loss from files overwriting each other.
-    fn validate_no_duplicate_targets(&self, items: &[PlanItem]) -> Result<()> {
+    pub fn validate_no_duplicate_targets(&self, items: &[PlanItem]) -> Result<()> {
         use std::collections::HashMap;
 
         let mut target_to_sources: HashMap<PathBuf, Vec<PathBuf>> = HashMap::new();
@@ -2650,7 +2693,7 @@ impl Planner {
     fn strip_sorting_prefix(name: &str) -> &str {
         // Pattern 1: Single ASCII letter + separator (_, -, .)
         // e.g., "A_剧名", "X-电影", "Z.标题"
-        if let Some(re) = regex::Regex::new(r"^[A-Za-z][_\-.]").ok() {
+        if let Ok(re) = regex::Regex::new(r"^[A-Za-z][_\-.]") {
             if re.is_match(name) && name.len() > 2 {
                 let stripped = &name[2..];
                 if !stripped.is_empty() {
@@ -2662,7 +2705,7 @@ impl Planner {
 
         // Pattern 2: Numbers + separator (_, -, .)
         // e.g., "01_剧名", "001-电影", "1.标题"
-        if let Some(re) = regex::Regex::new(r"^(\d{1,3})[_\-.]").ok() {
+        if let Ok(re) = regex::Regex::new(r"^(\d{1,3})[_\-.]") {
             if let Some(caps) = re.captures(name) {
                 let prefix_len = caps.get(0).map(|m| m.len()).unwrap_or(0);
                 if prefix_len > 0 && name.len() > prefix_len {
@@ -3295,7 +3338,7 @@ impl Planner {
             return None;
         }
 
-        scored_results.sort_by(|a, b| b.1.cmp(&a.1));
+        scored_results.sort_by_key(|r| std::cmp::Reverse(r.1));
         let (best_idx, best_score, best_exact) = scored_results[0];
 
         // Ambiguity check for non-exact matches
@@ -3737,7 +3780,7 @@ impl Planner {
         }
 
         // Sort by score descending
-        scored_results.sort_by(|a, b| b.1.cmp(&a.1));
+        scored_results.sort_by_key(|r| std::cmp::Reverse(r.1));
 
         let (best_idx, best_score) = scored_results[0];
 
@@ -4012,7 +4055,7 @@ impl Planner {
         }
 
         // Sort by score descending
-        scored_results.sort_by(|a, b| b.1.cmp(&a.1));
+        scored_results.sort_by_key(|r| std::cmp::Reverse(r.1));
 
         let (best_idx, best_score, best_exact) = scored_results[0];
 
@@ -4327,6 +4370,116 @@ impl Planner {
             video_metadata,
             target: target_info,
             operations,
+            placement: None,
+        })
+    }
+
+    /// Re-match a single video against an explicit TMDB ID.
+    ///
+    /// Used when a user corrects a match outside the planner (e.g. via CSV import).
+    /// The TMDB ID is trusted as-is, so the resulting item has full confidence.
+    pub async fn rematch_item(
+        &self,
+        video: &VideoFile,
+        tmdb_id: u64,
+        target: &Path,
+        media_type: MediaType,
+    ) -> Result<PlanItem> {
+        let tmdb = self
+            .tmdb_client
+            .as_ref()
+            .ok_or_else(|| crate::Error::other("TMDB client not initialized"))?;
+
+        let video_metadata = {
+            let ffprobe_meta = ffprobe::extract_metadata(&video.path).unwrap_or_default();
+            let filename_meta = ffprobe::parse_metadata_from_filename(&video.filename);
+            ffprobe::merge_metadata(ffprobe_meta, filename_meta)
+        };
+
+        let (movie_metadata, tvshow_metadata, parsed) = match media_type {
+            MediaType::Movies => {
+                let movie = self
+                    .get_movie_details(tmdb, tmdb_id)
+                    .await?
+                    .ok_or_else(|| crate::Error::MovieNotFound(format!("tmdb{}", tmdb_id)))?;
+                let parsed = ParsedFilename {
+                    title: Some(movie.title.clone()),
+                    original_title: Some(movie.original_title.clone()),
+                    year: Some(movie.year),
+                    confidence: 1.0,
+                    raw_response: Some("manual_tmdb_id".to_string()),
+                    ..Default::default()
+                };
+                (Some(movie), None, parsed)
+            }
+            MediaType::TvShows => {
+                let (season, episode) = parser::extract_episode_from_filename(&video.filename);
+                let episode = episode.ok_or_else(|| {
+                    crate::Error::ParseError(format!(
+                        "Cannot extract episode number from {}",
+                        video.filename
+                    ))
+                })?;
+                let season = season.unwrap_or(1);
+
+                let show = self.fetch_tvshow_by_id(tmdb_id).await?;
+                let season_cache: SeasonEpisodesCache = Arc::new(RwLock::new(HashMap::new()));
+                let ep_meta = self
+                    .get_episode_from_cache(tmdb_id, season, episode, &season_cache)
+                    .await;
+                let parsed = ParsedFilename {
+                    title: Some(show.name.clone()),
+                    original_title: Some(show.original_name.clone()),
+                    year: Some(show.year),
+                    season: Some(season),
+                    episode: Some(episode),
+                    confidence: 1.0,
+                    raw_response: Some("manual_tmdb_id".to_string()),
+                };
+                (None, Some((show, ep_meta)), parsed)
+            }
+        };
+
+        let (target_info, operations) = self
+            .generate_target_info(
+                video,
+                &movie_metadata,
+                &tvshow_metadata,
+                &parsed,
+                &video_metadata,
+                target,
+                media_type,
+            )?
+            .ok_or_else(|| {
+                crate::Error::other(format!(
+                    "Failed to generate target info for tmdb{}",
+                    tmdb_id
+                ))
+            })?;
+
+        let (tvshow_metadata, episode_metadata) = match tvshow_metadata {
+            Some((show, ep)) => (Some(show), ep),
+            None => (None, None),
+        };
+
+        Ok(PlanItem {
+            id: Uuid::new_v4().to_string(),
+            status: PlanItemStatus::Pending,
+            source: video.clone(),
+            parsed: ParsedInfo {
+                title: parsed.title,
+                original_title: parsed.original_title,
+                year: parsed.year,
+                confidence: parsed.confidence,
+                raw_response: parsed.raw_response,
+            },
+            movie_metadata,
+            tvshow_metadata,
+            episode_metadata,
+            video_metadata,
+            target: target_info,
+            operations,
+            placement: None,
         })
     }
 
@@ -4362,10 +4515,10 @@ impl Planner {
 
                 // Extract disc identifier from source filename (cd1, cd2, part1, part2, etc.)
                 let disc_id = gen_filename::extract_disc_identifier(&video.filename);
-                if disc_id.is_some() {
+                if let Some(ref disc) = disc_id {
                     tracing::debug!(
                         "[MULTI-DISC] Detected disc identifier '{}' in: {}",
-                        disc_id.as_ref().unwrap(),
+                        disc,
                         video.filename
                     );
                 }
@@ -4767,8 +4920,7 @@ pub fn save_plan(plan: &Plan, path: &Path) -> Result<()> {
 /// Load a plan from a JSON file.
 pub fn load_plan(path: &Path) -> Result<Plan> {
     let content = fs::read_to_string(path)?;
-    let plan: Plan = serde_json::from_str(&content)?;
-    Ok(plan)
+    migration::load_json(SchemaKind::Plan, &content)
 }
 
 /// Get the default plan output path.
@@ -4793,19 +4945,26 @@ pub fn sessions_dir() -> Result<PathBuf> {
 
 /// Save plan to sessions directory.
 pub fn save_to_sessions(plan: &Plan) -> Result<PathBuf> {
+    save_to_sessions_in(&sessions_dir()?, plan)
+}
+
+/// Save plan to a session under the given sessions directory.
+pub fn save_to_sessions_in(sessions: &Path, plan: &Plan) -> Result<PathBuf> {
     let session_id = format!(
         "{}_{}",
         Utc::now().format("%Y%m%d_%H%M%S"),
         &Uuid::new_v4().to_string()[..8]
     );
 
-    let sessions = sessions_dir()?;
     let session_dir = sessions.join(&session_id);
     fs::create_dir_all(&session_dir)?;
 
     let plan_path = session_dir.join("plan.json");
     save_plan(plan, &plan_path)?;
 
+    let manifest = SessionManifest::planned(&plan.created_at);
+    crate::core::sessions::save_manifest(&session_dir, &manifest)?;
+
     tracing::info!("Session saved: {}", session_id);
     Ok(session_dir)
 }
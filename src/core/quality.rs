@@ -0,0 +1,339 @@
+//! Video quality scoring for upgrade and duplicate reports.
+//!
+//! A movie copy is scored from 0 to 100 out of its resolution (45 points),
+//! bitrate relative to what its resolution calls for (20), codec (20) and
+//! HDR format (15). Unknown bitrates and codecs count as average so missing
+//! metadata does not push a title to the top of the upgrade list.
+
+use crate::models::index::MovieEntry;
+use serde::Serialize;
+
+/// Comparable quality of one movie copy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quality {
+    /// 0 unknown, 1 SD, 2 720p, 3 1080p, 4 2160p
+    resolution: u8,
+    /// 0 legacy (xvid, mpeg2...), 1 h264 or unknown, 2 hevc/vp9, 3 av1
+    codec: u8,
+    /// 0 SDR, 1 HDR10/HLG, 2 Dolby Vision
+    hdr: u8,
+    bitrate_kbps: Option<u32>,
+    size_bytes: u64,
+}
+
+impl Quality {
+    /// Quality of a movie copy.
+    pub fn of(movie: &MovieEntry) -> Self {
+        Self {
+            resolution: resolution_rank(movie.resolution.as_deref()),
+            codec: codec_rank(movie.video_codec.as_deref()),
+            hdr: match movie.hdr.as_deref() {
+                None => 0,
+                Some(h) if h.eq_ignore_ascii_case("dolby vision") => 2,
+                Some(_) => 1,
+            },
+            bitrate_kbps: movie.bitrate_kbps,
+            size_bytes: movie.size_bytes,
+        }
+    }
+
+    /// Overall score from 0 to 100.
+    pub fn score(&self) -> u32 {
+        let resolution = [0.0, 10.0, 25.0, 35.0, 45.0][self.resolution as usize];
+        let codec = [5.0, 10.0, 15.0, 20.0][self.codec as usize];
+        let hdr = [0.0, 10.0, 15.0][self.hdr as usize];
+        let bitrate = match self.bitrate_kbps {
+            Some(kbps) => 20.0 * (kbps as f64 / self.target_bitrate() as f64).min(1.0),
+            None => 10.0,
+        };
+        (resolution + codec + hdr + bitrate).round() as u32
+    }
+
+    /// Bitrate in kbit/s a good copy at this resolution reaches.
+    fn target_bitrate(&self) -> u32 {
+        match self.resolution {
+            4 => 20_000,
+            2 => 4_000,
+            1 => 1_500,
+            _ => 8_000,
+        }
+    }
+
+    /// Whether this copy is no better than `other` in any respect.
+    ///
+    /// Bitrates are compared when both are known, sizes otherwise.
+    pub fn is_dominated_by(&self, other: &Quality) -> bool {
+        let bitrate_or_size = match (self.bitrate_kbps, other.bitrate_kbps) {
+            (Some(a), Some(b)) => a <= b,
+            _ => self.size_bytes <= other.size_bytes,
+        };
+        self.resolution <= other.resolution
+            && self.codec <= other.codec
+            && self.hdr <= other.hdr
+            && bitrate_or_size
+    }
+
+    /// Why this copy is worth replacing.
+    pub fn weaknesses(&self) -> Vec<String> {
+        let mut reasons = Vec::new();
+        match self.resolution {
+            0 => reasons.push("unknown resolution".to_string()),
+            1 => reasons.push("SD".to_string()),
+            2 => reasons.push("720p".to_string()),
+            _ => {}
+        }
+        if self.codec == 0 {
+            reasons.push("legacy codec".to_string());
+        }
+        if let Some(kbps) = self.bitrate_kbps {
+            if kbps < self.target_bitrate() / 2 {
+                reasons.push(format!("low bitrate ({} kbps)", kbps));
+            }
+        }
+        reasons
+    }
+}
+
+/// Rank of a resolution string ("2160p", "4K", "1080p"...).
+pub fn resolution_rank(resolution: Option<&str>) -> u8 {
+    let Some(resolution) = resolution else {
+        return 0;
+    };
+    let lower = resolution.to_lowercase();
+    match lower.as_str() {
+        "4k" | "uhd" | "2160p" => 4,
+        "1080p" | "1080i" => 3,
+        "720p" => 2,
+        _ => match lower.trim_end_matches(['p', 'i']).parse::<u32>() {
+            Ok(lines) if lines >= 2160 => 4,
+            Ok(lines) if lines >= 1080 => 3,
+            Ok(lines) if lines >= 720 => 2,
+            Ok(_) => 1,
+            Err(_) => 0,
+        },
+    }
+}
+
+fn codec_rank(codec: Option<&str>) -> u8 {
+    match codec.map(str::to_lowercase).as_deref() {
+        Some("av1") => 3,
+        Some("hevc" | "h265" | "vp9") => 2,
+        Some("h264" | "avc") | None => 1,
+        Some(_) => 0,
+    }
+}
+
+/// A title whose best copy is worth replacing.
+#[derive(Debug, Clone, Serialize)]
+pub struct UpgradeCandidate {
+    pub title: String,
+    pub year: Option<u16>,
+    pub tmdb_id: Option<u64>,
+    pub rating: Option<f32>,
+    /// Score of the best copy owned
+    pub score: u32,
+    pub resolution: Option<String>,
+    pub video_codec: Option<String>,
+    pub hdr: Option<String>,
+    pub bitrate_kbps: Option<u32>,
+    pub size_bytes: u64,
+    pub disk: String,
+    pub path: String,
+    /// Number of copies owned
+    pub copies: usize,
+    pub reasons: Vec<String>,
+}
+
+/// Find titles whose best copy scores below `max_score`.
+///
+/// With `max_resolution`, only titles not owned in a higher resolution are
+/// listed. Candidates come lowest score first, better-rated films first
+/// among equal scores.
+pub fn upgrade_candidates(
+    movies: &[MovieEntry],
+    max_score: u32,
+    max_resolution: Option<&str>,
+) -> Vec<UpgradeCandidate> {
+    let max_rank = max_resolution.map(|r| resolution_rank(Some(r)));
+
+    // Copies of the same film are grouped by TMDB ID
+    let mut titles: Vec<Vec<&MovieEntry>> = Vec::new();
+    for movie in movies {
+        let group = movie
+            .tmdb_id
+            .and_then(|id| titles.iter_mut().find(|g| g[0].tmdb_id == Some(id)));
+        match group {
+            Some(group) => group.push(movie),
+            None => titles.push(vec![movie]),
+        }
+    }
+
+    let mut candidates: Vec<UpgradeCandidate> = titles
+        .into_iter()
+        .filter_map(|copies| {
+            let (best, quality) = copies
+                .iter()
+                .map(|m| (*m, Quality::of(m)))
+                .max_by_key(|(m, q)| (q.score(), m.size_bytes))?;
+            let score = quality.score();
+            // The best-scoring copy is not always the sharpest one
+            let top_resolution = copies
+                .iter()
+                .map(|m| resolution_rank(m.resolution.as_deref()))
+                .max()
+                .unwrap_or(0);
+            if score >= max_score || max_rank.is_some_and(|max| top_resolution > max) {
+                return None;
+            }
+            Some(UpgradeCandidate {
+                title: best.title.clone(),
+                year: best.year,
+                tmdb_id: best.tmdb_id,
+                rating: best.rating,
+                score,
+                resolution: best.resolution.clone(),
+                video_codec: best.video_codec.clone(),
+                hdr: best.hdr.clone(),
+                bitrate_kbps: best.bitrate_kbps,
+                size_bytes: best.size_bytes,
+                disk: best.disk.clone(),
+                path: best.relative_path.clone(),
+                copies: copies.len(),
+                reasons: quality.weaknesses(),
+            })
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| {
+        a.score
+            .cmp(&b.score)
+            .then_with(|| b.rating.unwrap_or(0.0).total_cmp(&a.rating.unwrap_or(0.0)))
+    });
+    candidates
+}
+
+/// What to do with one copy of a duplicated movie.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Recommendation {
+    /// The best copy
+    Keep,
+    /// No better than the kept copy in any respect
+    Delete,
+    /// Better than the kept copy in some respect; decide manually
+    Review,
+}
+
+/// Recommend which copies of a movie to keep and delete.
+///
+/// The highest-scoring copy is kept (the larger one on ties); other copies
+/// are deleted when they are no better in any respect.
+pub fn recommend_copies(copies: &[&MovieEntry]) -> Vec<Recommendation> {
+    let qualities: Vec<Quality> = copies.iter().map(|m| Quality::of(m)).collect();
+    let Some(best) =
+        (0..copies.len()).max_by_key(|&i| (qualities[i].score(), copies[i].size_bytes))
+    else {
+        return Vec::new();
+    };
+
+    qualities
+        .iter()
+        .enumerate()
+        .map(|(i, quality)| {
+            if i == best {
+                Recommendation::Keep
+            } else if quality.is_dominated_by(&qualities[best]) {
+                Recommendation::Delete
+            } else {
+                Recommendation::Review
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn copy(resolution: &str, codec: &str, hdr: Option<&str>, kbps: u32) -> MovieEntry {
+        MovieEntry {
+            id: format!("{}-{}", resolution, kbps),
+            disk: "D1".to_string(),
+            title: "Film".to_string(),
+            year: Some(2010),
+            tmdb_id: Some(1),
+            runtime: Some(120),
+            rating: Some(8.0),
+            size_bytes: kbps as u64 * 1000 / 8 * 7200,
+            resolution: Some(resolution.to_string()),
+            video_codec: Some(codec.to_string()),
+            hdr: hdr.map(|h| h.to_string()),
+            bitrate_kbps: Some(kbps),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_score() {
+        let best = Quality::of(&copy("2160p", "av1", Some("Dolby Vision"), 30_000));
+        assert_eq!(best.score(), 100);
+        let sd = Quality::of(&copy("480p", "xvid", None, 1_000));
+        assert!(sd.score() < 30);
+        assert_eq!(sd.weaknesses(), vec!["SD", "legacy codec"]);
+        assert!(Quality::of(&copy("720p", "h264", None, 1_500))
+            .weaknesses()
+            .contains(&"low bitrate (1500 kbps)".to_string()));
+    }
+
+    #[test]
+    fn test_upgrade_candidates_use_best_copy() {
+        let mut other = copy("1080p", "h264", None, 9_000);
+        other.tmdb_id = Some(2);
+        other.title = "Other".to_string();
+        let movies = vec![
+            copy("720p", "h264", None, 4_000),
+            copy("2160p", "hevc", Some("HDR10"), 20_000),
+            other,
+        ];
+
+        // The 4K copy lifts the first film out of the list
+        let candidates = upgrade_candidates(&movies, 80, None);
+        let titles: Vec<&str> = candidates.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, vec!["Other"]);
+        assert!(upgrade_candidates(&movies, 80, Some("720p")).is_empty());
+    }
+
+    #[test]
+    fn test_max_resolution_considers_every_copy() {
+        // A starved 4K encode scores below a good 1080p copy of the same film
+        let hd = copy("1080p", "hevc", Some("HDR10"), 15_000);
+        let uhd = copy("2160p", "h264", None, 3_000);
+        assert!(Quality::of(&hd).score() > Quality::of(&uhd).score());
+
+        let movies = vec![hd, uhd];
+        assert_eq!(upgrade_candidates(&movies, 101, Some("2160p")).len(), 1);
+        assert!(upgrade_candidates(&movies, 101, Some("1080p")).is_empty());
+    }
+
+    #[test]
+    fn test_recommend_copies() {
+        let uhd = copy("2160p", "hevc", Some("HDR10"), 20_000);
+        let hd = copy("1080p", "h264", None, 8_000);
+        let hd_hevc = copy("1080p", "hevc", None, 6_000);
+        let big_hd = copy("1080p", "h264", None, 30_000);
+
+        assert_eq!(
+            recommend_copies(&[&hd, &uhd, &hd_hevc]),
+            vec![
+                Recommendation::Delete,
+                Recommendation::Keep,
+                Recommendation::Delete
+            ]
+        );
+        // A higher bitrate is worth a look even at a lower resolution
+        assert_eq!(
+            recommend_copies(&[&uhd, &big_hd]),
+            vec![Recommendation::Keep, Recommendation::Review]
+        );
+    }
+}
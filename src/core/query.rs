@@ -0,0 +1,849 @@
+//! Search query language.
+//!
+//! A query is a list of terms matched against index entries:
+//!
+//! ```text
+//! actor:"Tony Leung" year:2000..2010 rating>7.5 resolution:2160p -genre:horror sort:rating limit:20
+//! ```
+//!
+//! Terms are combined with AND. `OR` and parentheses group alternatives,
+//! `-term` or `NOT term` negates a term. A term is `field:value`, a
+//! comparison (`rating>=8`, `size<4GB`) or a bare word matched against titles.
+//! Numeric fields also accept ranges (`year:2000..2010`, `runtime:..90`).
+//! `sort:field[:asc|desc]` and `limit:N` order and cap each result list.
+
+use super::indexer::SearchResults;
+use super::title_match::TitleQuery;
+use crate::models::index::{MovieEntry, TvShowEntry, UserData};
+use anyhow::{anyhow, bail, Result};
+use std::cmp::Ordering;
+
+/// A parsed search query.
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    filter: Option<Expr>,
+    sort: Option<Sort>,
+    limit: Option<usize>,
+}
+
+/// Filter expression.
+#[derive(Debug, Clone)]
+enum Expr {
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+    Term(Field, Condition),
+}
+
+/// Entry field a term matches against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Title,
+    Actor,
+    Director,
+    Genre,
+    Country,
+    Collection,
+    Disk,
+    Resolution,
+    Codec,
+    Hdr,
+    Type,
+    Imdb,
+    Year,
+    Rating,
+    Runtime,
+    Bitrate,
+    Size,
+    Tmdb,
+    Seasons,
+    Episodes,
+    Watched,
+    Favorite,
+    Tag,
+    Notes,
+    MyRating,
+    Plays,
+}
+
+impl Field {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "title" => Self::Title,
+            "actor" => Self::Actor,
+            "director" => Self::Director,
+            "genre" => Self::Genre,
+            "country" => Self::Country,
+            "collection" => Self::Collection,
+            "disk" => Self::Disk,
+            "resolution" | "res" => Self::Resolution,
+            "codec" => Self::Codec,
+            "hdr" => Self::Hdr,
+            "type" => Self::Type,
+            "imdb" => Self::Imdb,
+            "year" => Self::Year,
+            "rating" => Self::Rating,
+            "runtime" => Self::Runtime,
+            "bitrate" => Self::Bitrate,
+            "size" => Self::Size,
+            "tmdb" => Self::Tmdb,
+            "seasons" => Self::Seasons,
+            "episodes" => Self::Episodes,
+            "watched" => Self::Watched,
+            "favorite" | "fav" => Self::Favorite,
+            "tag" => Self::Tag,
+            "notes" => Self::Notes,
+            "myrating" => Self::MyRating,
+            "plays" => Self::Plays,
+            _ => return None,
+        })
+    }
+
+    fn is_numeric(self) -> bool {
+        matches!(
+            self,
+            Self::Year
+                | Self::Rating
+                | Self::Runtime
+                | Self::Bitrate
+                | Self::Size
+                | Self::Tmdb
+                | Self::Seasons
+                | Self::Episodes
+                | Self::MyRating
+                | Self::Plays
+        )
+    }
+}
+
+/// How a term compares a field.
+#[derive(Debug, Clone)]
+enum Condition {
+    /// Fuzzy title match.
+    Title(TitleQuery),
+    /// Case-insensitive substring.
+    Contains(String),
+    /// Case-insensitive equality.
+    Equals(String),
+    Compare(Op, f64),
+    /// Inclusive range; either end may be open.
+    Range(Option<f64>, Option<f64>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+/// Sort order requested with `sort:`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Sort {
+    key: SortKey,
+    descending: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SortKey {
+    Relevance,
+    Year,
+    Rating,
+    Runtime,
+    Size,
+    Title,
+    Added,
+    Disk,
+}
+
+impl SortKey {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "relevance" => Self::Relevance,
+            "year" => Self::Year,
+            "rating" => Self::Rating,
+            "runtime" => Self::Runtime,
+            "size" => Self::Size,
+            "title" => Self::Title,
+            "added" => Self::Added,
+            "disk" => Self::Disk,
+            _ => return None,
+        })
+    }
+
+    /// Text keys sort A-Z by default, everything else largest first.
+    fn descending_by_default(self) -> bool {
+        !matches!(self, Self::Title | Self::Disk)
+    }
+}
+
+/// A movie or TV show being matched.
+#[derive(Clone, Copy)]
+enum Entry<'a> {
+    Movie(&'a MovieEntry),
+    TvShow(&'a TvShowEntry),
+}
+
+impl Entry<'_> {
+    fn user_data(&self) -> &UserData {
+        match self {
+            Entry::Movie(m) => &m.user_data,
+            Entry::TvShow(t) => &t.user_data,
+        }
+    }
+
+    fn titles(&self) -> (&str, Option<&str>) {
+        match self {
+            Entry::Movie(m) => (&m.title, m.original_title.as_deref()),
+            Entry::TvShow(t) => (&t.title, t.original_title.as_deref()),
+        }
+    }
+
+    /// Text values of a field; empty when the entry has none.
+    fn text(&self, field: Field) -> Vec<&str> {
+        match (self, field) {
+            (Entry::Movie(m), Field::Actor) => m.actors.iter().map(String::as_str).collect(),
+            (Entry::TvShow(t), Field::Actor) => t.actors.iter().map(String::as_str).collect(),
+            (Entry::Movie(m), Field::Director) => m.directors.iter().map(String::as_str).collect(),
+            (Entry::Movie(m), Field::Genre) => m.genres.iter().map(String::as_str).collect(),
+            (Entry::TvShow(t), Field::Genre) => t.genres.iter().map(String::as_str).collect(),
+            (Entry::Movie(m), Field::Country) => m.country.as_deref().into_iter().collect(),
+            (Entry::TvShow(t), Field::Country) => t.country.as_deref().into_iter().collect(),
+            (Entry::Movie(m), Field::Collection) => {
+                m.collection_name.as_deref().into_iter().collect()
+            }
+            (Entry::Movie(m), Field::Disk) => vec![m.disk.as_str()],
+            (Entry::TvShow(t), Field::Disk) => vec![t.disk.as_str()],
+            (Entry::Movie(m), Field::Resolution) => m.resolution.as_deref().into_iter().collect(),
+            (Entry::Movie(m), Field::Codec) => m.video_codec.as_deref().into_iter().collect(),
+            (Entry::Movie(m), Field::Hdr) => m.hdr.as_deref().into_iter().collect(),
+            (Entry::Movie(_), Field::Type) => vec!["movie"],
+            (Entry::TvShow(_), Field::Type) => vec!["tvshow"],
+            (Entry::Movie(m), Field::Imdb) => m.imdb_id.as_deref().into_iter().collect(),
+            (Entry::TvShow(t), Field::Imdb) => t.imdb_id.as_deref().into_iter().collect(),
+            (_, Field::Watched) => vec![yes_no(self.user_data().watched)],
+            (_, Field::Favorite) => vec![yes_no(self.user_data().favorite)],
+            (_, Field::Tag) => self.user_data().tags.iter().map(String::as_str).collect(),
+            (_, Field::Notes) => self.user_data().notes.as_deref().into_iter().collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn number(&self, field: Field) -> Option<f64> {
+        match (self, field) {
+            (Entry::Movie(m), Field::Year) => m.year.map(f64::from),
+            (Entry::TvShow(t), Field::Year) => t.year.map(f64::from),
+            (Entry::Movie(m), Field::Rating) => m.rating.map(f64::from),
+            (Entry::Movie(m), Field::Runtime) => m.runtime.map(f64::from),
+            (Entry::Movie(m), Field::Bitrate) => m.bitrate_kbps.map(f64::from),
+            (Entry::Movie(m), Field::Size) => Some(m.size_bytes as f64),
+            (Entry::TvShow(t), Field::Size) => Some(t.size_bytes as f64),
+            (Entry::Movie(m), Field::Tmdb) => m.tmdb_id.map(|id| id as f64),
+            (Entry::TvShow(t), Field::Tmdb) => t.tmdb_id.map(|id| id as f64),
+            (Entry::TvShow(t), Field::Seasons) => Some(f64::from(t.seasons)),
+            (Entry::TvShow(t), Field::Episodes) => Some(f64::from(t.episodes)),
+            (_, Field::MyRating) => self.user_data().rating.map(f64::from),
+            (_, Field::Plays) => Some(f64::from(self.user_data().play_count)),
+            _ => None,
+        }
+    }
+
+    fn sort_text(&self, key: SortKey) -> &str {
+        match (self, key) {
+            (Entry::Movie(m), SortKey::Title) => &m.title,
+            (Entry::TvShow(t), SortKey::Title) => &t.title,
+            (Entry::Movie(m), SortKey::Added) => &m.indexed_at,
+            (Entry::TvShow(t), SortKey::Added) => &t.indexed_at,
+            (Entry::Movie(m), _) => &m.disk,
+            (Entry::TvShow(t), _) => &t.disk,
+        }
+    }
+}
+
+impl Expr {
+    fn matches(&self, entry: Entry) -> bool {
+        match self {
+            Expr::And(exprs) => exprs.iter().all(|e| e.matches(entry)),
+            Expr::Or(exprs) => exprs.iter().any(|e| e.matches(entry)),
+            Expr::Not(expr) => !expr.matches(entry),
+            Expr::Term(field, condition) => condition.matches(*field, entry),
+        }
+    }
+
+    /// Summed score of the title terms an entry must match.
+    fn relevance(&self, entry: Entry) -> f64 {
+        match self {
+            Expr::And(exprs) => exprs.iter().map(|e| e.relevance(entry)).sum(),
+            Expr::Or(exprs) => exprs.iter().map(|e| e.relevance(entry)).fold(0.0, f64::max),
+            Expr::Not(_) => 0.0,
+            Expr::Term(_, Condition::Title(query)) => {
+                let (title, original) = entry.titles();
+                query.score_entry(title, original).unwrap_or(0.0)
+            }
+            Expr::Term(..) => 0.0,
+        }
+    }
+
+    fn has_title_term(&self) -> bool {
+        match self {
+            Expr::And(exprs) | Expr::Or(exprs) => exprs.iter().any(Expr::has_title_term),
+            Expr::Not(_) => false,
+            Expr::Term(_, condition) => matches!(condition, Condition::Title(_)),
+        }
+    }
+}
+
+impl Condition {
+    fn matches(&self, field: Field, entry: Entry) -> bool {
+        match self {
+            Condition::Title(query) => {
+                let (title, original) = entry.titles();
+                query.score_entry(title, original).is_some()
+            }
+            Condition::Contains(value) => entry
+                .text(field)
+                .iter()
+                .any(|v| v.to_lowercase().contains(value)),
+            Condition::Equals(value) => entry.text(field).iter().any(|v| {
+                let v = v.to_lowercase();
+                v == *value || (field == Field::Resolution && resolution_alias(&v) == value)
+            }),
+            Condition::Compare(op, value) => entry.number(field).is_some_and(|n| match op {
+                Op::Eq => (n - value).abs() < f64::EPSILON,
+                Op::Gt => n > *value,
+                Op::Ge => n >= *value,
+                Op::Lt => n < *value,
+                Op::Le => n <= *value,
+            }),
+            Condition::Range(start, end) => entry
+                .number(field)
+                .is_some_and(|n| start.is_none_or(|s| n >= s) && end.is_none_or(|e| n <= e)),
+        }
+    }
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+/// Canonical resolution name, so `4k` matches `2160p`.
+fn resolution_alias(value: &str) -> &str {
+    match value {
+        "4k" | "uhd" => "2160p",
+        "fhd" => "1080p",
+        "hd" => "720p",
+        other => other,
+    }
+}
+
+impl Query {
+    /// Parse a query expression.
+    pub fn parse(input: &str) -> Result<Self> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            depth: 0,
+            query: Query::default(),
+        };
+        let filter = parser.parse_or()?;
+        if let Some(token) = parser.tokens.get(parser.pos) {
+            bail!("Unexpected '{}' in query", token);
+        }
+        let mut query = parser.query;
+        query.filter = filter;
+        Ok(query)
+    }
+
+    /// Whether a movie matches the query filter.
+    pub fn matches_movie(&self, movie: &MovieEntry) -> bool {
+        self.matches(Entry::Movie(movie))
+    }
+
+    /// Whether a TV show matches the query filter.
+    pub fn matches_tvshow(&self, tvshow: &TvShowEntry) -> bool {
+        self.matches(Entry::TvShow(tvshow))
+    }
+
+    fn matches(&self, entry: Entry) -> bool {
+        self.filter.as_ref().is_none_or(|f| f.matches(entry))
+    }
+
+    /// Filter, sort and limit search results.
+    ///
+    /// Without `sort:` the incoming order is kept, except that title terms
+    /// rank results by relevance. Collections are left untouched.
+    pub fn apply(&self, results: SearchResults) -> SearchResults {
+        SearchResults {
+            movies: self.select(results.movies, |m| Entry::Movie(m)),
+            tvshows: self.select(results.tvshows, |t| Entry::TvShow(t)),
+            collections: results.collections,
+        }
+    }
+
+    fn select<T>(&self, entries: Vec<T>, entry: fn(&T) -> Entry) -> Vec<T> {
+        let mut entries: Vec<T> = entries
+            .into_iter()
+            .filter(|e| self.matches(entry(e)))
+            .collect();
+
+        let sort = self.sort.or_else(|| {
+            self.filter
+                .as_ref()
+                .filter(|f| f.has_title_term())
+                .map(|_| Sort {
+                    key: SortKey::Relevance,
+                    descending: true,
+                })
+        });
+        if let Some(sort) = sort {
+            entries.sort_by(|a, b| {
+                let ordering = self.compare(sort.key, entry(a), entry(b));
+                if sort.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            });
+        }
+
+        if let Some(limit) = self.limit {
+            entries.truncate(limit);
+        }
+        entries
+    }
+
+    /// Ascending order of two entries; missing values sort first.
+    fn compare(&self, key: SortKey, a: Entry, b: Entry) -> Ordering {
+        let number = |e: Entry| match key {
+            SortKey::Relevance => Some(self.filter.as_ref().map_or(0.0, |f| f.relevance(e))),
+            SortKey::Year => e.number(Field::Year),
+            SortKey::Rating => e.number(Field::Rating),
+            SortKey::Runtime => e.number(Field::Runtime),
+            SortKey::Size => e.number(Field::Size),
+            SortKey::Title | SortKey::Added | SortKey::Disk => None,
+        };
+        match key {
+            SortKey::Title | SortKey::Added | SortKey::Disk => a
+                .sort_text(key)
+                .to_lowercase()
+                .cmp(&b.sort_text(key).to_lowercase()),
+            _ => match (number(a), number(b)) {
+                (Some(x), Some(y)) => x.total_cmp(&y),
+                (x, y) => x.is_some().cmp(&y.is_some()),
+            },
+        }
+    }
+}
+
+/// Query token.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Or,
+    Not,
+    /// A term, with `-` negation and quotes kept for the parser.
+    Word(String),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Open => write!(f, "("),
+            Token::Close => write!(f, ")"),
+            Token::Or => write!(f, "OR"),
+            Token::Not => write!(f, "NOT"),
+            Token::Word(word) => write!(f, "{}", word),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            _ => {
+                let mut word = String::new();
+                let mut quoted = false;
+                while let Some(&c) = chars.peek() {
+                    if !quoted && (c.is_whitespace() || c == '(' || c == ')') {
+                        break;
+                    }
+                    if c == '"' {
+                        quoted = !quoted;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                if quoted {
+                    bail!("Unterminated quote in query: {}", word);
+                }
+                tokens.push(match word.as_str() {
+                    "OR" | "|" => Token::Or,
+                    "NOT" => Token::Not,
+                    "AND" => continue,
+                    _ => Token::Word(word),
+                });
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    /// Nesting depth of groups and negations; directives must be at depth 0.
+    depth: usize,
+    query: Query,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    /// `and (OR and)*`; `None` when the query only holds directives.
+    fn parse_or(&mut self) -> Result<Option<Expr>> {
+        let mut alternatives = Vec::new();
+        let first = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            let next = self.parse_and()?;
+            alternatives.push(next.ok_or_else(|| anyhow!("Expected a term after OR"))?);
+        }
+        if alternatives.is_empty() {
+            return Ok(first);
+        }
+        let first = first.ok_or_else(|| anyhow!("Expected a term before OR"))?;
+        alternatives.insert(0, first);
+        Ok(Some(Expr::Or(alternatives)))
+    }
+
+    fn parse_and(&mut self) -> Result<Option<Expr>> {
+        let mut terms = Vec::new();
+        while let Some(token) = self.peek() {
+            if matches!(token, Token::Or | Token::Close) {
+                break;
+            }
+            if let Some(term) = self.parse_unary()? {
+                terms.push(term);
+            }
+        }
+        Ok(match terms.len() {
+            0 => None,
+            1 => terms.pop(),
+            _ => Some(Expr::And(terms)),
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<Option<Expr>> {
+        let token = self.tokens[self.pos].clone();
+        self.pos += 1;
+        match token {
+            Token::Not => {
+                if self.peek().is_none() {
+                    bail!("Expected a term after NOT");
+                }
+                self.negated(|p| p.parse_unary())
+            }
+            Token::Open => {
+                self.depth += 1;
+                let inner = self.parse_or()?;
+                self.depth -= 1;
+                if self.peek() != Some(&Token::Close) {
+                    bail!("Missing ')' in query");
+                }
+                self.pos += 1;
+                Ok(Some(
+                    inner.ok_or_else(|| anyhow!("Empty parentheses in query"))?,
+                ))
+            }
+            Token::Word(word) if word.len() > 1 && word.starts_with('-') => {
+                self.negated(|p| p.parse_word(&word[1..]))
+            }
+            Token::Word(word) => self.parse_word(&word),
+            Token::Close | Token::Or => bail!("Unexpected '{}' in query", token),
+        }
+    }
+
+    fn negated(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<Option<Expr>>,
+    ) -> Result<Option<Expr>> {
+        self.depth += 1;
+        let inner = parse(self)?;
+        self.depth -= 1;
+        let inner = inner.ok_or_else(|| anyhow!("Expected a term after negation"))?;
+        Ok(Some(Expr::Not(Box::new(inner))))
+    }
+
+    fn parse_word(&mut self, word: &str) -> Result<Option<Expr>> {
+        let name_len = word
+            .find(|c: char| !(c.is_ascii_alphabetic() || c == '_'))
+            .unwrap_or(word.len());
+        let (name, rest) = word.split_at(name_len);
+        let op = [">=", "<=", ":", "=", ">", "<"]
+            .into_iter()
+            .find(|op| rest.starts_with(op));
+
+        let Some(op) = op.filter(|_| !name.is_empty()) else {
+            return Ok(Some(title_term(&unquote(word))));
+        };
+        let name = name.to_lowercase();
+        let value = unquote(&rest[op.len()..]);
+
+        match name.as_str() {
+            "sort" => {
+                self.directive("sort")?;
+                self.query.sort = Some(parse_sort(&value)?);
+                return Ok(None);
+            }
+            "limit" => {
+                self.directive("limit")?;
+                let limit = value
+                    .parse()
+                    .map_err(|_| anyhow!("Invalid limit: {}", value))?;
+                self.query.limit = Some(limit);
+                return Ok(None);
+            }
+            _ => {}
+        }
+
+        let Some(field) = Field::parse(&name) else {
+            // Not a field, e.g. "Mission:Impossible"
+            if op == ":" {
+                return Ok(Some(title_term(&unquote(word))));
+            }
+            bail!("Unknown search field: {}", name);
+        };
+        if value.is_empty() {
+            bail!("Missing value for {}{}", name, op);
+        }
+
+        let condition = if field.is_numeric() {
+            parse_numeric(field, op, &value)?
+        } else {
+            match (field, op) {
+                (Field::Title, ":") => Condition::Title(TitleQuery::new(&value)),
+                (Field::Title, "=") => Condition::Equals(value.to_lowercase()),
+                (Field::Resolution, ":" | "=") => {
+                    Condition::Equals(resolution_alias(&value.to_lowercase()).to_string())
+                }
+                (Field::Type, ":" | "=") => {
+                    Condition::Equals(match value.to_lowercase().as_str() {
+                        "movie" | "movies" => "movie".to_string(),
+                        "tv" | "tvshow" | "tvshows" | "show" => "tvshow".to_string(),
+                        other => bail!("Invalid type: {} (expected movie or tvshow)", other),
+                    })
+                }
+                (Field::Watched | Field::Favorite, ":" | "=") => {
+                    Condition::Equals(match value.to_lowercase().as_str() {
+                        "yes" | "true" | "1" => "yes".to_string(),
+                        "no" | "false" | "0" => "no".to_string(),
+                        other => bail!("Invalid {}: {} (expected yes or no)", name, other),
+                    })
+                }
+                (Field::Country | Field::Imdb, ":" | "=") => {
+                    Condition::Equals(value.to_lowercase())
+                }
+                (_, ":") => Condition::Contains(value.to_lowercase()),
+                (_, "=") => Condition::Equals(value.to_lowercase()),
+                _ => bail!("{} does not support '{}'", name, op),
+            }
+        };
+        Ok(Some(Expr::Term(field, condition)))
+    }
+
+    fn directive(&self, name: &str) -> Result<()> {
+        if self.depth > 0 {
+            bail!("{}: cannot be negated or grouped", name);
+        }
+        Ok(())
+    }
+}
+
+fn title_term(value: &str) -> Expr {
+    Expr::Term(Field::Title, Condition::Title(TitleQuery::new(value)))
+}
+
+/// Strip the quotes of a quoted value.
+fn unquote(value: &str) -> String {
+    value.replace('"', "")
+}
+
+fn parse_sort(value: &str) -> Result<Sort> {
+    let (name, direction) = match value.split_once(':') {
+        Some((name, direction)) => (name, Some(direction)),
+        None => (value, None),
+    };
+    let key = SortKey::parse(&name.to_lowercase())
+        .ok_or_else(|| anyhow!("Unknown sort field: {}", name))?;
+    let descending = match direction.map(str::to_lowercase).as_deref() {
+        None => key.descending_by_default(),
+        Some("asc") => false,
+        Some("desc") => true,
+        Some(other) => bail!("Invalid sort direction: {} (expected asc or desc)", other),
+    };
+    Ok(Sort { key, descending })
+}
+
+fn parse_numeric(field: Field, op: &str, value: &str) -> Result<Condition> {
+    let number = |v: &str| parse_number(field, v);
+    if let Some((start, end)) = value.split_once("..") {
+        if op != ":" {
+            bail!("Ranges need ':' (e.g. year:2000..2010)");
+        }
+        let start = (!start.is_empty()).then(|| number(start)).transpose()?;
+        let end = (!end.is_empty()).then(|| number(end)).transpose()?;
+        return Ok(Condition::Range(start, end));
+    }
+    let op = match op {
+        ":" | "=" => Op::Eq,
+        ">" => Op::Gt,
+        ">=" => Op::Ge,
+        "<" => Op::Lt,
+        _ => Op::Le,
+    };
+    Ok(Condition::Compare(op, number(value)?))
+}
+
+/// Parse a number; sizes take a KB/MB/GB/TB unit and default to GB.
+fn parse_number(field: Field, value: &str) -> Result<f64> {
+    let invalid = || anyhow!("Invalid number: {}", value);
+    if field != Field::Size {
+        return value.parse().map_err(|_| invalid());
+    }
+
+    let upper = value.to_uppercase();
+    let split = upper
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(upper.len());
+    let (number, unit) = upper.split_at(split);
+    let multiplier: f64 = match unit.trim() {
+        "B" => 1.0,
+        "K" | "KB" => 1024.0,
+        "M" | "MB" => 1024.0 * 1024.0,
+        "" | "G" | "GB" => 1024.0 * 1024.0 * 1024.0,
+        "T" | "TB" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => return Err(invalid()),
+    };
+    let number: f64 = number.parse().map_err(|_| invalid())?;
+    Ok(number * multiplier)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn movie(title: &str, year: u16, rating: f32, genres: &[&str]) -> MovieEntry {
+        MovieEntry {
+            id: title.to_string(),
+            disk: "D1".to_string(),
+            title: title.to_string(),
+            year: Some(year),
+            country: Some("HK".to_string()),
+            genres: genres.iter().map(|g| g.to_string()).collect(),
+            actors: vec!["Tony Leung".to_string()],
+            runtime: Some(100),
+            rating: Some(rating),
+            size_bytes: 8 * 1024 * 1024 * 1024,
+            resolution: Some("2160p".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_terms_and_negation() {
+        let query =
+            Query::parse(r#"actor:"tony leung" year:2000..2010 rating>7.5 -genre:horror"#).unwrap();
+        assert!(query.matches_movie(&movie("Infernal Affairs", 2002, 8.0, &["Crime"])));
+        assert!(!query.matches_movie(&movie("Hard Boiled", 1992, 8.0, &["Crime"])));
+        assert!(!query.matches_movie(&movie("Scary", 2005, 8.0, &["Horror"])));
+        assert!(!query.matches_movie(&movie("Meh", 2005, 7.5, &["Crime"])));
+    }
+
+    #[test]
+    fn test_or_groups_and_units() {
+        let query = Query::parse("(genre:crime OR genre:romance) size>=8GB res:4k").unwrap();
+        assert!(query.matches_movie(&movie("A", 2000, 7.0, &["Romance"])));
+        assert!(!query.matches_movie(&movie("B", 2000, 7.0, &["Drama"])));
+        assert!(Query::parse("NOT (genre:crime OR year<2000)")
+            .unwrap()
+            .matches_movie(&movie("C", 2001, 7.0, &["Drama"])));
+    }
+
+    #[test]
+    fn test_sort_and_limit() {
+        let results = SearchResults {
+            movies: vec![
+                movie("A", 2001, 6.0, &[]),
+                movie("B", 2002, 9.0, &[]),
+                movie("C", 2003, 7.0, &[]),
+            ],
+            tvshows: vec![],
+            collections: vec![],
+        };
+        let results = Query::parse("sort:rating limit:2").unwrap().apply(results);
+        let titles: Vec<&str> = results.movies.iter().map(|m| m.title.as_str()).collect();
+        assert_eq!(titles, vec!["B", "C"]);
+    }
+
+    #[test]
+    fn test_user_data_fields() {
+        let mut seen = movie("Seen", 2000, 7.0, &[]);
+        seen.user_data = UserData {
+            watched: true,
+            play_count: 2,
+            rating: Some(9.0),
+            tags: vec!["Rewatch".to_string()],
+            ..Default::default()
+        };
+        let unseen = movie("Unseen", 2000, 7.0, &[]);
+
+        let query = Query::parse("watched:yes myrating>=8 tag:rewatch plays>1").unwrap();
+        assert!(query.matches_movie(&seen));
+        assert!(!query.matches_movie(&unseen));
+        let query = Query::parse("watched:no -favorite:yes").unwrap();
+        assert!(!query.matches_movie(&seen));
+        assert!(query.matches_movie(&unseen));
+        assert!(Query::parse("watched:maybe").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for input in [
+            "rating>abc",
+            "(genre:crime",
+            "sort:colour",
+            "-sort:year",
+            "bogus>3",
+            "type:film",
+            "actor:\"tony",
+            "genre:a OR",
+        ] {
+            assert!(Query::parse(input).is_err(), "{}", input);
+        }
+        // Unknown prefixes are just title words
+        assert!(Query::parse("Mission:Impossible").is_ok());
+    }
+}
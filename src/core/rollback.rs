@@ -4,20 +4,29 @@
 //! - Move files back to original locations
 //! - Delete created files (NFO, posters)
 //! - Remove created directories
+//!
+//! Supports partial rollback of a selection of operations, leaving a
+//! residual rollback file for the rest.
 
-use crate::models::rollback::{Rollback, RollbackActionType, RollbackOperation};
+use crate::core::migration::{self, SchemaKind};
+use crate::models::rollback::{Rollback, RollbackActionType, RollbackOpType, RollbackOperation};
 use crate::utils::hash;
 use crate::Result;
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
+use schemars::JsonSchema;
+use serde::Serialize;
 use std::fs;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Rollback executor.
 pub struct RollbackExecutor {
     /// Whether to verify checksums before rollback.
     verify_checksum: bool,
+    /// Whether a checksum mismatch blocks moving the file back
+    /// (otherwise it is only reported as a conflict).
+    strict_checksum: bool,
 }
 
 impl RollbackExecutor {
@@ -25,6 +34,16 @@ impl RollbackExecutor {
     pub fn new() -> Self {
         Self {
             verify_checksum: true,
+            strict_checksum: false,
+        }
+    }
+
+    /// Create a rollback executor that refuses to move back files whose
+    /// content no longer matches the checksum recorded at execution time.
+    pub fn with_strict_checksum(strict: bool) -> Self {
+        Self {
+            verify_checksum: true,
+            strict_checksum: strict,
         }
     }
 
@@ -55,6 +74,7 @@ impl RollbackExecutor {
             skip_count: 0,
             error_count: 0,
             errors: Vec::new(),
+            failed_seqs: Vec::new(),
         };
 
         // Execute operations in reverse order
@@ -118,6 +138,7 @@ impl RollbackExecutor {
                     tracing::error!("Rollback operation failed: {}", error_msg);
                     result.errors.push(error_msg);
                     result.error_count += 1;
+                    result.failed_seqs.push(op.seq);
                 }
             }
         }
@@ -153,8 +174,8 @@ impl RollbackExecutor {
                         }
                     }
 
-                    // Check checksum if available
-                    if self.verify_checksum {
+                    // Check checksum if available (strict mode checks it per operation)
+                    if self.verify_checksum && !self.strict_checksum {
                         if let Some(ref expected_checksum) = op.checksum {
                             if op.rollback.path.exists() {
                                 if let Ok(current_checksum) = hash::sha256_file(&op.rollback.path) {
@@ -179,6 +200,9 @@ impl RollbackExecutor {
                     // Directory not empty is not a conflict - we simply skip removing it
                     // The files were moved back, directory cleanup is optional
                 }
+                RollbackActionType::Mkdir => {
+                    // An existing directory is simply kept
+                }
             }
         }
 
@@ -199,6 +223,24 @@ impl RollbackExecutor {
                     return Ok(false);
                 }
 
+                // Refuse to move back a file that changed since execution
+                if self.strict_checksum {
+                    match op.checksum {
+                        Some(ref expected) => {
+                            let current = hash::sha256_file(from)?;
+                            if &current != expected {
+                                return Err(crate::Error::RollbackConflict(format!(
+                                    "Checksum mismatch (file modified since execution): {}",
+                                    from.display()
+                                )));
+                            }
+                        }
+                        None => {
+                            tracing::warn!("No checksum recorded, cannot verify: {:?}", from);
+                        }
+                    }
+                }
+
                 // Create parent directory if needed
                 if let Some(parent) = to.parent() {
                     if !parent.exists() {
@@ -244,6 +286,18 @@ impl RollbackExecutor {
                 tracing::debug!("Removed directory: {:?}", path);
                 Ok(true)
             }
+            RollbackActionType::Mkdir => {
+                let path = &op.rollback.path;
+
+                if path.exists() {
+                    tracing::debug!("Directory already exists, skipping: {:?}", path);
+                    return Ok(false);
+                }
+
+                fs::create_dir_all(path)?;
+                tracing::debug!("Recreated directory: {:?}", path);
+                Ok(true)
+            }
         }
     }
 }
@@ -255,7 +309,7 @@ impl Default for RollbackExecutor {
 }
 
 /// Result of a rollback execution.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Serialize, JsonSchema)]
 pub struct RollbackResult {
     /// Number of successful operations.
     pub success_count: usize,
@@ -265,6 +319,8 @@ pub struct RollbackResult {
     pub error_count: usize,
     /// Error messages.
     pub errors: Vec<String>,
+    /// Sequence numbers of the operations that failed.
+    pub failed_seqs: Vec<u32>,
 }
 
 impl RollbackResult {
@@ -296,11 +352,246 @@ pub async fn execute_rollback(rollback: &Rollback, dry_run: bool) -> Result<Roll
     executor.execute(rollback, dry_run).await
 }
 
+/// Integrity status of an executed operation's target.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditStatus {
+    /// Target exists (and matches its checksum, if one was recorded).
+    Intact,
+    /// Target exists but its content changed since execution.
+    Modified,
+    /// Target no longer exists.
+    Missing,
+    /// Target exists but no checksum was recorded to verify it.
+    Unverified,
+    /// Target exists but could not be read to verify it.
+    Unreadable(String),
+}
+
+/// Audit result for a single operation.
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    /// Sequence number of the operation.
+    pub seq: u32,
+    /// Operation type that was performed.
+    pub op_type: RollbackOpType,
+    /// Target path that was checked.
+    pub path: PathBuf,
+    /// Integrity status.
+    pub status: AuditStatus,
+}
+
+/// Audit whether the targets of an execution are still intact.
+///
+/// Only executed operations are checked. Moved files are hashed and compared
+/// with the checksum recorded at execution time when one is available.
+/// Files that cannot be hashed are reported as unreadable without stopping
+/// the audit.
+pub fn audit_rollback(rollback: &Rollback) -> Result<Vec<AuditEntry>> {
+    let mut entries = Vec::new();
+
+    for op in rollback.operations.iter().filter(|op| op.executed) {
+        let path = op.rollback.path.clone();
+        let status = if op.op_type == RollbackOpType::Rmdir {
+            // Removed directories have nothing left to check
+            AuditStatus::Intact
+        } else if !path.exists() {
+            AuditStatus::Missing
+        } else if op.op_type == RollbackOpType::Move {
+            match op.checksum {
+                Some(ref expected) => match hash::sha256_file(&path) {
+                    Ok(current) if &current == expected => AuditStatus::Intact,
+                    Ok(_) => AuditStatus::Modified,
+                    Err(e) => AuditStatus::Unreadable(e.to_string()),
+                },
+                None => AuditStatus::Unverified,
+            }
+        } else {
+            AuditStatus::Intact
+        };
+
+        entries.push(AuditEntry {
+            seq: op.seq,
+            op_type: op.op_type,
+            path,
+            status,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Selection criteria for a partial rollback.
+///
+/// All criteria that are set must match for an operation to be selected.
+#[derive(Debug, Clone, Default)]
+pub struct RollbackSelection {
+    /// Inclusive sequence number range.
+    pub seq_range: Option<(u32, u32)>,
+    /// Only operations whose target lives under this folder.
+    pub folder: Option<PathBuf>,
+    /// Only operations whose target path contains this title (case-insensitive).
+    pub title: Option<String>,
+}
+
+impl RollbackSelection {
+    /// Whether no criteria are set (i.e. a full rollback).
+    pub fn is_empty(&self) -> bool {
+        self.seq_range.is_none() && self.folder.is_none() && self.title.is_none()
+    }
+
+    /// Check whether an operation matches all criteria.
+    fn matches(&self, op: &RollbackOperation) -> bool {
+        if let Some((start, end)) = self.seq_range {
+            if op.seq < start || op.seq > end {
+                return false;
+            }
+        }
+        if let Some(ref folder) = self.folder {
+            if !op.to.starts_with(folder) {
+                return false;
+            }
+        }
+        if let Some(ref title) = self.title {
+            let path = op.to.to_string_lossy().to_lowercase();
+            if !path.contains(&title.to_lowercase()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// A rollback split into the operations to undo and the ones to keep.
+#[derive(Debug, Clone)]
+pub struct PartialRollback {
+    /// Operations to undo now.
+    pub selected: Rollback,
+    /// Remaining operations, to be saved as a residual rollback file.
+    pub residual: Rollback,
+    /// Directories kept because files that are not rolled back still live in them.
+    pub kept_dirs: Vec<PathBuf>,
+}
+
+impl PartialRollback {
+    /// The residual rollback with failed selected operations added back.
+    ///
+    /// Selected directory removals that contain a failed operation's target
+    /// are kept too, since they were skipped as non-empty.
+    pub fn residual_with_failed(&self, failed_seqs: &[u32]) -> Rollback {
+        let failed_targets: Vec<&PathBuf> = self
+            .selected
+            .operations
+            .iter()
+            .filter(|op| failed_seqs.contains(&op.seq))
+            .map(|op| &op.to)
+            .collect();
+        let mut residual = self.residual.clone();
+        residual.operations.extend(
+            self.selected
+                .operations
+                .iter()
+                .filter(|op| {
+                    failed_seqs.contains(&op.seq)
+                        || (op.rollback.op == RollbackActionType::Rmdir
+                            && failed_targets.iter().any(|t| {
+                                **t != op.rollback.path && t.starts_with(&op.rollback.path)
+                            }))
+                })
+                .cloned(),
+        );
+        residual.operations.sort_by_key(|op| op.seq);
+        residual
+    }
+}
+
+/// Parse a sequence range like "5" or "3-10".
+pub fn parse_seq_range(s: &str) -> Result<(u32, u32)> {
+    let invalid = || crate::Error::other(format!("Invalid sequence range: {}", s));
+    let (start, end) = match s.split_once('-') {
+        Some((a, b)) => (
+            a.trim().parse().map_err(|_| invalid())?,
+            b.trim().parse().map_err(|_| invalid())?,
+        ),
+        None => {
+            let n = s.trim().parse().map_err(|_| invalid())?;
+            (n, n)
+        }
+    };
+    if start > end {
+        return Err(invalid());
+    }
+    Ok((start, end))
+}
+
+/// Split a rollback into selected and residual operations.
+///
+/// Directory removals are only selected when no kept operation has its
+/// target inside that directory, so a folder is never removed (or attempted)
+/// while other organized files still live in it.
+pub fn select_operations(rollback: &Rollback, selection: &RollbackSelection) -> PartialRollback {
+    let mut selected: Vec<bool> = rollback
+        .operations
+        .iter()
+        .map(|op| selection.matches(op))
+        .collect();
+
+    // Deselect directory removals that kept files depend on. Repeat until stable,
+    // since keeping a directory can in turn keep its parent directories.
+    let mut kept_dirs = Vec::new();
+    loop {
+        let mut changed = false;
+        for (i, op) in rollback.operations.iter().enumerate() {
+            if !selected[i] || op.rollback.op != RollbackActionType::Rmdir {
+                continue;
+            }
+            let dir = &op.rollback.path;
+            let needed = rollback.operations.iter().enumerate().any(|(j, other)| {
+                !selected[j] && other.executed && other.to != *dir && other.to.starts_with(dir)
+            });
+            if needed {
+                selected[i] = false;
+                kept_dirs.push(dir.clone());
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    let split = |want: bool| Rollback {
+        version: rollback.version.clone(),
+        plan_id: rollback.plan_id.clone(),
+        executed_at: rollback.executed_at.clone(),
+        operations: rollback
+            .operations
+            .iter()
+            .zip(&selected)
+            .filter(|(_, s)| **s == want)
+            .map(|(op, _)| op.clone())
+            .collect(),
+    };
+
+    PartialRollback {
+        selected: split(true),
+        residual: split(false),
+        kept_dirs,
+    }
+}
+
+/// Default path for the residual rollback file: `<stem>_residual.json`.
+pub fn residual_rollback_path(rollback_file: &Path) -> PathBuf {
+    let stem = rollback_file
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "rollback".to_string());
+    rollback_file.with_file_name(format!("{}_residual.json", stem))
+}
+
 /// Load a rollback from a JSON file.
 pub fn load_rollback(path: &Path) -> Result<Rollback> {
     let content = fs::read_to_string(path)?;
-    let rollback: Rollback = serde_json::from_str(&content)?;
-    Ok(rollback)
+    migration::load_json(SchemaKind::Rollback, &content)
 }
 
 /// Save a rollback to a JSON file.
@@ -337,9 +628,176 @@ mod tests {
             skip_count: 1,
             error_count: 2,
             errors: vec!["error1".to_string(), "error2".to_string()],
+            failed_seqs: vec![3, 4],
         };
         assert!(!result.is_success());
     }
 
+    fn op(seq: u32, action: RollbackActionType, to: &str) -> RollbackOperation {
+        use crate::models::rollback::RollbackAction;
+        let op_type = match action {
+            RollbackActionType::Rmdir => RollbackOpType::Mkdir,
+            RollbackActionType::Move => RollbackOpType::Move,
+            RollbackActionType::Delete => RollbackOpType::Create,
+            RollbackActionType::Mkdir => RollbackOpType::Rmdir,
+        };
+        RollbackOperation {
+            seq,
+            op_type,
+            from: PathBuf::from("/src"),
+            to: PathBuf::from(to),
+            checksum: None,
+            rollback: RollbackAction {
+                op: action,
+                path: PathBuf::from(to),
+                to: None,
+            },
+            executed: true,
+        }
+    }
+
+    fn sample_rollback() -> Rollback {
+        Rollback {
+            operations: vec![
+                op(1, RollbackActionType::Rmdir, "/dst/ZH"),
+                op(2, RollbackActionType::Rmdir, "/dst/ZH/[A](2001)"),
+                op(3, RollbackActionType::Move, "/dst/ZH/[A](2001)/a.mkv"),
+                op(4, RollbackActionType::Rmdir, "/dst/ZH/[B](2002)"),
+                op(5, RollbackActionType::Move, "/dst/ZH/[B](2002)/b.mkv"),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_seq_range() {
+        assert_eq!(parse_seq_range("5").unwrap(), (5, 5));
+        assert_eq!(parse_seq_range("3-10").unwrap(), (3, 10));
+        assert!(parse_seq_range("10-3").is_err());
+        assert!(parse_seq_range("a-b").is_err());
+    }
+
+    #[test]
+    fn test_select_by_title_keeps_shared_parent_dir() {
+        let selection = RollbackSelection {
+            title: Some("[a]".to_string()),
+            ..Default::default()
+        };
+        let partial = select_operations(&sample_rollback(), &selection);
+
+        let seqs: Vec<u32> = partial.selected.operations.iter().map(|o| o.seq).collect();
+        assert_eq!(seqs, vec![2, 3]);
+        let residual: Vec<u32> = partial.residual.operations.iter().map(|o| o.seq).collect();
+        assert_eq!(residual, vec![1, 4, 5]);
+    }
+
+    #[test]
+    fn test_select_by_seq_range_protects_dirs_in_use() {
+        let selection = RollbackSelection {
+            seq_range: Some((1, 2)),
+            ..Default::default()
+        };
+        let partial = select_operations(&sample_rollback(), &selection);
+
+        // Both directories still contain kept files
+        assert!(partial.selected.operations.is_empty());
+        assert_eq!(partial.kept_dirs.len(), 2);
+        assert_eq!(partial.residual.operations.len(), 5);
+    }
+
+    #[test]
+    fn test_residual_with_failed_keeps_failed_ops() {
+        let selection = RollbackSelection {
+            title: Some("[a]".to_string()),
+            ..Default::default()
+        };
+        let partial = select_operations(&sample_rollback(), &selection);
+
+        // The failed move and the directory it lives in stay in the residual
+        let residual = partial.residual_with_failed(&[3]);
+        let seqs: Vec<u32> = residual.operations.iter().map(|o| o.seq).collect();
+        assert_eq!(seqs, vec![1, 2, 3, 4, 5]);
+
+        let residual = partial.residual_with_failed(&[]);
+        assert_eq!(residual.operations.len(), 3);
+    }
+
+    #[test]
+    fn test_audit_rollback_detects_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let intact = dir.path().join("intact.mkv");
+        let modified = dir.path().join("modified.mkv");
+        std::fs::write(&intact, b"intact").unwrap();
+        std::fs::write(&modified, b"original").unwrap();
+
+        let mut ops = vec![
+            op(1, RollbackActionType::Move, intact.to_str().unwrap()),
+            op(2, RollbackActionType::Move, modified.to_str().unwrap()),
+            op(3, RollbackActionType::Delete, "/nonexistent/movie.nfo"),
+            op(4, RollbackActionType::Move, dir.path().to_str().unwrap()),
+            op(5, RollbackActionType::Move, intact.to_str().unwrap()),
+        ];
+        ops[0].checksum = Some(hash::sha256_file(&intact).unwrap());
+        ops[1].checksum = Some(hash::sha256_file(&modified).unwrap());
+        // A directory cannot be hashed; the audit goes on past it
+        ops[3].checksum = Some(hash::sha256_string("unreadable"));
+        ops[4].checksum = ops[0].checksum.clone();
+        std::fs::write(&modified, b"changed").unwrap();
+
+        let rollback = Rollback {
+            operations: ops,
+            ..Default::default()
+        };
+        let mut statuses: Vec<_> = audit_rollback(&rollback)
+            .unwrap()
+            .iter()
+            .map(|e| e.status.clone())
+            .collect();
+        assert!(matches!(statuses.remove(3), AuditStatus::Unreadable(_)));
+        assert_eq!(
+            statuses,
+            vec![
+                AuditStatus::Intact,
+                AuditStatus::Modified,
+                AuditStatus::Missing,
+                AuditStatus::Intact
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strict_checksum_blocks_modified_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("target.mkv");
+        let original = dir.path().join("original.mkv");
+        std::fs::write(&target, b"changed").unwrap();
+
+        let mut operation = op(1, RollbackActionType::Move, target.to_str().unwrap());
+        operation.rollback.to = Some(original.clone());
+        operation.checksum = Some(hash::sha256_string("original"));
+
+        let executor = RollbackExecutor::with_strict_checksum(true);
+        assert!(executor.execute_rollback_op(&operation).is_err());
+        assert!(target.exists());
+        assert!(!original.exists());
+
+        // Non-strict mode moves the file back regardless
+        let executor = RollbackExecutor::new();
+        assert!(executor.execute_rollback_op(&operation).unwrap());
+        assert!(original.exists());
+    }
+
+    #[test]
+    fn test_rollback_recreates_removed_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let removed = dir.path().join("Old [1990]");
+        let operation = op(1, RollbackActionType::Mkdir, removed.to_str().unwrap());
+
+        let executor = RollbackExecutor::new();
+        assert!(executor.execute_rollback_op(&operation).unwrap());
+        assert!(removed.is_dir());
+        assert!(!executor.execute_rollback_op(&operation).unwrap());
+    }
+
     // test_load_save_rollback moved to tests/io_tests.rs
 }
@@ -138,7 +138,7 @@ fn is_sample_filename(filename: &str) -> bool {
 }
 
 /// Create a VideoFile from a path.
-fn create_video_file(path: &Path) -> Result<VideoFile> {
+pub(crate) fn create_video_file(path: &Path) -> Result<VideoFile> {
     let metadata = std::fs::metadata(path)?;
     let modified = metadata
         .modified()
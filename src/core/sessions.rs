@@ -0,0 +1,553 @@
+//! Session history management.
+//!
+//! Sessions live in `~/.config/media_organizer/sessions/<timestamp>_<id>/` and
+//! contain the `plan.json` and, once executed, the `rollback.json`. All
+//! functions take the sessions directory explicitly so they can be tested
+//! against a temporary directory.
+
+use crate::core::planner::load_plan;
+use crate::models::plan::{Plan, PlanItem, PlanItemStatus};
+use crate::models::rollback::Rollback;
+use crate::models::session::{FailedOperation, SessionManifest, SessionStatus};
+use crate::Result;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Plan file name inside a session directory.
+pub const PLAN_FILE: &str = "plan.json";
+/// Rollback file name inside a session directory.
+pub const ROLLBACK_FILE: &str = "rollback.json";
+/// Rollback file name after the session has been undone.
+pub const UNDONE_ROLLBACK_FILE: &str = "rollback.undone.json";
+/// Manifest file name inside a session directory.
+pub const MANIFEST_FILE: &str = "session.json";
+
+/// A session directory.
+#[derive(Debug, Clone)]
+pub struct SessionEntry {
+    /// Session ID (directory name).
+    pub id: String,
+    /// Session directory.
+    pub path: PathBuf,
+    /// Plan creation time (falls back to the directory modification time).
+    pub created_at: DateTime<Utc>,
+}
+
+impl SessionEntry {
+    /// Path to the session's plan file.
+    pub fn plan_path(&self) -> PathBuf {
+        self.path.join(PLAN_FILE)
+    }
+
+    /// Path to the session's rollback file.
+    pub fn rollback_path(&self) -> PathBuf {
+        self.path.join(ROLLBACK_FILE)
+    }
+
+    /// Whether the session has been executed and not fully undone.
+    pub fn is_executed(&self) -> bool {
+        matches!(
+            self.manifest().status,
+            SessionStatus::Executed
+                | SessionStatus::PartiallyExecuted
+                | SessionStatus::PartiallyRolledBack
+        )
+    }
+
+    /// Path to the rollback file undoing what is still applied: the residual
+    /// file after a partial rollback, otherwise the session's rollback file.
+    pub fn pending_rollback_path(&self) -> PathBuf {
+        let manifest = self.manifest();
+        match manifest.residual_rollback_path {
+            Some(path) if manifest.status == SessionStatus::PartiallyRolledBack => path,
+            _ => self.rollback_path(),
+        }
+    }
+
+    /// Load the session manifest.
+    ///
+    /// Sessions created before manifests existed get one inferred from the
+    /// files present in the session directory.
+    pub fn manifest(&self) -> SessionManifest {
+        load_manifest(&self.path).unwrap_or_else(|_| {
+            let mut manifest = SessionManifest::planned(&self.created_at.to_rfc3339());
+            if self.rollback_path().exists() {
+                manifest.status = SessionStatus::Executed;
+                manifest.rollback_path = Some(self.rollback_path());
+            } else if self.path.join(UNDONE_ROLLBACK_FILE).exists() {
+                manifest.status = SessionStatus::RolledBack;
+            }
+            manifest
+        })
+    }
+}
+
+/// Load the manifest of a session directory.
+pub fn load_manifest(session_dir: &Path) -> Result<SessionManifest> {
+    let content = fs::read_to_string(session_dir.join(MANIFEST_FILE))?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Save the manifest of a session directory.
+pub fn save_manifest(session_dir: &Path, manifest: &SessionManifest) -> Result<()> {
+    let json = serde_json::to_string_pretty(manifest)?;
+    fs::write(session_dir.join(MANIFEST_FILE), json)?;
+    Ok(())
+}
+
+/// Record a plan execution in the session manifest.
+pub fn record_execution(
+    session: &SessionEntry,
+    rollback: &Rollback,
+    rollback_path: &Path,
+    success_count: usize,
+    failed_operations: Vec<FailedOperation>,
+) -> Result<SessionManifest> {
+    let mut manifest = session.manifest();
+    manifest.status = if failed_operations.is_empty() {
+        SessionStatus::Executed
+    } else {
+        SessionStatus::PartiallyExecuted
+    };
+    manifest.executed_at = Some(rollback.executed_at.clone());
+    manifest.executed_by = Some(whoami::username());
+    manifest.rolled_back_at = None;
+    manifest.residual_rollback_path = None;
+    manifest.success_count = success_count;
+    manifest.error_count = failed_operations.len();
+    manifest.failed_operations = failed_operations;
+    manifest.rollback_path = Some(rollback_path.to_path_buf());
+    manifest.rollback_plan_id = Some(rollback.plan_id.clone());
+    manifest.tool_version = env!("CARGO_PKG_VERSION").to_string();
+    save_manifest(&session.path, &manifest)?;
+    Ok(manifest)
+}
+
+/// Record a completed rollback in the session manifest.
+pub fn record_rollback(session: &SessionEntry) -> Result<SessionManifest> {
+    let mut manifest = session.manifest();
+    manifest.status = SessionStatus::RolledBack;
+    manifest.rolled_back_at = Some(Utc::now().to_rfc3339());
+    manifest.tool_version = env!("CARGO_PKG_VERSION").to_string();
+    save_manifest(&session.path, &manifest)?;
+    Ok(manifest)
+}
+
+/// Record a partial rollback and the residual rollback file it left.
+pub fn record_partial_rollback(
+    session: &SessionEntry,
+    residual_path: &Path,
+) -> Result<SessionManifest> {
+    let mut manifest = session.manifest();
+    manifest.status = SessionStatus::PartiallyRolledBack;
+    manifest.rolled_back_at = Some(Utc::now().to_rfc3339());
+    manifest.residual_rollback_path = Some(residual_path.to_path_buf());
+    manifest.tool_version = env!("CARGO_PKG_VERSION").to_string();
+    save_manifest(&session.path, &manifest)?;
+    Ok(manifest)
+}
+
+/// Find the session whose execution produced the given rollback.
+pub fn find_session_for_rollback(
+    sessions_dir: &Path,
+    rollback: &Rollback,
+) -> Result<Option<SessionEntry>> {
+    Ok(list(sessions_dir)?.into_iter().find(|s| {
+        load_manifest(&s.path)
+            .ok()
+            .and_then(|m| m.rollback_plan_id)
+            .is_some_and(|id| id == rollback.plan_id)
+    }))
+}
+
+/// List all sessions, most recent first.
+pub fn list(sessions_dir: &Path) -> Result<Vec<SessionEntry>> {
+    if !sessions_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut sessions: Vec<SessionEntry> = fs::read_dir(sessions_dir)?
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .map(|e| {
+            let path = e.path();
+            let created_at = session_created_at(&path);
+            SessionEntry {
+                id: e.file_name().to_string_lossy().to_string(),
+                path,
+                created_at,
+            }
+        })
+        .collect();
+
+    // Session IDs start with a timestamp, so name order is creation order
+    sessions.sort_by(|a, b| b.id.cmp(&a.id));
+    Ok(sessions)
+}
+
+/// Determine when a session was created.
+fn session_created_at(path: &Path) -> DateTime<Utc> {
+    if let Ok(plan) = load_plan(&path.join(PLAN_FILE)) {
+        if let Ok(created) = DateTime::parse_from_rfc3339(&plan.created_at) {
+            return created.with_timezone(&Utc);
+        }
+    }
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map(DateTime::<Utc>::from)
+        .unwrap_or_else(|_| Utc::now())
+}
+
+/// Resolve a session ID to its directory.
+pub fn session_path(sessions_dir: &Path, session_id: &str) -> Result<PathBuf> {
+    // Reject anything that could escape the sessions directory
+    if session_id.is_empty()
+        || session_id.contains('/')
+        || session_id.contains('\\')
+        || session_id.starts_with('.')
+    {
+        return Err(crate::Error::other(format!(
+            "Invalid session ID: {}",
+            session_id
+        )));
+    }
+
+    let path = sessions_dir.join(session_id);
+    if !path.is_dir() {
+        return Err(crate::Error::PathNotFound(format!(
+            "Session not found: {}",
+            session_id
+        )));
+    }
+    Ok(path)
+}
+
+/// Look up a session by ID.
+pub fn get(sessions_dir: &Path, session_id: &str) -> Result<SessionEntry> {
+    let path = session_path(sessions_dir, session_id)?;
+    Ok(SessionEntry {
+        id: session_id.to_string(),
+        created_at: session_created_at(&path),
+        path,
+    })
+}
+
+/// Find the most recent session that has been executed and not undone.
+pub fn latest_executed(sessions_dir: &Path) -> Result<Option<SessionEntry>> {
+    Ok(list(sessions_dir)?.into_iter().find(|s| s.is_executed()))
+}
+
+/// Find the session a plan was saved to (matched by creation time and source).
+pub fn find_session_for_plan(sessions_dir: &Path, plan: &Plan) -> Result<Option<SessionEntry>> {
+    for session in list(sessions_dir)? {
+        if let Ok(session_plan) = load_plan(&session.plan_path()) {
+            if session_plan.created_at == plan.created_at
+                && session_plan.source_path == plan.source_path
+            {
+                return Ok(Some(session));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Mark a session as undone by renaming its rollback file.
+pub fn mark_undone(session: &SessionEntry) -> Result<()> {
+    fs::rename(
+        session.rollback_path(),
+        session.path.join(UNDONE_ROLLBACK_FILE),
+    )?;
+    Ok(())
+}
+
+/// Parse an age like "90d", "2w" or "12h".
+pub fn parse_age(s: &str) -> Result<Duration> {
+    let s = s.trim();
+    let invalid =
+        || crate::Error::other(format!("Invalid age: {} (expected e.g. 90d, 2w, 12h)", s));
+    let (unit_start, unit) = s.char_indices().last().ok_or_else(invalid)?;
+    let n: i64 = s[..unit_start].parse().map_err(|_| invalid())?;
+    if n <= 0 {
+        return Err(invalid());
+    }
+    match unit {
+        'h' => Duration::try_hours(n),
+        'd' => Duration::try_days(n),
+        'w' => Duration::try_weeks(n),
+        _ => None,
+    }
+    .ok_or_else(invalid)
+}
+
+/// Select sessions older than `older_than` for pruning.
+///
+/// With `keep_executed`, sessions that still hold a rollback file are kept,
+/// since deleting them would lose the ability to undo that execution.
+pub fn prune_candidates(
+    sessions: &[SessionEntry],
+    older_than: Duration,
+    keep_executed: bool,
+    now: DateTime<Utc>,
+) -> Vec<SessionEntry> {
+    sessions
+        .iter()
+        .filter(|s| now - s.created_at > older_than)
+        .filter(|s| !(keep_executed && s.is_executed()))
+        .cloned()
+        .collect()
+}
+
+/// Delete a session directory.
+pub fn delete(session: &SessionEntry) -> Result<()> {
+    fs::remove_dir_all(&session.path)?;
+    Ok(())
+}
+
+/// Item-level difference between two plans, keyed by source path.
+#[derive(Debug, Default)]
+pub struct PlanDiff {
+    /// Items only in the first plan.
+    pub removed: Vec<PathBuf>,
+    /// Items only in the second plan.
+    pub added: Vec<PathBuf>,
+    /// Items in both plans with a different match, target or status.
+    pub changed: Vec<ItemChange>,
+    /// Number of identical items.
+    pub unchanged: usize,
+}
+
+/// A changed item.
+#[derive(Debug)]
+pub struct ItemChange {
+    /// Source path.
+    pub source: PathBuf,
+    /// Summary of the item in the first plan.
+    pub before: String,
+    /// Summary of the item in the second plan.
+    pub after: String,
+}
+
+/// Short summary of an item used for comparison.
+fn item_summary(item: &PlanItem) -> String {
+    let tmdb = item
+        .movie_metadata
+        .as_ref()
+        .map(|m| m.tmdb_id)
+        .or_else(|| item.tvshow_metadata.as_ref().map(|s| s.tmdb_id));
+    let status = match item.status {
+        PlanItemStatus::Pending => "pending",
+        PlanItemStatus::Skip => "skip",
+        PlanItemStatus::Error => "error",
+    };
+    format!(
+        "[{}] tmdb{} -> {}",
+        status,
+        tmdb.map(|id| id.to_string())
+            .unwrap_or_else(|| "?".to_string()),
+        item.target.full_path.display()
+    )
+}
+
+/// Compare two plans item by item.
+///
+/// Unknown files count as items without a match, so a file that moved from
+/// unknown to matched shows up as changed rather than added.
+pub fn diff_plans(a: &Plan, b: &Plan) -> PlanDiff {
+    let summarize = |plan: &Plan| -> HashMap<PathBuf, String> {
+        let mut map: HashMap<PathBuf, String> = plan
+            .items
+            .iter()
+            .map(|i| (i.source.path.clone(), item_summary(i)))
+            .collect();
+        for u in &plan.unknown {
+            map.insert(u.source.path.clone(), format!("[unknown] {}", u.reason));
+        }
+        map
+    };
+
+    let before = summarize(a);
+    let after = summarize(b);
+    let mut diff = PlanDiff::default();
+
+    for (source, old) in &before {
+        match after.get(source) {
+            None => diff.removed.push(source.clone()),
+            Some(new) if new != old => diff.changed.push(ItemChange {
+                source: source.clone(),
+                before: old.clone(),
+                after: new.clone(),
+            }),
+            Some(_) => diff.unchanged += 1,
+        }
+    }
+    for source in after.keys() {
+        if !before.contains_key(source) {
+            diff.added.push(source.clone());
+        }
+    }
+
+    diff.removed.sort();
+    diff.added.sort();
+    diff.changed.sort_by(|x, y| x.source.cmp(&y.source));
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::planner::save_plan;
+    use crate::models::media::VideoFile;
+    use crate::models::plan::UnknownItem;
+
+    fn unknown(path: &str) -> UnknownItem {
+        UnknownItem {
+            source: VideoFile {
+                path: PathBuf::from(path),
+                filename: path.to_string(),
+                size: 0,
+                modified: Utc::now(),
+                is_sample: false,
+                parent_dir: PathBuf::from("/"),
+            },
+            reason: "no match".to_string(),
+        }
+    }
+
+    fn make_session(dir: &Path, id: &str, created_at: &str, executed: bool) {
+        let session = dir.join(id);
+        fs::create_dir_all(&session).unwrap();
+        let plan = Plan {
+            created_at: created_at.to_string(),
+            ..Default::default()
+        };
+        save_plan(&plan, &session.join(PLAN_FILE)).unwrap();
+        if executed {
+            fs::write(session.join(ROLLBACK_FILE), "{}").unwrap();
+        }
+    }
+
+    #[test]
+    fn test_latest_executed_and_prune() {
+        let dir = tempfile::tempdir().unwrap();
+        make_session(
+            dir.path(),
+            "20240101_000000_aaaa",
+            "2024-01-01T00:00:00Z",
+            true,
+        );
+        make_session(
+            dir.path(),
+            "20240201_000000_bbbb",
+            "2024-02-01T00:00:00Z",
+            false,
+        );
+        make_session(
+            dir.path(),
+            "20240301_000000_cccc",
+            "2024-03-01T00:00:00Z",
+            true,
+        );
+
+        let latest = latest_executed(dir.path()).unwrap().unwrap();
+        assert_eq!(latest.id, "20240301_000000_cccc");
+
+        let sessions = list(dir.path()).unwrap();
+        let now = DateTime::parse_from_rfc3339("2024-03-15T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let older = prune_candidates(&sessions, parse_age("30d").unwrap(), false, now);
+        assert_eq!(older.len(), 2);
+        let older = prune_candidates(&sessions, parse_age("30d").unwrap(), true, now);
+        assert_eq!(older.len(), 1);
+        assert_eq!(older[0].id, "20240201_000000_bbbb");
+    }
+
+    #[test]
+    fn test_manifest_lifecycle() {
+        let dir = tempfile::tempdir().unwrap();
+        make_session(
+            dir.path(),
+            "20240101_000000_aaaa",
+            "2024-01-01T00:00:00Z",
+            false,
+        );
+        let session = list(dir.path()).unwrap().remove(0);
+
+        // Legacy session without a manifest
+        assert_eq!(session.manifest().status, SessionStatus::Planned);
+
+        let rollback = Rollback {
+            plan_id: "plan-1".to_string(),
+            executed_at: "2024-01-02T00:00:00Z".to_string(),
+            ..Default::default()
+        };
+        let failed = vec![FailedOperation {
+            op: crate::models::plan::OperationType::Download,
+            path: PathBuf::from("/dst/poster.jpg"),
+            error: "timeout".to_string(),
+        }];
+        record_execution(&session, &rollback, &session.rollback_path(), 5, failed).unwrap();
+
+        let manifest = load_manifest(&session.path).unwrap();
+        assert_eq!(manifest.status, SessionStatus::PartiallyExecuted);
+        assert_eq!(manifest.success_count, 5);
+        assert_eq!(manifest.error_count, 1);
+
+        let found = find_session_for_rollback(dir.path(), &rollback).unwrap();
+        assert_eq!(found.unwrap().id, session.id);
+        assert_eq!(session.pending_rollback_path(), session.rollback_path());
+
+        let residual = session.path.join("rollback_residual.json");
+        record_partial_rollback(&session, &residual).unwrap();
+        let manifest = load_manifest(&session.path).unwrap();
+        assert_eq!(manifest.status, SessionStatus::PartiallyRolledBack);
+        assert!(session.is_executed());
+        assert_eq!(session.pending_rollback_path(), residual);
+
+        record_rollback(&session).unwrap();
+        let manifest = load_manifest(&session.path).unwrap();
+        assert_eq!(manifest.status, SessionStatus::RolledBack);
+        assert!(manifest.rolled_back_at.is_some());
+        assert!(!session.is_executed());
+        assert_eq!(session.pending_rollback_path(), session.rollback_path());
+    }
+
+    #[test]
+    fn test_session_path_rejects_traversal() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(session_path(dir.path(), "../etc").is_err());
+        assert!(session_path(dir.path(), "missing").is_err());
+    }
+
+    #[test]
+    fn test_parse_age() {
+        assert_eq!(parse_age("90d").unwrap(), Duration::days(90));
+        assert_eq!(parse_age("2w").unwrap(), Duration::weeks(2));
+        assert!(parse_age("90").is_err());
+        assert!(parse_age("xd").is_err());
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("90日").is_err());
+        assert!(parse_age("99999999999999d").is_err());
+        assert!(parse_age("-5d").is_err());
+        assert!(parse_age("0w").is_err());
+    }
+
+    #[test]
+    fn test_diff_plans() {
+        let a = Plan {
+            unknown: vec![unknown("/a.mkv"), unknown("/b.mkv")],
+            ..Default::default()
+        };
+        let b = Plan {
+            unknown: vec![unknown("/b.mkv"), unknown("/c.mkv")],
+            ..Default::default()
+        };
+        let diff = diff_plans(&a, &b);
+        assert_eq!(diff.removed, vec![PathBuf::from("/a.mkv")]);
+        assert_eq!(diff.added, vec![PathBuf::from("/c.mkv")]);
+        assert_eq!(diff.unchanged, 1);
+        assert!(diff.changed.is_empty());
+    }
+}
@@ -0,0 +1,209 @@
+//! Relevance scoring for title search.
+//!
+//! Titles and queries are compared after normalization: Traditional Chinese
+//! is converted to Simplified, letters are lowercased and punctuation and
+//! whitespace are dropped, so "Avengers Endgame" matches "Avengers: Endgame"
+//! and 復仇者聯盟 matches 复仇者联盟. A title matches when it contains the
+//! query, when a Latin query spells the pinyin initials of a Chinese title
+//! (`fczl` for 复仇者联盟), or when it contains the query with a few typos.
+
+use crate::utils::chinese;
+
+/// Score of an exact match.
+const EXACT: f64 = 1.0;
+/// Score of a title starting with the query.
+const PREFIX: f64 = 0.9;
+/// Score of a title containing the query.
+const CONTAINS: f64 = 0.8;
+/// Score of pinyin initials starting with the query.
+const INITIALS_PREFIX: f64 = 0.75;
+/// Score of pinyin initials containing the query.
+const INITIALS_CONTAINS: f64 = 0.7;
+/// Score of a match with one typo; each further typo costs `TYPO_PENALTY`.
+const FUZZY: f64 = 0.6;
+const TYPO_PENALTY: f64 = 0.1;
+
+/// Normalize a title for matching.
+pub fn normalize_title(title: &str) -> String {
+    chinese::to_simplified(title)
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+/// A parsed title query.
+#[derive(Debug, Clone)]
+pub struct TitleQuery {
+    normalized: String,
+    chars: Vec<char>,
+    /// Whether the query can be pinyin initials.
+    initials: bool,
+    /// Typos tolerated for this query length.
+    max_typos: usize,
+}
+
+impl TitleQuery {
+    pub fn new(query: &str) -> Self {
+        let normalized = normalize_title(query);
+        let chars: Vec<char> = normalized.chars().collect();
+        let max_typos = match chars.len() {
+            0..=3 => 0,
+            4..=7 => 1,
+            _ => 2,
+        };
+        Self {
+            initials: chars.len() >= 2 && chars.iter().all(|c| c.is_ascii_alphanumeric()),
+            normalized,
+            chars,
+            max_typos,
+        }
+    }
+
+    /// Relevance of a title, or `None` if it does not match.
+    pub fn score(&self, title: &str) -> Option<f64> {
+        let title = normalize_title(title);
+        if self.normalized.is_empty() || title == self.normalized {
+            return Some(EXACT);
+        }
+        if title.starts_with(&self.normalized) {
+            return Some(PREFIX);
+        }
+        if title.contains(&self.normalized) {
+            return Some(CONTAINS);
+        }
+
+        let has_chinese = chinese::contains_chinese(&title);
+        let title: Vec<char> = title.chars().collect();
+        if self.initials && has_chinese {
+            if let Some(at) = self.find_initials(&title) {
+                return Some(if at == 0 {
+                    INITIALS_PREFIX
+                } else {
+                    INITIALS_CONTAINS
+                });
+            }
+        }
+
+        if self.max_typos > 0 {
+            let typos = substring_distance(&self.chars, &title);
+            if typos <= self.max_typos {
+                return Some(FUZZY - TYPO_PENALTY * typos.saturating_sub(1) as f64);
+            }
+        }
+        None
+    }
+
+    /// Best relevance of a localized and an original title.
+    pub fn score_entry(&self, title: &str, original_title: Option<&str>) -> Option<f64> {
+        let original = original_title.and_then(|t| self.score(t));
+        match (self.score(title), original) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Position where the query spells the pinyin initials of the title.
+    fn find_initials(&self, title: &[char]) -> Option<usize> {
+        let matches = |t: char, q: char| {
+            t == q || (q.is_ascii_lowercase() && chinese::pinyin_initials(t) & initial_bit(q) != 0)
+        };
+        (0..title.len().saturating_sub(self.chars.len() - 1)).find(|&start| {
+            self.chars
+                .iter()
+                .zip(&title[start..])
+                .all(|(&q, &t)| matches(t, q))
+        })
+    }
+}
+
+fn initial_bit(c: char) -> u32 {
+    1 << (c as u32 - 'a' as u32)
+}
+
+/// Fewest edits turning `query` into any substring of `text`.
+fn substring_distance(query: &[char], text: &[char]) -> usize {
+    // Edit distance where skipping a prefix and a suffix of `text` is free
+    let mut prev = vec![0; text.len() + 1];
+    for (i, &q) in query.iter().enumerate() {
+        let mut row = vec![i + 1; text.len() + 1];
+        for (j, &t) in text.iter().enumerate() {
+            let substitution = prev[j] + usize::from(q != t);
+            row[j + 1] = substitution.min(prev[j + 1] + 1).min(row[j] + 1);
+        }
+        prev = row;
+    }
+    prev.into_iter().min().unwrap_or(query.len())
+}
+
+/// Sort entries by relevance to a title query, dropping non-matching ones.
+///
+/// Ties keep the usual newest-first order.
+pub fn rank_by_relevance<T>(
+    entries: Vec<T>,
+    score: impl Fn(&T) -> Option<f64>,
+    year: impl Fn(&T) -> Option<u16>,
+) -> Vec<T> {
+    let mut scored: Vec<(f64, T)> = entries
+        .into_iter()
+        .filter_map(|e| score(&e).map(|s| (s, e)))
+        .collect();
+    scored.sort_by(|(sa, a), (sb, b)| {
+        sb.total_cmp(sa)
+            .then_with(|| std::cmp::Reverse(year(a)).cmp(&std::cmp::Reverse(year(b))))
+    });
+    scored.into_iter().map(|(_, e)| e).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punctuation_and_script_insensitive() {
+        let query = TitleQuery::new("Avengers Endgame");
+        assert_eq!(query.score("Avengers: Endgame"), Some(EXACT));
+        let query = TitleQuery::new("復仇者聯盟");
+        assert_eq!(query.score("复仇者联盟4：终局之战"), Some(PREFIX));
+    }
+
+    #[test]
+    fn test_pinyin_initials() {
+        let query = TitleQuery::new("fczl");
+        assert_eq!(query.score("复仇者联盟"), Some(INITIALS_PREFIX));
+        assert_eq!(query.score("復仇者聯盟"), Some(INITIALS_PREFIX));
+        assert_eq!(
+            TitleQuery::new("zlm").score("复仇者联盟"),
+            Some(INITIALS_CONTAINS)
+        );
+        assert_eq!(query.score("Avengers"), None);
+    }
+
+    #[test]
+    fn test_typo_tolerance() {
+        let query = TitleQuery::new("Avengrs");
+        assert_eq!(query.score("The Avengers"), Some(FUZZY));
+        assert_eq!(
+            TitleQuery::new("Interstelar").score("Interstellar"),
+            Some(FUZZY)
+        );
+        // Short queries must match exactly
+        assert_eq!(TitleQuery::new("Upp").score("Up"), None);
+        assert_eq!(TitleQuery::new("Matrix").score("Titanic"), None);
+    }
+
+    #[test]
+    fn test_rank_by_relevance() {
+        let entries = vec![
+            ("The Matrix Reloaded", 2003),
+            ("Matrix", 1999),
+            ("Inception", 2010),
+        ];
+        let query = TitleQuery::new("matrix");
+        let ranked = rank_by_relevance(entries, |e| query.score(e.0), |e| Some(e.1));
+        assert_eq!(
+            ranked,
+            vec![("Matrix", 1999), ("The Matrix Reloaded", 2003)]
+        );
+    }
+}
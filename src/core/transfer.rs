@@ -0,0 +1,467 @@
+//! Moving indexed titles from one disk to another.
+//!
+//! A migration is an ordinary plan: every file of a title's folder gets a
+//! Move operation (copied and checksum-verified across disks) into the same
+//! relative folder on the target disk, and the emptied source folders are
+//! removed. Once executed, the moved entries are reassigned to the target
+//! disk in the central index.
+
+use crate::core::index_store::IndexChanges;
+use crate::core::migration;
+use crate::core::query::Query;
+use crate::core::scanner;
+use crate::models::index::{CentralIndex, DiskInfo};
+use crate::models::plan::{
+    Operation, OperationType, ParsedInfo, Placement, Plan, PlanItem, PlanItemStatus, TargetInfo,
+};
+use crate::Result;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// An indexed title selected for migration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationEntry {
+    /// Index entry ID
+    pub id: String,
+    /// "movies" or "tvshows"
+    pub media_type: String,
+    pub title: String,
+    pub year: Option<u16>,
+    /// Folder of the title on the source disk
+    pub source_dir: PathBuf,
+    /// Folder of the title on the target disk
+    pub target_dir: PathBuf,
+}
+
+/// Select the entries of a disk to migrate.
+///
+/// `source_roots` and `target_roots` map a media type to its folder on each
+/// disk as mounted now; media types missing from either are left out.
+pub fn select_entries(
+    index: &CentralIndex,
+    from: &str,
+    source_roots: &HashMap<String, PathBuf>,
+    target_roots: &HashMap<String, PathBuf>,
+    filter: Option<&Query>,
+) -> Vec<MigrationEntry> {
+    let roots =
+        |media_type: &str| Some((source_roots.get(media_type)?, target_roots.get(media_type)?));
+    let entry = |media_type: &str, id: &str, title: &str, year, relative_path: &str| {
+        let (source_root, target_root) = roots(media_type)?;
+        Some(MigrationEntry {
+            id: id.to_string(),
+            media_type: media_type.to_string(),
+            title: title.to_string(),
+            year,
+            source_dir: source_root.join(relative_path),
+            target_dir: target_root.join(relative_path),
+        })
+    };
+
+    let movies = index
+        .movies
+        .iter()
+        .filter(|m| m.disk == from)
+        .filter(|m| filter.is_none_or(|q| q.matches_movie(m)))
+        .filter_map(|m| entry("movies", &m.id, &m.title, m.year, &m.relative_path));
+    let tvshows = index
+        .tvshows
+        .iter()
+        .filter(|t| t.disk == from)
+        .filter(|t| filter.is_none_or(|q| q.matches_tvshow(t)))
+        .filter_map(|t| entry("tvshows", &t.id, &t.title, t.year, &t.relative_path));
+    movies.chain(tvshows).collect()
+}
+
+/// Build a plan moving each entry's folder to the target disk.
+///
+/// Entries whose folder holds no files are left out of the plan. Source
+/// folders are removed after the moves, deepest first; folders that still
+/// hold files when the plan runs are kept.
+pub fn migration_plan(entries: &[MigrationEntry], from: &str, to: &str) -> Result<Plan> {
+    let mut items = Vec::new();
+    for entry in entries {
+        let mut files: Vec<PathBuf> = Vec::new();
+        let mut source_dirs = vec![entry.source_dir.clone()];
+        let mut operations = vec![Operation {
+            op: OperationType::Mkdir,
+            from: None,
+            to: entry.target_dir.clone(),
+            url: None,
+            content_ref: None,
+        }];
+        for dir_entry in WalkDir::new(&entry.source_dir)
+            .min_depth(1)
+            .sort_by_file_name()
+            .into_iter()
+            .filter_map(|e| e.ok())
+        {
+            let Ok(relative) = dir_entry.path().strip_prefix(&entry.source_dir) else {
+                continue;
+            };
+            let to = entry.target_dir.join(relative);
+            if dir_entry.file_type().is_dir() {
+                source_dirs.push(dir_entry.path().to_path_buf());
+                operations.push(Operation {
+                    op: OperationType::Mkdir,
+                    from: None,
+                    to,
+                    url: None,
+                    content_ref: None,
+                });
+            } else if dir_entry.file_type().is_file() {
+                files.push(dir_entry.path().to_path_buf());
+                operations.push(Operation {
+                    op: OperationType::Move,
+                    from: Some(dir_entry.path().to_path_buf()),
+                    to,
+                    url: None,
+                    content_ref: None,
+                });
+            }
+        }
+
+        // The largest file stands for the title in validation and reports
+        let Some(main_file) = files
+            .iter()
+            .max_by_key(|f| f.metadata().map(|m| m.len()).unwrap_or(0))
+        else {
+            tracing::warn!("Nothing to migrate in {}", entry.source_dir.display());
+            continue;
+        };
+        source_dirs.sort_by_key(|d| std::cmp::Reverse(d.components().count()));
+        operations.extend(source_dirs.into_iter().map(|dir| Operation {
+            op: OperationType::Rmdir,
+            from: None,
+            to: dir,
+            url: None,
+            content_ref: None,
+        }));
+        let source = scanner::create_video_file(main_file)?;
+        let full_path = entry.target_dir.join(
+            main_file
+                .strip_prefix(&entry.source_dir)
+                .unwrap_or(main_file),
+        );
+
+        items.push(PlanItem {
+            id: uuid::Uuid::new_v4().to_string(),
+            status: PlanItemStatus::Pending,
+            parsed: ParsedInfo {
+                title: Some(entry.title.clone()),
+                year: entry.year,
+                confidence: 1.0,
+                ..Default::default()
+            },
+            movie_metadata: None,
+            tvshow_metadata: None,
+            episode_metadata: None,
+            video_metadata: Default::default(),
+            target: TargetInfo {
+                folder: entry.target_dir.display().to_string(),
+                filename: source.filename.clone(),
+                full_path,
+                ..Default::default()
+            },
+            source,
+            operations,
+            placement: Some(Placement {
+                disk: to.to_string(),
+                root: entry
+                    .target_dir
+                    .parent()
+                    .map(Path::to_path_buf)
+                    .unwrap_or_default(),
+                reason: format!("Migrated from {}", from),
+            }),
+        });
+    }
+
+    let common_root = |dirs: Vec<&Path>| {
+        dirs.first()
+            .and_then(|d| d.parent())
+            .map(Path::to_path_buf)
+            .unwrap_or_default()
+    };
+    Ok(Plan {
+        version: migration::PLAN_VERSION.to_string(),
+        created_at: Utc::now().to_rfc3339(),
+        media_type: None,
+        source_path: common_root(entries.iter().map(|e| e.source_dir.as_path()).collect()),
+        target_path: common_root(entries.iter().map(|e| e.target_dir.as_path()).collect()),
+        items,
+        samples: Vec::new(),
+        unknown: Vec::new(),
+    })
+}
+
+/// Reassign migrated entries to the target disk and update disk counts.
+///
+/// `to` is stored as the target disk's info, so it should carry the media
+/// folders the entries were moved into. Returns the changed entries, disks
+/// and collections so the index can be saved without a full rewrite.
+pub fn apply_migration(
+    index: &mut CentralIndex,
+    migrated: &[&MigrationEntry],
+    from: &str,
+    to: &DiskInfo,
+) -> IndexChanges {
+    let ids: Vec<&str> = migrated.iter().map(|e| e.id.as_str()).collect();
+    let mut changes = IndexChanges::default();
+    let mut moved_movies = Vec::new();
+    for movie in index
+        .movies
+        .iter_mut()
+        .filter(|m| ids.contains(&m.id.as_str()))
+    {
+        movie.disk = to.label.clone();
+        movie.disk_uuid = to.uuid.clone();
+        moved_movies.extend(movie.tmdb_id);
+        changes.movies.push(movie.clone());
+    }
+    for show in index
+        .tvshows
+        .iter_mut()
+        .filter(|t| ids.contains(&t.id.as_str()))
+    {
+        show.disk = to.label.clone();
+        show.disk_uuid = to.uuid.clone();
+        changes.tvshows.push(show.clone());
+    }
+    for collection in index.collections.values_mut() {
+        let mut changed = false;
+        for owned in collection
+            .movies
+            .iter_mut()
+            .filter(|m| m.disk.as_deref() == Some(from) && moved_movies.contains(&m.tmdb_id))
+        {
+            owned.disk = Some(to.label.clone());
+            changed = true;
+        }
+        if changed {
+            changes.collections.push(collection.clone());
+        }
+    }
+
+    index.disks.insert(to.label.clone(), to.clone());
+    index.update_disk_counts(from);
+    index.update_disk_counts(&to.label);
+    changes.disks.extend(
+        [from, to.label.as_str()]
+            .iter()
+            .filter_map(|l| index.disks.get(*l))
+            .cloned(),
+    );
+    index.rebuild_indexes();
+    index.update_statistics();
+    index.updated_at = Utc::now().to_rfc3339();
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::index::{CollectionInfo, CollectionMovie, MovieEntry};
+    use std::fs;
+
+    fn movie(id: &str, disk: &str, folder: &str, year: u16, size: u64) -> MovieEntry {
+        MovieEntry {
+            id: id.to_string(),
+            disk: disk.to_string(),
+            relative_path: folder.to_string(),
+            title: folder.to_string(),
+            year: Some(year),
+            tmdb_id: Some(year as u64),
+            size_bytes: size,
+            ..Default::default()
+        }
+    }
+
+    fn disk(label: &str, uuid: Option<&str>) -> DiskInfo {
+        DiskInfo {
+            label: label.to_string(),
+            uuid: uuid.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn roots(path: &str) -> HashMap<String, PathBuf> {
+        HashMap::from([("movies".to_string(), PathBuf::from(path))])
+    }
+
+    fn index() -> CentralIndex {
+        let mut index = CentralIndex {
+            movies: vec![
+                movie("a", "DiskA", "Old [1990]", 1990, 10),
+                movie("b", "DiskA", "New [2010]", 2010, 20),
+                movie("c", "DiskB", "Other [1995]", 1995, 30),
+            ],
+            ..Default::default()
+        };
+        index.disks.insert("DiskA".to_string(), disk("DiskA", None));
+        index.disks.insert("DiskB".to_string(), disk("DiskB", None));
+        index.update_disk_counts("DiskA");
+        index.update_disk_counts("DiskB");
+        index
+    }
+
+    #[test]
+    fn test_select_entries_keeps_relative_folder() {
+        let index = index();
+        let entries = select_entries(
+            &index,
+            "DiskA",
+            &roots("/mnt/DiskA/Movies"),
+            &roots("/mnt/DiskB/Movies"),
+            None,
+        );
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0].source_dir,
+            PathBuf::from("/mnt/DiskA/Movies/Old [1990]")
+        );
+        assert_eq!(
+            entries[0].target_dir,
+            PathBuf::from("/mnt/DiskB/Movies/Old [1990]")
+        );
+
+        let query = Query::parse("year<2000").unwrap();
+        let entries = select_entries(
+            &index,
+            "DiskA",
+            &roots("/mnt/DiskA/Movies"),
+            &roots("/mnt/DiskB/Movies"),
+            Some(&query),
+        );
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].id, "a");
+
+        // No target folder for the media type, nothing to move
+        let entries = select_entries(
+            &index,
+            "DiskA",
+            &roots("/mnt/DiskA/Movies"),
+            &HashMap::new(),
+            None,
+        );
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_migration_plan_moves_every_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let source_dir = dir.path().join("A/Movies/Old [1990]");
+        fs::create_dir_all(source_dir.join("extras")).unwrap();
+        fs::write(source_dir.join("Old [1990].mkv"), vec![0u8; 64]).unwrap();
+        fs::write(source_dir.join("movie.nfo"), "<movie/>").unwrap();
+        fs::write(source_dir.join("extras/trailer.mkv"), vec![0u8; 8]).unwrap();
+        let empty_dir = dir.path().join("A/Movies/Empty");
+        fs::create_dir_all(&empty_dir).unwrap();
+
+        let target_dir = dir.path().join("B/Movies/Old [1990]");
+        let entry = |id: &str, source_dir: &Path, target_dir: &Path| MigrationEntry {
+            id: id.to_string(),
+            media_type: "movies".to_string(),
+            title: "Old".to_string(),
+            year: Some(1990),
+            source_dir: source_dir.to_path_buf(),
+            target_dir: target_dir.to_path_buf(),
+        };
+        let entries = vec![
+            entry("a", &source_dir, &target_dir),
+            entry("e", &empty_dir, &dir.path().join("B/Movies/Empty")),
+        ];
+
+        let plan = migration_plan(&entries, "DiskA", "DiskB").unwrap();
+        assert_eq!(plan.items.len(), 1);
+        let item = &plan.items[0];
+        assert_eq!(item.source.filename, "Old [1990].mkv");
+        assert_eq!(item.target.full_path, target_dir.join("Old [1990].mkv"));
+        assert_eq!(item.placement.as_ref().unwrap().disk, "DiskB");
+
+        let mkdirs: Vec<&PathBuf> = item
+            .operations
+            .iter()
+            .filter(|op| op.op == OperationType::Mkdir)
+            .map(|op| &op.to)
+            .collect();
+        assert_eq!(mkdirs, vec![&target_dir, &target_dir.join("extras")]);
+        let moves: Vec<&PathBuf> = item
+            .operations
+            .iter()
+            .filter(|op| op.op == OperationType::Move)
+            .map(|op| &op.to)
+            .collect();
+        assert_eq!(moves.len(), 3);
+        assert!(moves.contains(&&target_dir.join("extras/trailer.mkv")));
+        assert!(moves.contains(&&target_dir.join("movie.nfo")));
+
+        // Source folders are removed last, deepest first
+        let rmdirs: Vec<&PathBuf> = item
+            .operations
+            .iter()
+            .skip_while(|op| op.op != OperationType::Rmdir)
+            .map(|op| &op.to)
+            .collect();
+        assert_eq!(rmdirs, vec![&source_dir.join("extras"), &source_dir]);
+    }
+
+    #[test]
+    fn test_apply_migration_updates_disks_and_collections() {
+        let mut index = index();
+        index.movies[0].collection_id = Some(7);
+        index.collections.insert(
+            7,
+            CollectionInfo {
+                id: 7,
+                name: "Saga".to_string(),
+                poster_url: None,
+                movies: vec![CollectionMovie {
+                    tmdb_id: 1990,
+                    title: "Old".to_string(),
+                    year: Some(1990),
+                    disk: Some("DiskA".to_string()),
+                    owned: true,
+                }],
+                total_in_collection: 2,
+                owned_count: 1,
+            },
+        );
+
+        let entries = select_entries(
+            &index,
+            "DiskA",
+            &roots("/mnt/DiskA/Movies"),
+            &roots("/mnt/DiskB/Movies"),
+            None,
+        );
+        let migrated: Vec<&MigrationEntry> = entries.iter().filter(|e| e.id == "a").collect();
+        let mut to = disk("DiskB", Some("uuid-b"));
+        to.paths
+            .insert("movies".to_string(), "/mnt/DiskB/Movies".to_string());
+        let changes = apply_migration(&mut index, &migrated, "DiskA", &to);
+
+        assert_eq!(index.movies[0].disk, "DiskB");
+        assert_eq!(index.movies[0].disk_uuid.as_deref(), Some("uuid-b"));
+        assert_eq!(index.movies[1].disk, "DiskA");
+        assert_eq!(index.disks["DiskA"].movie_count, 1);
+        assert_eq!(index.disks["DiskA"].total_size_bytes, 20);
+        assert_eq!(index.disks["DiskB"].movie_count, 2);
+        assert_eq!(index.disks["DiskB"].total_size_bytes, 40);
+        assert_eq!(index.disks["DiskB"].paths["movies"], "/mnt/DiskB/Movies");
+        assert_eq!(
+            index.collections[&7].movies[0].disk.as_deref(),
+            Some("DiskB")
+        );
+
+        // Only what moved is reported for saving
+        let ids: Vec<&str> = changes.movies.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["a"]);
+        let mut labels: Vec<&str> = changes.disks.iter().map(|d| d.label.as_str()).collect();
+        labels.sort();
+        assert_eq!(labels, vec!["DiskA", "DiskB"]);
+        assert_eq!(changes.collections.len(), 1);
+    }
+}
@@ -0,0 +1,210 @@
+//! Watch-folder support.
+//!
+//! Tracks new entries in an incoming folder until they stop growing, so that
+//! downloads still in progress are not planned. Also splits generated plans
+//! into items that are confident enough to execute automatically and items
+//! that need manual approval.
+
+use crate::models::plan::{Plan, PlanItemStatus};
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, Instant};
+use walkdir::WalkDir;
+
+/// Tracks the size of watched directories until they are stable.
+#[derive(Debug, Default)]
+pub struct GrowthTracker {
+    /// Path -> (last observed size, time of last size change).
+    entries: HashMap<PathBuf, (u64, Instant)>,
+}
+
+impl GrowthTracker {
+    /// Create an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the current size of a path. A size change resets its quiet timer.
+    pub fn observe(&mut self, path: &Path, size: u64, now: Instant) {
+        match self.entries.get_mut(path) {
+            Some((last_size, changed_at)) => {
+                if *last_size != size {
+                    *last_size = size;
+                    *changed_at = now;
+                }
+            }
+            None => {
+                self.entries.insert(path.to_path_buf(), (size, now));
+            }
+        }
+    }
+
+    /// Mark a path as changed (e.g. on a filesystem event) without knowing its size.
+    pub fn touch(&mut self, path: &Path, now: Instant) {
+        if let Some((_, changed_at)) = self.entries.get_mut(path) {
+            *changed_at = now;
+        } else {
+            self.entries.insert(path.to_path_buf(), (0, now));
+        }
+    }
+
+    /// Paths currently being tracked.
+    pub fn tracked(&self) -> Vec<PathBuf> {
+        self.entries.keys().cloned().collect()
+    }
+
+    /// Remove and return paths that have not changed for `quiet`.
+    pub fn take_ready(&mut self, now: Instant, quiet: Duration) -> Vec<PathBuf> {
+        let mut ready: Vec<PathBuf> = self
+            .entries
+            .iter()
+            .filter(|(_, (_, changed_at))| now.duration_since(*changed_at) >= quiet)
+            .map(|(path, _)| path.clone())
+            .collect();
+        ready.sort();
+        for path in &ready {
+            self.entries.remove(path);
+        }
+        ready
+    }
+
+    /// Stop tracking a path.
+    pub fn remove(&mut self, path: &Path) {
+        self.entries.remove(path);
+    }
+}
+
+/// Map a path inside the incoming folder to its top-level entry.
+///
+/// Returns `None` for the incoming folder itself and paths outside it.
+pub fn top_level_entry(incoming: &Path, path: &Path) -> Option<PathBuf> {
+    let relative = path.strip_prefix(incoming).ok()?;
+    match relative.components().next()? {
+        Component::Normal(name) => Some(incoming.join(name)),
+        _ => None,
+    }
+}
+
+/// Total size of all files under a path.
+pub fn total_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Split a plan into an auto-executable plan and a plan left for approval.
+///
+/// Pending items with confidence at or above `min_confidence` go into the
+/// first plan. Everything else (low-confidence, skipped and unknown items)
+/// goes into the second, with low-confidence items left pending.
+pub fn split_by_confidence(plan: &Plan, min_confidence: f32) -> (Plan, Plan) {
+    let (confident, rest): (Vec<_>, Vec<_>) = plan.items.iter().cloned().partition(|item| {
+        item.status == PlanItemStatus::Pending && item.parsed.confidence >= min_confidence
+    });
+
+    let auto = Plan {
+        items: confident,
+        samples: Vec::new(),
+        unknown: Vec::new(),
+        ..plan.clone()
+    };
+    let pending = Plan {
+        items: rest,
+        ..plan.clone()
+    };
+    (auto, pending)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::media::{VideoFile, VideoMetadata};
+    use crate::models::plan::{ParsedInfo, PlanItem, TargetInfo};
+
+    fn item(confidence: f32, status: PlanItemStatus) -> PlanItem {
+        PlanItem {
+            id: confidence.to_string(),
+            status,
+            source: VideoFile {
+                path: PathBuf::from("/in/a.mkv"),
+                filename: "a.mkv".to_string(),
+                size: 0,
+                modified: chrono::Utc::now(),
+                is_sample: false,
+                parent_dir: PathBuf::from("/in"),
+            },
+            parsed: ParsedInfo {
+                confidence,
+                ..Default::default()
+            },
+            movie_metadata: None,
+            tvshow_metadata: None,
+            episode_metadata: None,
+            video_metadata: VideoMetadata::default(),
+            target: TargetInfo::default(),
+            operations: vec![],
+            placement: None,
+        }
+    }
+
+    #[test]
+    fn test_growth_tracker_waits_for_stable_size() {
+        let start = Instant::now();
+        let quiet = Duration::from_secs(10);
+        let dir = PathBuf::from("/in/Movie");
+        let mut tracker = GrowthTracker::new();
+
+        tracker.observe(&dir, 100, start);
+        assert!(tracker
+            .take_ready(start + Duration::from_secs(5), quiet)
+            .is_empty());
+
+        // Still growing: timer resets
+        tracker.observe(&dir, 200, start + Duration::from_secs(8));
+        assert!(tracker
+            .take_ready(start + Duration::from_secs(12), quiet)
+            .is_empty());
+
+        // Same size: timer keeps running
+        tracker.observe(&dir, 200, start + Duration::from_secs(15));
+        let ready = tracker.take_ready(start + Duration::from_secs(18), quiet);
+        assert_eq!(ready, vec![dir]);
+        assert!(tracker.tracked().is_empty());
+    }
+
+    #[test]
+    fn test_top_level_entry() {
+        let incoming = Path::new("/in");
+        assert_eq!(
+            top_level_entry(incoming, Path::new("/in/Movie (2020)/CD1/a.mkv")),
+            Some(PathBuf::from("/in/Movie (2020)"))
+        );
+        assert_eq!(top_level_entry(incoming, Path::new("/in")), None);
+        assert_eq!(top_level_entry(incoming, Path::new("/other/a.mkv")), None);
+    }
+
+    #[test]
+    fn test_split_by_confidence() {
+        let plan = Plan {
+            items: vec![
+                item(0.95, PlanItemStatus::Pending),
+                item(0.5, PlanItemStatus::Pending),
+                item(0.99, PlanItemStatus::Skip),
+            ],
+            ..Default::default()
+        };
+
+        let (auto, pending) = split_by_confidence(&plan, 0.9);
+        assert_eq!(auto.items.len(), 1);
+        assert_eq!(auto.items[0].id, "0.95");
+        assert_eq!(pending.items.len(), 2);
+        assert!(pending
+            .items
+            .iter()
+            .any(|i| i.status == PlanItemStatus::Pending));
+    }
+}
@@ -80,6 +80,10 @@ pub enum Error {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
+    // CSV errors
+    #[error("CSV error: {0}")]
+    Csv(#[from] csv::Error),
+
     // Generic errors
     #[error("{0}")]
     Other(String),
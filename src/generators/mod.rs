@@ -1,5 +1,6 @@
-//! Generator modules for filenames, folders, and NFO files.
+//! Generator modules for filenames, folders, NFO files, and HTML reports.
 
 pub mod filename;
 pub mod folder;
 pub mod nfo;
+pub mod report;
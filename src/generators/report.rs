@@ -0,0 +1,426 @@
+//! HTML report generator for plans and executions.
+//!
+//! Produces a single self-contained HTML page (inline CSS and JS) that lists
+//! every plan item with its poster, matched title, source/target paths and
+//! confidence, plus the unknown files grouped by reason. When a rollback file
+//! is supplied, each item is annotated with its execution outcome.
+
+use crate::models::plan::{OperationType, Plan, PlanItem, PlanItemStatus};
+use crate::models::rollback::{Rollback, RollbackOpType};
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+
+/// Outcome of a plan item, as shown in the report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemOutcome {
+    /// Not executed yet (no rollback supplied).
+    Planned,
+    /// Video file was moved to its target.
+    Succeeded,
+    /// Item was pending but its move was not recorded in the rollback.
+    Failed,
+    /// Item was marked skip/error in the plan.
+    Skipped,
+}
+
+impl ItemOutcome {
+    fn label(&self) -> &'static str {
+        match self {
+            ItemOutcome::Planned => "planned",
+            ItemOutcome::Succeeded => "succeeded",
+            ItemOutcome::Failed => "failed",
+            ItemOutcome::Skipped => "skipped",
+        }
+    }
+}
+
+/// Determine the outcome of an item given an optional rollback.
+///
+/// An item is considered executed when its video move appears in the rollback,
+/// or when the move was already done by an earlier (interrupted) run, i.e. the
+/// source is gone and the target exists.
+pub fn item_outcome(item: &PlanItem, moved: Option<&HashSet<(PathBuf, PathBuf)>>) -> ItemOutcome {
+    if item.status != PlanItemStatus::Pending {
+        return ItemOutcome::Skipped;
+    }
+
+    let Some(moved) = moved else {
+        return ItemOutcome::Planned;
+    };
+
+    let video_moves: Vec<_> = item
+        .operations
+        .iter()
+        .filter(|op| op.op == OperationType::Move && op.from.as_ref() == Some(&item.source.path))
+        .collect();
+
+    let recorded = video_moves.iter().any(|op| {
+        op.from
+            .as_ref()
+            .map(|from| moved.contains(&(from.clone(), op.to.clone())))
+            .unwrap_or(false)
+    });
+
+    if recorded || (!item.source.path.exists() && item.target.full_path.exists()) {
+        ItemOutcome::Succeeded
+    } else {
+        ItemOutcome::Failed
+    }
+}
+
+/// Collect the (from, to) pairs of all executed move operations in a rollback.
+pub fn executed_moves(rollback: &Rollback) -> HashSet<(PathBuf, PathBuf)> {
+    rollback
+        .operations
+        .iter()
+        .filter(|op| op.executed && op.op_type == RollbackOpType::Move)
+        .map(|op| (op.from.clone(), op.to.clone()))
+        .collect()
+}
+
+/// Generate an HTML report for a plan, optionally annotated with a rollback.
+pub fn generate_plan_report(plan: &Plan, rollback: Option<&Rollback>) -> String {
+    let moved = rollback.map(executed_moves);
+
+    let outcomes: Vec<ItemOutcome> = plan
+        .items
+        .iter()
+        .map(|item| item_outcome(item, moved.as_ref()))
+        .collect();
+
+    let count = |o: ItemOutcome| outcomes.iter().filter(|x| **x == o).count();
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str(&format!(
+        "<title>Media Organizer Report - {}</title>\n",
+        escape_html(&plan.source_path.display().to_string())
+    ));
+    html.push_str(STYLE);
+    html.push_str("</head>\n<body>\n");
+
+    // Header and summary
+    html.push_str("<h1>Media Organizer Report</h1>\n<table class=\"meta\">\n");
+    let media_type = plan
+        .media_type
+        .map(|t| t.to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    for (key, value) in [
+        ("Created", plan.created_at.clone()),
+        ("Media type", media_type),
+        ("Source", plan.source_path.display().to_string()),
+        ("Target", plan.target_path.display().to_string()),
+    ] {
+        html.push_str(&format!(
+            "<tr><th>{}</th><td>{}</td></tr>\n",
+            key,
+            escape_html(&value)
+        ));
+    }
+    if let Some(rb) = rollback {
+        html.push_str(&format!(
+            "<tr><th>Executed</th><td>{}</td></tr>\n",
+            escape_html(&rb.executed_at)
+        ));
+    }
+    html.push_str("</table>\n");
+
+    html.push_str("<div class=\"summary\">\n");
+    html.push_str(&format!(
+        "<span>Items: <b>{}</b></span>\n",
+        plan.items.len()
+    ));
+    if rollback.is_some() {
+        html.push_str(&format!(
+            "<span class=\"succeeded\">Succeeded: <b>{}</b></span>\n",
+            count(ItemOutcome::Succeeded)
+        ));
+        html.push_str(&format!(
+            "<span class=\"failed\">Failed: <b>{}</b></span>\n",
+            count(ItemOutcome::Failed)
+        ));
+    }
+    html.push_str(&format!(
+        "<span class=\"skipped\">Skipped: <b>{}</b></span>\n",
+        count(ItemOutcome::Skipped)
+    ));
+    html.push_str(&format!(
+        "<span>Unknown: <b>{}</b></span>\n",
+        plan.unknown.len()
+    ));
+    html.push_str("</div>\n");
+
+    // Filters
+    html.push_str("<div class=\"filters\">\n");
+    html.push_str("<input id=\"q\" type=\"search\" placeholder=\"Filter by title or path...\">\n");
+    html.push_str("<select id=\"status\">\n<option value=\"\">All statuses</option>\n");
+    for outcome in [
+        ItemOutcome::Planned,
+        ItemOutcome::Succeeded,
+        ItemOutcome::Failed,
+        ItemOutcome::Skipped,
+    ] {
+        if count(outcome) > 0 {
+            html.push_str(&format!(
+                "<option value=\"{0}\">{0}</option>\n",
+                outcome.label()
+            ));
+        }
+    }
+    html.push_str("</select>\n");
+    html.push_str("<label>Min confidence <input id=\"conf\" type=\"number\" min=\"0\" max=\"1\" step=\"0.05\" value=\"0\"></label>\n");
+    html.push_str("</div>\n");
+
+    // Items
+    html.push_str("<h2>Items</h2>\n<table id=\"items\" class=\"items\">\n");
+    html.push_str("<thead><tr><th>Poster</th><th>Title</th><th>TMDB</th><th>Source → Target</th><th>Confidence</th><th>Status</th></tr></thead>\n<tbody>\n");
+    for (item, outcome) in plan.items.iter().zip(&outcomes) {
+        html.push_str(&render_item(item, *outcome));
+    }
+    html.push_str("</tbody>\n</table>\n");
+
+    // Unknown files grouped by reason
+    if !plan.unknown.is_empty() {
+        let mut grouped: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for item in &plan.unknown {
+            grouped
+                .entry(item.reason.as_str())
+                .or_default()
+                .push(item.source.path.display().to_string());
+        }
+
+        html.push_str("<h2>Unknown Files</h2>\n");
+        for (reason, paths) in grouped {
+            html.push_str(&format!(
+                "<details class=\"unknown\" open><summary>{} ({} files)</summary>\n<ul>\n",
+                escape_html(reason),
+                paths.len()
+            ));
+            for path in paths {
+                html.push_str(&format!("<li>{}</li>\n", escape_html(&path)));
+            }
+            html.push_str("</ul>\n</details>\n");
+        }
+    }
+
+    html.push_str(SCRIPT);
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Render a single item row.
+fn render_item(item: &PlanItem, outcome: ItemOutcome) -> String {
+    let (title, year, tmdb_id, poster) = if let Some(ref movie) = item.movie_metadata {
+        (
+            movie.title.clone(),
+            movie.year,
+            Some(movie.tmdb_id),
+            movie.poster_urls.first().cloned(),
+        )
+    } else if let Some(ref show) = item.tvshow_metadata {
+        let title = match item.episode_metadata {
+            Some(ref ep) => format!(
+                "{} S{:02}E{:02} {}",
+                show.name, ep.season_number, ep.episode_number, ep.name
+            ),
+            None => show.name.clone(),
+        };
+        (
+            title,
+            show.year,
+            Some(show.tmdb_id),
+            show.poster_urls.first().cloned(),
+        )
+    } else {
+        (
+            item.parsed.title.clone().unwrap_or_default(),
+            item.parsed.year.unwrap_or(0),
+            None,
+            None,
+        )
+    };
+
+    let poster_cell = match poster {
+        Some(url) => format!(
+            "<img src=\"{}\" loading=\"lazy\" alt=\"\">",
+            escape_html(&url)
+        ),
+        None => String::new(),
+    };
+    let year_str = if year > 0 {
+        format!(" ({})", year)
+    } else {
+        String::new()
+    };
+    let tmdb_cell = tmdb_id.map(|id| id.to_string()).unwrap_or_default();
+    let source = item.source.path.display().to_string();
+    let target = item.target.full_path.display().to_string();
+
+    format!(
+        "<tr data-status=\"{status}\" data-conf=\"{conf:.2}\" data-text=\"{text}\">\
+<td class=\"poster\">{poster}</td>\
+<td><b>{title}</b>{year}</td>\
+<td>{tmdb}</td>\
+<td class=\"paths\"><div>{source}</div><div class=\"arrow\">→ {target}</div></td>\
+<td>{conf:.2}</td>\
+<td class=\"{status}\">{status}</td></tr>\n",
+        status = outcome.label(),
+        conf = item.parsed.confidence,
+        text = escape_html(&format!("{} {} {}", title, source, target).to_lowercase()),
+        poster = poster_cell,
+        title = escape_html(&title),
+        year = year_str,
+        tmdb = tmdb_cell,
+        source = escape_html(&source),
+        target = escape_html(&target),
+    )
+}
+
+/// Escape special characters for HTML text and attribute values.
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+const STYLE: &str = r#"<style>
+body { font-family: sans-serif; margin: 2em; color: #222; }
+table.meta th { text-align: left; padding-right: 1em; }
+.summary span { margin-right: 1.5em; }
+.filters { margin: 1em 0; display: flex; gap: 1em; align-items: center; }
+.filters input[type=search] { width: 24em; }
+table.items { border-collapse: collapse; width: 100%; }
+table.items th, table.items td { border-bottom: 1px solid #ddd; padding: 4px 8px; vertical-align: top; }
+td.poster img { width: 60px; }
+td.paths { font-family: monospace; font-size: 0.85em; word-break: break-all; }
+td.paths .arrow { color: #555; }
+.succeeded { color: #1a7f37; }
+.failed { color: #cf222e; }
+.skipped { color: #9a6700; }
+details.unknown { margin-bottom: 0.5em; }
+details.unknown li { font-family: monospace; font-size: 0.85em; }
+</style>
+"#;
+
+const SCRIPT: &str = r#"<script>
+(function () {
+  var q = document.getElementById('q');
+  var status = document.getElementById('status');
+  var conf = document.getElementById('conf');
+  function apply() {
+    var text = q.value.toLowerCase();
+    var st = status.value;
+    var min = parseFloat(conf.value) || 0;
+    document.querySelectorAll('#items tbody tr').forEach(function (row) {
+      var show = (!text || row.dataset.text.indexOf(text) >= 0)
+        && (!st || row.dataset.status === st)
+        && parseFloat(row.dataset.conf) >= min;
+      row.style.display = show ? '' : 'none';
+    });
+  }
+  [q, status, conf].forEach(function (el) { el.addEventListener('input', apply); });
+})();
+</script>
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::media::{MovieMetadata, VideoFile, VideoMetadata};
+    use crate::models::plan::{Operation, ParsedInfo, TargetInfo, UnknownItem};
+    use crate::models::rollback::{RollbackAction, RollbackActionType, RollbackOperation};
+
+    fn video(path: &str) -> VideoFile {
+        let path = PathBuf::from(path);
+        VideoFile {
+            filename: path.file_name().unwrap().to_string_lossy().to_string(),
+            parent_dir: path.parent().unwrap().to_path_buf(),
+            path,
+            size: 1024,
+            modified: chrono::Utc::now(),
+            is_sample: false,
+        }
+    }
+
+    fn movie_item(source: &str, target: &str) -> PlanItem {
+        PlanItem {
+            id: "1".to_string(),
+            status: PlanItemStatus::Pending,
+            source: video(source),
+            parsed: ParsedInfo {
+                confidence: 0.9,
+                ..Default::default()
+            },
+            movie_metadata: Some(MovieMetadata {
+                tmdb_id: 19995,
+                title: "Avatar <3D>".to_string(),
+                year: 2009,
+                poster_urls: vec!["https://image.tmdb.org/t/p/w500/a.jpg".to_string()],
+                ..Default::default()
+            }),
+            tvshow_metadata: None,
+            episode_metadata: None,
+            video_metadata: VideoMetadata::default(),
+            target: TargetInfo {
+                full_path: PathBuf::from(target),
+                ..Default::default()
+            },
+            operations: vec![Operation {
+                op: OperationType::Move,
+                from: Some(PathBuf::from(source)),
+                to: PathBuf::from(target),
+                url: None,
+                content_ref: None,
+            }],
+            placement: None,
+        }
+    }
+
+    #[test]
+    fn test_report_contains_items_and_unknown() {
+        let plan = Plan {
+            items: vec![movie_item("/src/avatar.mkv", "/dst/Avatar/avatar.mkv")],
+            unknown: vec![UnknownItem {
+                source: video("/src/random.mkv"),
+                reason: "No TMDB match".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        let html = generate_plan_report(&plan, None);
+        assert!(html.contains("Avatar &lt;3D&gt;"));
+        assert!(html.contains("https://image.tmdb.org/t/p/w500/a.jpg"));
+        assert!(html.contains("/dst/Avatar/avatar.mkv"));
+        assert!(html.contains("No TMDB match (1 files)"));
+        assert!(html.contains("data-status=\"planned\""));
+    }
+
+    #[test]
+    fn test_item_outcome_with_rollback() {
+        let item = movie_item("/src/a.mkv", "/dst/a.mkv");
+        let other = movie_item("/src/b.mkv", "/dst/b.mkv");
+        let rollback = Rollback {
+            operations: vec![RollbackOperation {
+                seq: 1,
+                op_type: RollbackOpType::Move,
+                from: PathBuf::from("/src/a.mkv"),
+                to: PathBuf::from("/dst/a.mkv"),
+                checksum: None,
+                rollback: RollbackAction {
+                    op: RollbackActionType::Move,
+                    path: PathBuf::from("/dst/a.mkv"),
+                    to: Some(PathBuf::from("/src/a.mkv")),
+                },
+                executed: true,
+            }],
+            ..Default::default()
+        };
+
+        let moved = executed_moves(&rollback);
+        assert_eq!(item_outcome(&item, Some(&moved)), ItemOutcome::Succeeded);
+        assert_eq!(item_outcome(&other, Some(&moved)), ItemOutcome::Failed);
+        assert_eq!(item_outcome(&item, None), ItemOutcome::Planned);
+    }
+}
@@ -8,6 +8,7 @@ pub mod error;
 pub mod generators;
 pub mod models;
 pub mod preflight;
+pub mod server;
 pub mod services;
 pub mod utils;
 
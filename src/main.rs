@@ -4,8 +4,10 @@
 
 use clap::Parser;
 use media_organizer::cli::{
-    args::{Cli, Commands, PlanType, SessionsAction},
-    commands::{execute, export_import, index, plan, rollback, search, sessions, verify},
+    args::{Cli, Commands, PlanType, RollbackAction, SessionsAction},
+    commands::{
+        execute, export_import, index, plan, rollback, search, serve, sessions, verify, watch,
+    },
 };
 use media_organizer::preflight;
 
@@ -20,8 +22,12 @@ async fn main() -> anyhow::Result<()> {
     // Run the appropriate command
     match cli.command {
         Commands::Plan { media_type } => {
-            // Run preflight checks unless skipped
-            if !cli.skip_preflight {
+            // Run preflight checks unless skipped (only planning needs external services)
+            let needs_preflight = matches!(
+                media_type,
+                PlanType::Movies { .. } | PlanType::Tvshows { .. }
+            );
+            if !cli.skip_preflight && needs_preflight {
                 run_preflight_checks().await?;
             }
 
@@ -40,19 +46,74 @@ async fn main() -> anyhow::Result<()> {
                 } => {
                     plan::plan_tvshows(&source, target.as_deref(), output.as_deref()).await?;
                 }
+                PlanType::Report {
+                    plan_file,
+                    html,
+                    rollback,
+                } => {
+                    plan::report_plan(&plan_file, &html, rollback.as_deref()).await?;
+                }
+                PlanType::Export {
+                    plan_file,
+                    format,
+                    output,
+                } => {
+                    plan::export_plan(&plan_file, &format, output.as_deref()).await?;
+                }
+                PlanType::ImportCsv {
+                    plan_file,
+                    csv_file,
+                    output,
+                } => {
+                    plan::import_plan_csv(&plan_file, &csv_file, output.as_deref()).await?;
+                }
             }
         }
 
-        Commands::Execute { plan_file, output } => {
-            execute::execute_plan(&plan_file, output.as_deref()).await?;
+        Commands::Execute {
+            plan_file,
+            output,
+            checksum,
+            refresh_library,
+            refresh_dry_run,
+        } => {
+            let refresh = if refresh_dry_run {
+                execute::LibraryRefresh::DryRun
+            } else if refresh_library {
+                execute::LibraryRefresh::Send
+            } else {
+                execute::LibraryRefresh::Off
+            };
+            execute::execute_plan(&plan_file, output.as_deref(), checksum, refresh).await?;
         }
 
         Commands::Rollback {
+            action,
             rollback_file,
             dry_run,
-        } => {
-            rollback::rollback(&rollback_file, dry_run).await?;
-        }
+            seq,
+            folder,
+            title,
+            residual,
+            verify_checksum,
+        } => match action {
+            Some(RollbackAction::Verify { rollback_file }) => {
+                rollback::verify_rollback(&rollback_file).await?;
+            }
+            None => {
+                // clap enforces ROLLBACK_FILE when no subcommand is given
+                let rollback_file = rollback_file.expect("ROLLBACK_FILE is required");
+                let selection = rollback::parse_selection(seq.as_deref(), folder, title)?;
+                rollback::rollback(
+                    &rollback_file,
+                    dry_run,
+                    &selection,
+                    residual.as_deref(),
+                    verify_checksum,
+                )
+                .await?;
+            }
+        },
 
         Commands::Sessions { action } => match action {
             SessionsAction::List => {
@@ -61,8 +122,55 @@ async fn main() -> anyhow::Result<()> {
             SessionsAction::Show { session_id } => {
                 sessions::show_session(&session_id).await?;
             }
+            SessionsAction::Migrate => {
+                sessions::migrate_sessions().await?;
+            }
+            SessionsAction::UndoLast { dry_run } => {
+                sessions::undo_last(dry_run).await?;
+            }
+            SessionsAction::Prune {
+                older_than,
+                keep_executed,
+                dry_run,
+            } => {
+                sessions::prune_sessions(&older_than, keep_executed, dry_run).await?;
+            }
+            SessionsAction::Delete { session_id } => {
+                sessions::delete_session(&session_id).await?;
+            }
+            SessionsAction::Diff {
+                session_a,
+                session_b,
+            } => {
+                sessions::diff_sessions(&session_a, &session_b).await?;
+            }
         },
 
+        Commands::Watch {
+            incoming,
+            target,
+            media_type,
+            quiet_secs,
+            auto_execute,
+            min_confidence,
+        } => {
+            if !cli.skip_preflight {
+                run_preflight_checks().await?;
+            }
+            let options = watch::WatchOptions {
+                media_type: watch::parse_media_type(&media_type)?,
+                target,
+                quiet_secs,
+                auto_execute,
+                min_confidence,
+            };
+            watch::watch(&incoming, options).await?;
+        }
+
+        Commands::Serve { bind } => {
+            serve::serve(&bind).await?;
+        }
+
         Commands::Verify { path } => {
             verify::verify(&path).await?;
         }
@@ -79,9 +187,20 @@ async fn main() -> anyhow::Result<()> {
             year,
             genre,
             country,
+            query,
+            watched,
+            unwatched,
+            favorite,
+            tag,
             show_status,
             format,
         } => {
+            let user_filter = search::UserFilter {
+                watched,
+                unwatched,
+                favorite,
+                tag,
+            };
             search::execute_search(
                 title,
                 actor,
@@ -90,6 +209,8 @@ async fn main() -> anyhow::Result<()> {
                 year,
                 genre,
                 country,
+                query,
+                user_filter,
                 show_status,
                 format,
             )
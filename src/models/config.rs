@@ -5,6 +5,7 @@ use std::path::PathBuf;
 
 /// Application configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
     /// Ollama configuration.
     pub ollama: OllamaConfig,
@@ -12,6 +13,12 @@ pub struct Config {
     pub tmdb: TmdbConfig,
     /// Sessions directory.
     pub sessions_dir: PathBuf,
+    /// Hooks run after plan generation, execution and rollback.
+    pub hooks: Vec<HookConfig>,
+    /// Media servers to refresh after execution.
+    pub media_servers: Vec<MediaServerConfig>,
+    /// Central index storage.
+    pub index: IndexConfig,
 }
 
 /// Ollama configuration.
@@ -36,12 +43,154 @@ pub struct TmdbConfig {
     pub language: String,
 }
 
+/// Event that triggers a hook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HookEvent {
+    /// A plan was generated.
+    Plan,
+    /// A plan was executed.
+    Execute,
+    /// An execution was rolled back.
+    Rollback,
+}
+
+impl std::fmt::Display for HookEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HookEvent::Plan => write!(f, "plan"),
+            HookEvent::Execute => write!(f, "execute"),
+            HookEvent::Rollback => write!(f, "rollback"),
+        }
+    }
+}
+
+/// A hook: a shell command and/or a webhook receiving a JSON summary.
+///
+/// ```toml
+/// [[hooks]]
+/// events = ["execute", "rollback"]
+/// command = "~/bin/notify-chat.sh"
+/// url = "http://localhost:9000/media-organizer"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HookConfig {
+    /// Events that trigger the hook (all events if empty).
+    #[serde(default)]
+    pub events: Vec<HookEvent>,
+    /// Shell command, run with `sh -c` and the summary on stdin.
+    #[serde(default)]
+    pub command: Option<String>,
+    /// Webhook URL, receives the summary as a JSON POST body.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Timeout in seconds.
+    #[serde(default = "default_hook_timeout")]
+    pub timeout: u64,
+}
+
+fn default_hook_timeout() -> u64 {
+    30
+}
+
+impl HookConfig {
+    /// Whether the hook should run for an event.
+    pub fn handles(&self, event: HookEvent) -> bool {
+        self.events.is_empty() || self.events.contains(&event)
+    }
+}
+
+/// Media server type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MediaServerKind {
+    Jellyfin,
+    Emby,
+    Plex,
+}
+
+impl std::fmt::Display for MediaServerKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MediaServerKind::Jellyfin => write!(f, "Jellyfin"),
+            MediaServerKind::Emby => write!(f, "Emby"),
+            MediaServerKind::Plex => write!(f, "Plex"),
+        }
+    }
+}
+
+/// A media server whose library is refreshed after execution.
+///
+/// ```toml
+/// [[media_servers]]
+/// kind = "plex"
+/// url = "http://localhost:32400"
+/// token = "..."
+/// section_id = 1
+/// local_root = "/mnt/media"    # optional: rewrite paths for the server
+/// server_root = "/data/media"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediaServerConfig {
+    /// Server type.
+    pub kind: MediaServerKind,
+    /// Base URL, e.g. "http://localhost:8096".
+    pub url: String,
+    /// API key (Jellyfin/Emby) or X-Plex-Token (Plex).
+    pub token: String,
+    /// Plex library section to refresh.
+    #[serde(default)]
+    pub section_id: Option<u32>,
+    /// Local path prefix to replace with `server_root`.
+    #[serde(default)]
+    pub local_root: Option<PathBuf>,
+    /// Path prefix as seen by the media server.
+    #[serde(default)]
+    pub server_root: Option<PathBuf>,
+}
+
+/// Storage backend for the central index.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IndexBackend {
+    /// Single `central_index.json` file.
+    #[default]
+    Json,
+    /// SQLite database `central_index.db`.
+    Sqlite,
+}
+
+impl std::fmt::Display for IndexBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IndexBackend::Json => write!(f, "json"),
+            IndexBackend::Sqlite => write!(f, "sqlite"),
+        }
+    }
+}
+
+/// Central index configuration.
+///
+/// ```toml
+/// [index]
+/// backend = "sqlite"
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IndexConfig {
+    /// Storage backend.
+    pub backend: IndexBackend,
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
             ollama: OllamaConfig::default(),
             tmdb: TmdbConfig::default(),
             sessions_dir: dirs_config_path().join("sessions"),
+            hooks: Vec::new(),
+            media_servers: Vec::new(),
+            index: IndexConfig::default(),
         }
     }
 }
@@ -79,8 +228,9 @@ pub fn load_config() -> Config {
 
     if config_path.exists() {
         if let Ok(content) = std::fs::read_to_string(&config_path) {
-            if let Ok(config) = toml::from_str(&content) {
-                return config;
+            match toml::from_str(&content) {
+                Ok(config) => return config,
+                Err(e) => tracing::warn!("Ignoring invalid config {:?}: {}", config_path, e),
             }
         }
     }
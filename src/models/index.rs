@@ -1,10 +1,12 @@
 //! Central index data structures for cross-disk media management.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::Path;
 
 /// Central index containing all media information across all disks.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CentralIndex {
     /// Schema version
     pub version: String,
@@ -29,7 +31,7 @@ pub struct CentralIndex {
 impl Default for CentralIndex {
     fn default() -> Self {
         Self {
-            version: "1.0".to_string(),
+            version: crate::core::migration::INDEX_VERSION.to_string(),
             created_at: chrono::Utc::now().to_rfc3339(),
             updated_at: chrono::Utc::now().to_rfc3339(),
             disks: HashMap::new(),
@@ -46,7 +48,7 @@ impl Default for CentralIndex {
 ///
 /// Supports composite storage: one disk label can have multiple media types
 /// (movies and tvshows) with different paths.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct DiskInfo {
     /// Disk label (user-friendly name)
     pub label: String,
@@ -67,10 +69,25 @@ pub struct DiskInfo {
     /// Extensible for future media types (e.g., "music", "audiobooks")
     #[serde(default)]
     pub paths: HashMap<String, String>,
+    /// Disk capacity in bytes, when last seen
+    #[serde(default)]
+    pub capacity_bytes: Option<u64>,
+    /// Free space in bytes, when last seen
+    #[serde(default)]
+    pub free_bytes: Option<u64>,
+    /// Filesystem type (e.g., "ext4", "ntfs")
+    #[serde(default)]
+    pub filesystem: Option<String>,
+    /// Mount point when last seen
+    #[serde(default)]
+    pub mount_point: Option<String>,
+    /// When the disk was last seen mounted
+    #[serde(default)]
+    pub last_seen: Option<String>,
 }
 
 /// A movie entry in the index.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct MovieEntry {
     /// Unique identifier
     pub id: String,
@@ -112,12 +129,27 @@ pub struct MovieEntry {
     pub size_bytes: u64,
     /// Video resolution (e.g., "1080p", "4K")
     pub resolution: Option<String>,
+    /// Video codec (e.g., "hevc", "h264")
+    #[serde(default)]
+    pub video_codec: Option<String>,
+    /// HDR format (e.g., "HDR10", "Dolby Vision"); None for SDR or unknown
+    #[serde(default)]
+    pub hdr: Option<String>,
+    /// Average bitrate in kbit/s, estimated from size and runtime
+    #[serde(default)]
+    pub bitrate_kbps: Option<u32>,
     /// When this entry was indexed
     pub indexed_at: String,
+    /// State of the NFO and video files when indexed
+    #[serde(default)]
+    pub scan_stamp: Option<ScanStamp>,
+    /// Watched state, personal rating, tags and notes
+    #[serde(default, skip_serializing_if = "UserData::is_empty")]
+    pub user_data: UserData,
 }
 
 /// A TV show entry in the index.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct TvShowEntry {
     /// Unique identifier
     pub id: String,
@@ -151,10 +183,82 @@ pub struct TvShowEntry {
     pub size_bytes: u64,
     /// When this entry was indexed
     pub indexed_at: String,
+    /// Episode files found in the show directory
+    #[serde(default)]
+    pub episode_files: Vec<EpisodeFile>,
+    /// State of the NFO and video files when indexed
+    #[serde(default)]
+    pub scan_stamp: Option<ScanStamp>,
+    /// Watched state, personal rating, tags and notes
+    #[serde(default, skip_serializing_if = "UserData::is_empty")]
+    pub user_data: UserData,
+}
+
+/// Personal state of a title, kept across rescans.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct UserData {
+    /// Whether the title has been watched
+    #[serde(default)]
+    pub watched: bool,
+    /// When it was last watched (e.g., "2024-05-01")
+    #[serde(default)]
+    pub watched_at: Option<String>,
+    /// Number of times played, as recorded by a media center
+    #[serde(default)]
+    pub play_count: u32,
+    /// Whether the watched state was set by hand, so NFO watch history no
+    /// longer overrides it
+    #[serde(default)]
+    pub watched_by_hand: bool,
+    /// Favorite title
+    #[serde(default)]
+    pub favorite: bool,
+    /// Personal rating (0-10)
+    #[serde(default)]
+    pub rating: Option<f32>,
+    /// Personal tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Personal notes
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+/// Modification times and sizes of an entry's files, used by incremental
+/// scans to skip entries that have not changed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ScanStamp {
+    /// NFO modification time (Unix nanoseconds)
+    pub nfo_modified: i64,
+    /// NFO size in bytes
+    pub nfo_size: u64,
+    /// Number of video files in the directory
+    pub video_count: usize,
+    /// Total size of the video files in bytes
+    pub videos_size: u64,
+    /// Latest modification time of the video files (Unix nanoseconds)
+    pub videos_modified: i64,
+}
+
+/// An episode file of an indexed TV show.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct EpisodeFile {
+    /// Season number
+    pub season: u16,
+    /// Episode number
+    pub episode: u16,
+    /// Episode title
+    pub title: Option<String>,
+    /// Video resolution (e.g., "1080p")
+    pub resolution: Option<String>,
+    /// File size in bytes
+    pub size_bytes: u64,
+    /// Relative path from disk root
+    pub relative_path: String,
 }
 
 /// Movie collection information (e.g., "Pirates of the Caribbean Collection").
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CollectionInfo {
     /// TMDB collection ID
     pub id: u64,
@@ -171,7 +275,7 @@ pub struct CollectionInfo {
 }
 
 /// A movie within a collection.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CollectionMovie {
     /// TMDB ID
     pub tmdb_id: u64,
@@ -186,7 +290,7 @@ pub struct CollectionMovie {
 }
 
 /// Search indexes for fast lookup.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct SearchIndexes {
     /// Index by actor name -> list of movie/tvshow IDs
     pub by_actor: HashMap<String, Vec<String>>,
@@ -203,7 +307,7 @@ pub struct SearchIndexes {
 }
 
 /// Collection statistics.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct IndexStatistics {
     /// Total number of movies
     pub total_movies: usize,
@@ -224,7 +328,7 @@ pub struct IndexStatistics {
 }
 
 /// Individual disk index (stored separately for each disk).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DiskIndex {
     /// Schema version
     pub version: String,
@@ -239,16 +343,10 @@ pub struct DiskIndex {
 impl Default for DiskIndex {
     fn default() -> Self {
         Self {
-            version: "1.0".to_string(),
+            version: crate::core::migration::INDEX_VERSION.to_string(),
             disk: DiskInfo {
-                label: String::new(),
-                uuid: None,
                 last_indexed: chrono::Utc::now().to_rfc3339(),
-                movie_count: 0,
-                tvshow_count: 0,
-                total_size_bytes: 0,
-                base_path: String::new(),
-                paths: HashMap::new(),
+                ..Default::default()
             },
             movies: Vec::new(),
             tvshows: Vec::new(),
@@ -257,7 +355,7 @@ impl Default for DiskIndex {
 }
 
 /// Export manifest for backup files.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ExportManifest {
     /// Schema version
     pub version: String,
@@ -278,7 +376,7 @@ pub struct ExportManifest {
 }
 
 /// What's included in the export.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ExportContents {
     /// Whether config is included
     pub config: bool,
@@ -293,7 +391,7 @@ pub struct ExportContents {
 }
 
 /// Export statistics.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ExportStatistics {
     pub total_movies: usize,
     pub total_tvshows: usize,
@@ -303,13 +401,252 @@ pub struct ExportStatistics {
 }
 
 /// Source path information for the export.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SourcePaths {
     pub config_dir: String,
     pub hostname: String,
 }
 
+impl DiskInfo {
+    /// Take the space and mount details of a later sighting of the disk.
+    pub fn update_mount(&mut self, seen: &DiskInfo) {
+        if seen.last_seen.is_none() {
+            return;
+        }
+        if let Some(mount_point) = &seen.mount_point {
+            self.rebase_paths(mount_point);
+        }
+        self.capacity_bytes = seen.capacity_bytes;
+        self.free_bytes = seen.free_bytes;
+        self.filesystem = seen.filesystem.clone();
+        self.mount_point = seen.mount_point.clone();
+        self.last_seen = seen.last_seen.clone();
+    }
+
+    /// Move the indexed paths from the recorded mount point to another one.
+    pub fn rebase_paths(&mut self, mount_point: &str) {
+        let Some(old) = self.mount_point.clone() else {
+            return;
+        };
+        let rebase = |path: &mut String| {
+            if let Ok(relative) = Path::new(path.as_str()).strip_prefix(&old) {
+                *path = Path::new(mount_point)
+                    .join(relative)
+                    .to_string_lossy()
+                    .to_string();
+            }
+        };
+        self.paths.values_mut().for_each(rebase);
+        rebase(&mut self.base_path);
+    }
+}
+
+/// Changes to a title's user data; unset fields are left as they are.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserDataUpdate {
+    pub watched: Option<bool>,
+    /// Watch date used when marking watched
+    pub watched_at: Option<String>,
+    pub favorite: Option<bool>,
+    pub rating: Option<f32>,
+    pub add_tags: Vec<String>,
+    pub remove_tags: Vec<String>,
+    /// New notes; empty clears them
+    pub notes: Option<String>,
+}
+
+impl UserData {
+    /// Apply a change made by the user.
+    pub fn apply(&mut self, update: &UserDataUpdate) {
+        match update.watched {
+            Some(true) => {
+                self.watched = true;
+                self.play_count = self.play_count.max(1);
+                if update.watched_at.is_some() {
+                    self.watched_at = update.watched_at.clone();
+                }
+            }
+            Some(false) => {
+                self.watched = false;
+                self.play_count = 0;
+                self.watched_at = None;
+            }
+            None => {}
+        }
+        if update.watched.is_some() {
+            self.watched_by_hand = true;
+        }
+        if let Some(favorite) = update.favorite {
+            self.favorite = favorite;
+        }
+        if update.rating.is_some() {
+            self.rating = update.rating;
+        }
+        for tag in &update.add_tags {
+            if !self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                self.tags.push(tag.clone());
+            }
+        }
+        self.tags
+            .retain(|t| !update.remove_tags.iter().any(|r| r.eq_ignore_ascii_case(t)));
+        if let Some(notes) = &update.notes {
+            self.notes = Some(notes.clone()).filter(|n| !n.is_empty());
+        }
+    }
+
+    /// Whether nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Add the watch history a media center wrote to the NFO.
+    ///
+    /// NFO data only adds to what is kept: a title marked watched stays
+    /// watched, and personal fields already set are not overwritten. Watch
+    /// history is ignored once the watched state has been set by hand.
+    pub fn merge_nfo(&mut self, nfo: &UserData) {
+        if !self.watched_by_hand {
+            self.watched |= nfo.watched;
+            self.play_count = self.play_count.max(nfo.play_count);
+            if nfo.watched_at > self.watched_at {
+                self.watched_at = nfo.watched_at.clone();
+            }
+        }
+        self.favorite |= nfo.favorite;
+        self.rating = self.rating.or(nfo.rating);
+    }
+}
+
+impl DiskIndex {
+    /// Keep the user data of the entries this scan replaces.
+    ///
+    /// Scanned entries are matched with the previous ones by ID, then by
+    /// relative path, then by TMDB ID; their NFO data is added on top.
+    pub fn carry_user_data<'a>(
+        &mut self,
+        movies: impl IntoIterator<Item = &'a MovieEntry>,
+        tvshows: impl IntoIterator<Item = &'a TvShowEntry>,
+    ) {
+        let previous: Vec<PreviousEntry> = movies
+            .into_iter()
+            .filter(|m| !m.user_data.is_empty())
+            .map(|m| {
+                (
+                    m.id.as_str(),
+                    m.relative_path.as_str(),
+                    m.tmdb_id,
+                    &m.user_data,
+                )
+            })
+            .collect();
+        for movie in &mut self.movies {
+            carry_user_data(
+                &mut movie.user_data,
+                &previous,
+                (&movie.id, &movie.relative_path, movie.tmdb_id),
+            );
+        }
+
+        let previous: Vec<PreviousEntry> = tvshows
+            .into_iter()
+            .filter(|t| !t.user_data.is_empty())
+            .map(|t| {
+                (
+                    t.id.as_str(),
+                    t.relative_path.as_str(),
+                    t.tmdb_id,
+                    &t.user_data,
+                )
+            })
+            .collect();
+        for tvshow in &mut self.tvshows {
+            carry_user_data(
+                &mut tvshow.user_data,
+                &previous,
+                (&tvshow.id, &tvshow.relative_path, tvshow.tmdb_id),
+            );
+        }
+    }
+}
+
+/// ID, relative path, TMDB ID and user data of a previously indexed entry.
+type PreviousEntry<'a> = (&'a str, &'a str, Option<u64>, &'a UserData);
+
+fn carry_user_data(
+    data: &mut UserData,
+    previous: &[PreviousEntry],
+    (id, relative_path, tmdb_id): (&str, &str, Option<u64>),
+) {
+    let kept = previous
+        .iter()
+        .find(|p| p.0 == id)
+        .or_else(|| previous.iter().find(|p| p.1 == relative_path))
+        .or_else(|| previous.iter().find(|p| p.2.is_some() && p.2 == tmdb_id));
+    if let Some(&(.., kept)) = kept {
+        let mut merged = kept.clone();
+        merged.merge_nfo(data);
+        *data = merged;
+    }
+}
+
+impl CollectionInfo {
+    /// Create an empty collection named after a movie's collection.
+    pub fn for_movie(id: u64, movie: &MovieEntry) -> Self {
+        Self {
+            id,
+            name: movie
+                .collection_name
+                .clone()
+                .unwrap_or_else(|| "Unknown Collection".to_string()),
+            poster_url: None,
+            movies: Vec::new(),
+            total_in_collection: 0, // Will be updated from NFO if available
+            owned_count: 0,
+        }
+    }
+
+    /// Record an owned movie of this collection.
+    pub fn add_owned(&mut self, movie: &MovieEntry) {
+        // Update total_in_collection from NFO data if available and not already set
+        if let Some(total) = movie.collection_total_movies {
+            // Use the maximum value seen (in case different NFOs have different info)
+            if total > self.total_in_collection {
+                self.total_in_collection = total;
+            }
+        }
+
+        // Add movie to collection if not already present
+        let already_in_collection = self
+            .movies
+            .iter()
+            .any(|m| m.tmdb_id == movie.tmdb_id.unwrap_or(0));
+        if !already_in_collection {
+            self.movies.push(CollectionMovie {
+                tmdb_id: movie.tmdb_id.unwrap_or(0),
+                title: movie.title.clone(),
+                year: movie.year,
+                disk: Some(movie.disk.clone()),
+                owned: true,
+            });
+            self.owned_count += 1;
+        }
+    }
+}
+
 impl CentralIndex {
+    /// Recount the entries and size of a disk from its entries.
+    pub fn update_disk_counts(&mut self, label: &str) {
+        let Some(disk) = self.disks.get_mut(label) else {
+            return;
+        };
+        let movies = self.movies.iter().filter(|m| m.disk == label);
+        let tvshows = self.tvshows.iter().filter(|t| t.disk == label);
+        disk.movie_count = movies.clone().count();
+        disk.tvshow_count = tvshows.clone().count();
+        disk.total_size_bytes =
+            movies.map(|m| m.size_bytes).sum::<u64>() + tvshows.map(|t| t.size_bytes).sum::<u64>();
+    }
+
     /// Rebuild search indexes from movie and tvshow entries.
     pub fn rebuild_indexes(&mut self) {
         self.indexes = SearchIndexes::default();
@@ -370,43 +707,10 @@ impl CentralIndex {
                     .push(movie.id.clone());
 
                 // Build collection info
-                let collection = self.collections.entry(collection_id).or_insert_with(|| {
-                    CollectionInfo {
-                        id: collection_id,
-                        name: movie
-                            .collection_name
-                            .clone()
-                            .unwrap_or_else(|| "Unknown Collection".to_string()),
-                        poster_url: None,
-                        movies: Vec::new(),
-                        total_in_collection: 0, // Will be updated from NFO if available
-                        owned_count: 0,
-                    }
-                });
-
-                // Update total_in_collection from NFO data if available and not already set
-                if let Some(total) = movie.collection_total_movies {
-                    // Use the maximum value seen (in case different NFOs have different info)
-                    if total > collection.total_in_collection {
-                        collection.total_in_collection = total;
-                    }
-                }
-
-                // Add movie to collection if not already present
-                let already_in_collection = collection
-                    .movies
-                    .iter()
-                    .any(|m| m.tmdb_id == movie.tmdb_id.unwrap_or(0));
-                if !already_in_collection {
-                    collection.movies.push(CollectionMovie {
-                        tmdb_id: movie.tmdb_id.unwrap_or(0),
-                        title: movie.title.clone(),
-                        year: movie.year,
-                        disk: Some(movie.disk.clone()),
-                        owned: true,
-                    });
-                    collection.owned_count += 1;
-                }
+                self.collections
+                    .entry(collection_id)
+                    .or_insert_with(|| CollectionInfo::for_movie(collection_id, movie))
+                    .add_owned(movie);
             }
         }
 
@@ -1,10 +1,11 @@
 //! Media-related data models.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 /// Media type enum.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum MediaType {
     Movies,
@@ -21,7 +22,7 @@ impl std::fmt::Display for MediaType {
 }
 
 /// Actor with role information.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Actor {
     /// Actor name.
     pub name: String,
@@ -32,7 +33,7 @@ pub struct Actor {
 }
 
 /// Video file information.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct VideoFile {
     /// Full path to the file.
     pub path: PathBuf,
@@ -49,7 +50,7 @@ pub struct VideoFile {
 }
 
 /// Video metadata extracted from ffprobe.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct VideoMetadata {
     /// Video width in pixels.
     pub width: u32,
@@ -70,7 +71,7 @@ pub struct VideoMetadata {
 }
 
 /// TMDB metadata for a movie.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct MovieMetadata {
     /// TMDB ID.
     pub tmdb_id: u64,
@@ -129,7 +130,7 @@ pub struct MovieMetadata {
 }
 
 /// TMDB metadata for a TV show.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct TvShowMetadata {
     /// TMDB ID.
     pub tmdb_id: u64,
@@ -178,7 +179,7 @@ pub struct TvShowMetadata {
 }
 
 /// TMDB metadata for a TV episode.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct EpisodeMetadata {
     /// Season number.
     pub season_number: u16,
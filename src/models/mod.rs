@@ -5,3 +5,4 @@ pub mod index;
 pub mod media;
 pub mod plan;
 pub mod rollback;
+pub mod session;
@@ -3,11 +3,12 @@
 use super::media::{
     EpisodeMetadata, MediaType, MovieMetadata, TvShowMetadata, VideoFile, VideoMetadata,
 };
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 /// Plan file structure.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Plan {
     /// Plan version.
     pub version: String,
@@ -28,7 +29,7 @@ pub struct Plan {
 }
 
 /// A single item in the plan.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PlanItem {
     /// Unique item ID.
     pub id: String,
@@ -53,10 +54,24 @@ pub struct PlanItem {
     pub target: TargetInfo,
     /// Operations to perform.
     pub operations: Vec<Operation>,
+    /// Disk chosen for the item by `--target auto`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placement: Option<Placement>,
+}
+
+/// Where an item was placed and why.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Placement {
+    /// Disk label.
+    pub disk: String,
+    /// Media folder on the disk the item goes under.
+    pub root: PathBuf,
+    /// Why this disk was chosen.
+    pub reason: String,
 }
 
 /// Plan item status.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum PlanItemStatus {
     Pending,
@@ -65,7 +80,7 @@ pub enum PlanItemStatus {
 }
 
 /// AI parsed information.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct ParsedInfo {
     /// Detected title.
     pub title: Option<String>,
@@ -81,7 +96,7 @@ pub struct ParsedInfo {
 }
 
 /// Target path information.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct TargetInfo {
     /// Target folder name.
     pub folder: String,
@@ -97,7 +112,7 @@ pub struct TargetInfo {
 }
 
 /// Operation to perform.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Operation {
     /// Operation type.
     pub op: OperationType,
@@ -115,17 +130,19 @@ pub struct Operation {
 }
 
 /// Operation type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum OperationType {
     Mkdir,
     Move,
     Create,
     Download,
+    /// Remove a directory if it is empty.
+    Rmdir,
 }
 
 /// Sample file item.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SampleItem {
     /// Source path.
     pub source: PathBuf,
@@ -134,7 +151,7 @@ pub struct SampleItem {
 }
 
 /// Unknown/failed file item.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct UnknownItem {
     /// Source file.
     pub source: VideoFile,
@@ -1,10 +1,11 @@
 //! Rollback data model.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 /// Rollback file structure.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Rollback {
     /// Rollback version.
     pub version: String,
@@ -17,7 +18,7 @@ pub struct Rollback {
 }
 
 /// A single rollback operation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RollbackOperation {
     /// Sequence number.
     pub seq: u32,
@@ -37,17 +38,18 @@ pub struct RollbackOperation {
 }
 
 /// Operation type for rollback tracking.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum RollbackOpType {
     Mkdir,
     Move,
     Create,
     Download,
+    Rmdir,
 }
 
 /// Action to undo an operation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RollbackAction {
     /// Rollback operation type.
     pub op: RollbackActionType,
@@ -59,7 +61,7 @@ pub struct RollbackAction {
 }
 
 /// Rollback action type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum RollbackActionType {
     /// Remove a directory.
@@ -68,4 +70,6 @@ pub enum RollbackActionType {
     Move,
     /// Delete a created file.
     Delete,
+    /// Recreate a removed directory.
+    Mkdir,
 }
@@ -0,0 +1,104 @@
+//! Session manifest data model.
+
+use super::plan::OperationType;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Session lifecycle status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum SessionStatus {
+    /// Plan generated, not executed yet.
+    Planned,
+    /// Plan executed without errors.
+    Executed,
+    /// Plan executed, but some operations failed.
+    PartiallyExecuted,
+    /// Execution was rolled back.
+    RolledBack,
+    /// Part of the execution was rolled back; the rest is in a residual
+    /// rollback file.
+    PartiallyRolledBack,
+}
+
+impl std::fmt::Display for SessionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionStatus::Planned => write!(f, "planned"),
+            SessionStatus::Executed => write!(f, "executed"),
+            SessionStatus::PartiallyExecuted => write!(f, "partially-executed"),
+            SessionStatus::RolledBack => write!(f, "rolled-back"),
+            SessionStatus::PartiallyRolledBack => write!(f, "partially-rolled-back"),
+        }
+    }
+}
+
+/// Session manifest (session.json), recording what happened to a plan.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SessionManifest {
+    /// Current status.
+    pub status: SessionStatus,
+    /// Plan creation timestamp.
+    pub created_at: String,
+    /// Execution timestamp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub executed_at: Option<String>,
+    /// User who executed the plan.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub executed_by: Option<String>,
+    /// Rollback timestamp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rolled_back_at: Option<String>,
+    /// Number of successful operations.
+    #[serde(default)]
+    pub success_count: usize,
+    /// Number of failed operations.
+    #[serde(default)]
+    pub error_count: usize,
+    /// Operations that failed during execution.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failed_operations: Vec<FailedOperation>,
+    /// Rollback file written by the execution.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollback_path: Option<PathBuf>,
+    /// Plan ID recorded in the rollback file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollback_plan_id: Option<String>,
+    /// Rollback file for the operations a partial rollback left in place.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub residual_rollback_path: Option<PathBuf>,
+    /// Version of media-organizer that last updated the session.
+    pub tool_version: String,
+}
+
+impl SessionManifest {
+    /// Create a manifest for a newly planned session.
+    pub fn planned(created_at: &str) -> Self {
+        Self {
+            status: SessionStatus::Planned,
+            created_at: created_at.to_string(),
+            executed_at: None,
+            executed_by: None,
+            rolled_back_at: None,
+            success_count: 0,
+            error_count: 0,
+            failed_operations: Vec::new(),
+            rollback_path: None,
+            rollback_plan_id: None,
+            residual_rollback_path: None,
+            tool_version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+/// An operation that failed during execution.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FailedOperation {
+    /// Operation type.
+    pub op: OperationType,
+    /// Target path of the operation.
+    pub path: PathBuf,
+    /// Error message.
+    pub error: String,
+}
@@ -0,0 +1,179 @@
+//! Background plan generation jobs.
+
+use crate::core::hooks::{self, HookSummary};
+use crate::core::planner::{self, Planner};
+use crate::core::sessions;
+use crate::models::media::MediaType;
+use chrono::Utc;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use uuid::Uuid;
+
+/// Plan job status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    /// Waiting to start.
+    Queued,
+    /// Scanning and matching files.
+    Running,
+    /// Plan saved to a session.
+    Completed,
+    /// Plan generation failed.
+    Failed,
+}
+
+/// Request body for starting a plan job.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub struct PlanRequest {
+    /// Source directory to plan.
+    pub source: PathBuf,
+    /// Target directory for organized files.
+    pub target: PathBuf,
+    /// Media type of the source directory.
+    pub media_type: MediaType,
+}
+
+/// A plan generation job and its progress.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PlanJob {
+    /// Job ID.
+    pub id: String,
+    /// Current status.
+    pub status: JobStatus,
+    /// Source directory.
+    pub source: PathBuf,
+    /// Target directory.
+    pub target: PathBuf,
+    /// Media type.
+    pub media_type: MediaType,
+    /// Videos processed so far.
+    pub processed: u64,
+    /// Total videos to process (0 until scanning finishes).
+    pub total: u64,
+    /// Session the plan was saved to, once completed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// Error message, if the job failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Job creation timestamp.
+    pub created_at: String,
+}
+
+/// In-memory registry of plan jobs.
+#[derive(Debug, Default)]
+pub struct JobRegistry {
+    jobs: RwLock<HashMap<String, PlanJob>>,
+}
+
+impl JobRegistry {
+    /// All jobs, newest first.
+    pub fn list(&self) -> Vec<PlanJob> {
+        let mut jobs: Vec<PlanJob> = self.jobs.read().unwrap().values().cloned().collect();
+        jobs.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        jobs
+    }
+
+    /// Look up a job by ID.
+    pub fn get(&self, id: &str) -> Option<PlanJob> {
+        self.jobs.read().unwrap().get(id).cloned()
+    }
+
+    /// Register a new queued job.
+    fn insert(&self, request: &PlanRequest) -> PlanJob {
+        let job = PlanJob {
+            id: Uuid::new_v4().to_string(),
+            status: JobStatus::Queued,
+            source: request.source.clone(),
+            target: request.target.clone(),
+            media_type: request.media_type,
+            processed: 0,
+            total: 0,
+            session_id: None,
+            error: None,
+            created_at: Utc::now().to_rfc3339(),
+        };
+        self.jobs
+            .write()
+            .unwrap()
+            .insert(job.id.clone(), job.clone());
+        job
+    }
+
+    /// Apply an update to a job.
+    fn update<F: FnOnce(&mut PlanJob)>(&self, id: &str, f: F) {
+        if let Some(job) = self.jobs.write().unwrap().get_mut(id) {
+            f(job);
+        }
+    }
+}
+
+/// Start generating a plan in the background and return the queued job.
+pub fn spawn_plan_job(state: Arc<super::AppState>, request: PlanRequest) -> crate::Result<PlanJob> {
+    if !request.source.is_dir() {
+        return Err(crate::Error::NotADirectory(
+            request.source.display().to_string(),
+        ));
+    }
+
+    let job = state.jobs.insert(&request);
+    let job_id = job.id.clone();
+
+    tokio::spawn(async move {
+        state
+            .jobs
+            .update(&job_id, |j| j.status = JobStatus::Running);
+        let result = run_plan_job(&state, &job_id, &request).await;
+        state.jobs.update(&job_id, |j| match result {
+            Ok(session_id) => {
+                j.status = JobStatus::Completed;
+                j.session_id = Some(session_id);
+            }
+            Err(e) => {
+                j.status = JobStatus::Failed;
+                j.error = Some(e.to_string());
+            }
+        });
+    });
+
+    Ok(job)
+}
+
+/// Generate the plan and save it to a new session, returning the session ID.
+async fn run_plan_job(
+    state: &Arc<super::AppState>,
+    job_id: &str,
+    request: &PlanRequest,
+) -> crate::Result<String> {
+    let progress_state = Arc::clone(state);
+    let progress_id = job_id.to_string();
+    let planner = Planner::new()?.with_progress(Arc::new(move |processed, total| {
+        progress_state.jobs.update(&progress_id, |j| {
+            j.processed = processed;
+            j.total = total;
+        });
+    }));
+
+    let plan = planner
+        .generate(&request.source, &request.target, request.media_type)
+        .await?;
+    let session_dir = planner::save_to_sessions_in(&state.sessions_dir, &plan)?;
+    hooks::fire(&HookSummary::for_plan(
+        &plan,
+        Some(&session_dir.join(sessions::PLAN_FILE)),
+    ))
+    .await;
+    Ok(session_id_of(&session_dir))
+}
+
+/// Session ID (directory name) of a session directory.
+fn session_id_of(session_dir: &Path) -> String {
+    session_dir
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
@@ -0,0 +1,125 @@
+//! Local HTTP/JSON API server.
+//!
+//! Exposes search, index statistics, sessions, background plan generation,
+//! plan item edits, execution and rollback as REST endpoints. Request and
+//! response bodies are the existing serde models; their JSON schemas are
+//! served under `/api/schemas`.
+
+pub mod jobs;
+pub mod routes;
+
+use crate::Result;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use jobs::JobRegistry;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::Mutex;
+
+/// Shared server state.
+#[derive(Debug)]
+pub struct AppState {
+    /// Sessions directory plans are saved to and loaded from.
+    pub sessions_dir: PathBuf,
+    /// Background plan generation jobs.
+    pub jobs: JobRegistry,
+    /// Serializes execution, rollback and plan edits so they never touch
+    /// session files concurrently.
+    pub fs_lock: Mutex<()>,
+}
+
+impl AppState {
+    /// Create server state for a sessions directory.
+    pub fn new(sessions_dir: PathBuf) -> Arc<Self> {
+        Arc::new(Self {
+            sessions_dir,
+            jobs: JobRegistry::default(),
+            fs_lock: Mutex::new(()),
+        })
+    }
+}
+
+/// Error returned by API handlers, rendered as `{"error": "..."}`.
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub message: String,
+}
+
+impl ApiError {
+    /// 400 Bad Request.
+    pub fn bad_request<S: Into<String>>(message: S) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            message: message.into(),
+        }
+    }
+
+    /// 404 Not Found.
+    pub fn not_found<S: Into<String>>(message: S) -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            message: message.into(),
+        }
+    }
+
+    /// 409 Conflict.
+    pub fn conflict<S: Into<String>>(message: S) -> Self {
+        Self {
+            status: StatusCode::CONFLICT,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<crate::Error> for ApiError {
+    fn from(e: crate::Error) -> Self {
+        use crate::Error;
+        let status = match e {
+            Error::PathNotFound(_) => StatusCode::NOT_FOUND,
+            Error::NotADirectory(_)
+            | Error::InvalidPlanFile(_)
+            | Error::InvalidRollbackFile(_)
+            | Error::PlanValidationError(_)
+            | Error::ParseError(_)
+            | Error::Other(_) => StatusCode::BAD_REQUEST,
+            Error::FileAlreadyExists(_) | Error::RollbackConflict(_) => StatusCode::CONFLICT,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        Self {
+            status,
+            message: e.to_string(),
+        }
+    }
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(e: anyhow::Error) -> Self {
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: format!("{:#}", e),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = serde_json::json!({ "error": self.message });
+        (self.status, Json(body)).into_response()
+    }
+}
+
+/// Serve the API on an already bound listener until the task is dropped.
+pub async fn serve_on(listener: TcpListener, state: Arc<AppState>) -> Result<()> {
+    axum::serve(listener, routes::router(state)).await?;
+    Ok(())
+}
+
+/// Bind an address and serve the API.
+pub async fn serve(addr: SocketAddr, state: Arc<AppState>) -> Result<()> {
+    let listener = TcpListener::bind(addr).await?;
+    serve_on(listener, state).await
+}
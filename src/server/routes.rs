@@ -0,0 +1,384 @@
+//! API routes and handlers.
+
+use super::jobs::{self, PlanJob, PlanRequest};
+use super::{ApiError, AppState};
+use crate::core::executor::{self, Executor, ExecutorConfig};
+use crate::core::hooks::{self, HookSummary};
+use crate::core::indexer::{self, SearchResults};
+use crate::core::planner::{self, Planner};
+use crate::core::rollback::{self, RollbackExecutor, RollbackResult};
+use crate::core::sessions;
+use crate::models::index::IndexStatistics;
+use crate::models::plan::{Plan, PlanItem, PlanItemStatus};
+use crate::models::rollback::Rollback;
+use crate::models::session::SessionManifest;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::routing::{get, patch, post};
+use axum::{Json, Router};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+type ApiResult<T> = std::result::Result<Json<T>, ApiError>;
+
+/// Build the API router.
+pub fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/api/health", get(health))
+        .route("/api/schemas", get(list_schemas))
+        .route("/api/schemas/:name", get(get_schema))
+        .route("/api/search", get(search))
+        .route("/api/stats", get(stats))
+        .route("/api/sessions", get(list_sessions))
+        .route("/api/sessions/:id", get(get_session))
+        .route("/api/sessions/:id/items/:item_id", patch(edit_item))
+        .route("/api/sessions/:id/execute", post(execute_session))
+        .route("/api/sessions/:id/rollback", post(rollback_session))
+        .route("/api/plans", post(start_plan))
+        .route("/api/jobs", get(list_jobs))
+        .route("/api/jobs/:id", get(get_job))
+        .with_state(state)
+}
+
+/// Session as listed by `GET /api/sessions`.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SessionSummary {
+    /// Session ID.
+    pub id: String,
+    /// Plan creation timestamp.
+    pub created_at: String,
+    /// Session manifest.
+    pub manifest: SessionManifest,
+}
+
+/// Session with its plan, as returned by `GET /api/sessions/{id}`.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SessionDetail {
+    /// Session ID.
+    pub id: String,
+    /// Plan creation timestamp.
+    pub created_at: String,
+    /// Session manifest.
+    pub manifest: SessionManifest,
+    /// The session's plan.
+    pub plan: Plan,
+}
+
+/// Request body for `PATCH /api/sessions/{id}/items/{item_id}`.
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ItemEdit {
+    /// New item status.
+    #[serde(default)]
+    pub status: Option<PlanItemStatus>,
+    /// Re-match the item against this TMDB ID.
+    #[serde(default)]
+    pub tmdb_id: Option<u64>,
+}
+
+/// Request body for `POST /api/sessions/{id}/execute`.
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ExecuteRequest {
+    /// Record checksums for every move.
+    #[serde(default)]
+    pub checksum: bool,
+}
+
+/// Request body for `POST /api/sessions/{id}/rollback`.
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct RollbackRequest {
+    /// Only report what would be done.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Refuse to move files whose checksum changed since execution.
+    #[serde(default)]
+    pub verify_checksum: bool,
+}
+
+/// Query parameters for `GET /api/search`, mirroring the search command.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct SearchQuery {
+    pub title: Option<String>,
+    pub actor: Option<String>,
+    pub director: Option<String>,
+    pub collection: Option<String>,
+    /// Year ("2010") or range ("2000-2010").
+    pub year: Option<String>,
+    pub genre: Option<String>,
+    pub country: Option<String>,
+    /// Query expression, as accepted by `search --query`.
+    pub q: Option<String>,
+}
+
+async fn health() -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "status": "ok",
+        "version": env!("CARGO_PKG_VERSION"),
+    }))
+}
+
+/// Names of the schemas served under `/api/schemas/{name}`.
+const SCHEMA_NAMES: &[&str] = &[
+    "plan",
+    "plan-item",
+    "rollback",
+    "rollback-result",
+    "session-manifest",
+    "session-summary",
+    "session-detail",
+    "search-results",
+    "index-statistics",
+    "plan-request",
+    "plan-job",
+    "item-edit",
+    "execute-request",
+    "rollback-request",
+];
+
+async fn list_schemas() -> Json<Vec<&'static str>> {
+    Json(SCHEMA_NAMES.to_vec())
+}
+
+async fn get_schema(Path(name): Path<String>) -> ApiResult<schemars::schema::RootSchema> {
+    let schema = match name.as_str() {
+        "plan" => schemars::schema_for!(Plan),
+        "plan-item" => schemars::schema_for!(PlanItem),
+        "rollback" => schemars::schema_for!(Rollback),
+        "rollback-result" => schemars::schema_for!(RollbackResult),
+        "session-manifest" => schemars::schema_for!(SessionManifest),
+        "session-summary" => schemars::schema_for!(SessionSummary),
+        "session-detail" => schemars::schema_for!(SessionDetail),
+        "search-results" => schemars::schema_for!(SearchResults),
+        "index-statistics" => schemars::schema_for!(IndexStatistics),
+        "plan-request" => schemars::schema_for!(PlanRequest),
+        "plan-job" => schemars::schema_for!(PlanJob),
+        "item-edit" => schemars::schema_for!(ItemEdit),
+        "execute-request" => schemars::schema_for!(ExecuteRequest),
+        "rollback-request" => schemars::schema_for!(RollbackRequest),
+        _ => return Err(ApiError::not_found(format!("Unknown schema: {}", name))),
+    };
+    Ok(Json(schema))
+}
+
+async fn search(Query(query): Query<SearchQuery>) -> ApiResult<SearchResults> {
+    let (year, year_range) = match query.year.as_deref() {
+        Some(y) if y.contains('-') => {
+            let (start, end) = y.split_once('-').unwrap_or_default();
+            let start = start
+                .parse()
+                .map_err(|_| ApiError::bad_request(format!("Invalid year range: {}", y)))?;
+            let end = end
+                .parse()
+                .map_err(|_| ApiError::bad_request(format!("Invalid year range: {}", y)))?;
+            (None, Some((start, end)))
+        }
+        Some(y) => (
+            Some(
+                y.parse()
+                    .map_err(|_| ApiError::bad_request(format!("Invalid year: {}", y)))?,
+            ),
+            None,
+        ),
+        None => (None, None),
+    };
+    let expr = query
+        .q
+        .as_deref()
+        .map(crate::core::query::Query::parse)
+        .transpose()
+        .map_err(|e| ApiError::bad_request(e.to_string()))?;
+
+    let results = indexer::search_central(
+        query.title.as_deref(),
+        query.actor.as_deref(),
+        query.director.as_deref(),
+        query.collection.as_deref(),
+        year,
+        year_range,
+        query.genre.as_deref(),
+        query.country.as_deref(),
+    )?;
+    Ok(Json(match expr {
+        Some(expr) => expr.apply(results),
+        None => results,
+    }))
+}
+
+async fn stats() -> ApiResult<IndexStatistics> {
+    let index = indexer::load_central_index()?;
+    Ok(Json(index.statistics))
+}
+
+async fn list_sessions(State(state): State<Arc<AppState>>) -> ApiResult<Vec<SessionSummary>> {
+    let summaries = sessions::list(&state.sessions_dir)?
+        .into_iter()
+        .map(|s| SessionSummary {
+            manifest: s.manifest(),
+            created_at: s.created_at.to_rfc3339(),
+            id: s.id,
+        })
+        .collect();
+    Ok(Json(summaries))
+}
+
+async fn get_session(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> ApiResult<SessionDetail> {
+    let session = sessions::get(&state.sessions_dir, &id)?;
+    let plan = planner::load_plan(&session.plan_path())?;
+    Ok(Json(SessionDetail {
+        manifest: session.manifest(),
+        created_at: session.created_at.to_rfc3339(),
+        id: session.id,
+        plan,
+    }))
+}
+
+async fn edit_item(
+    State(state): State<Arc<AppState>>,
+    Path((id, item_id)): Path<(String, String)>,
+    Json(edit): Json<ItemEdit>,
+) -> ApiResult<PlanItem> {
+    // Hold the lock so the plan cannot be executed while it is being edited
+    let _guard = state.fs_lock.lock().await;
+
+    let session = sessions::get(&state.sessions_dir, &id)?;
+    if session.is_executed() {
+        return Err(ApiError::conflict(format!(
+            "Session {} has already been executed",
+            id
+        )));
+    }
+
+    let mut plan = planner::load_plan(&session.plan_path())?;
+    let idx = plan
+        .items
+        .iter()
+        .position(|i| i.id == item_id)
+        .ok_or_else(|| ApiError::not_found(format!("Item not found: {}", item_id)))?;
+
+    if let Some(tmdb_id) = edit.tmdb_id {
+        let media_type = plan
+            .media_type
+            .ok_or_else(|| ApiError::bad_request("Plan has no media type"))?;
+        let planner = Planner::new()?;
+        let item = planner
+            .rematch_item(
+                &plan.items[idx].source,
+                tmdb_id,
+                &plan.target_path,
+                media_type,
+            )
+            .await?;
+        plan.items[idx] = item;
+        planner.validate_no_duplicate_targets(&plan.items)?;
+    }
+    if let Some(status) = edit.status {
+        plan.items[idx].status = status;
+    }
+
+    planner::save_plan(&plan, &session.plan_path())?;
+    Ok(Json(plan.items.swap_remove(idx)))
+}
+
+async fn execute_session(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    body: Option<Json<ExecuteRequest>>,
+) -> ApiResult<SessionManifest> {
+    let request = body.map(|Json(b)| b).unwrap_or_default();
+    let _guard = state.fs_lock.lock().await;
+
+    let session = sessions::get(&state.sessions_dir, &id)?;
+    if session.is_executed() {
+        return Err(ApiError::conflict(format!(
+            "Session {} has already been executed",
+            id
+        )));
+    }
+
+    let plan = planner::load_plan(&session.plan_path())?;
+    let executor = Executor::with_config(ExecutorConfig {
+        checksum_renames: request.checksum,
+        ..Default::default()
+    });
+    let report = executor.execute_with_report(&plan).await?;
+    executor::save_rollback(&report.rollback, &session.rollback_path())?;
+    spawn_hooks(HookSummary::for_execution(
+        &plan,
+        &report.rollback,
+        Some(&session.plan_path()),
+    ));
+    let manifest = sessions::record_execution(
+        &session,
+        &report.rollback,
+        &session.rollback_path(),
+        report.success_count,
+        report.failed_operations,
+    )?;
+    Ok(Json(manifest))
+}
+
+async fn rollback_session(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    body: Option<Json<RollbackRequest>>,
+) -> ApiResult<RollbackResult> {
+    let request = body.map(|Json(b)| b).unwrap_or_default();
+    let _guard = state.fs_lock.lock().await;
+
+    let session = sessions::get(&state.sessions_dir, &id)?;
+    if !session.is_executed() {
+        return Err(ApiError::conflict(format!(
+            "Session {} has not been executed",
+            id
+        )));
+    }
+
+    let rollback_path = session.pending_rollback_path();
+    let rb = rollback::load_rollback(&rollback_path)?;
+    let result = RollbackExecutor::with_strict_checksum(request.verify_checksum)
+        .execute(&rb, request.dry_run)
+        .await?;
+
+    if !request.dry_run {
+        let plan = planner::load_plan(&session.plan_path()).ok();
+        spawn_hooks(HookSummary::for_rollback(
+            &rb,
+            plan.as_ref(),
+            result.error_count,
+            Some(&rollback_path),
+        ));
+        if result.is_success() {
+            sessions::mark_undone(&session)?;
+            sessions::record_rollback(&session)?;
+        }
+    }
+    Ok(Json(result))
+}
+
+/// Run configured hooks without delaying the response.
+fn spawn_hooks(summary: HookSummary) {
+    tokio::spawn(async move { hooks::fire(&summary).await });
+}
+
+async fn start_plan(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<PlanRequest>,
+) -> std::result::Result<(StatusCode, Json<PlanJob>), ApiError> {
+    let job = jobs::spawn_plan_job(state, request)?;
+    Ok((StatusCode::ACCEPTED, Json(job)))
+}
+
+async fn list_jobs(State(state): State<Arc<AppState>>) -> Json<Vec<PlanJob>> {
+    Json(state.jobs.list())
+}
+
+async fn get_job(State(state): State<Arc<AppState>>, Path(id): Path<String>) -> ApiResult<PlanJob> {
+    state
+        .jobs
+        .get(&id)
+        .map(Json)
+        .ok_or_else(|| ApiError::not_found(format!("Job not found: {}", id)))
+}
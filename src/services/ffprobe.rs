@@ -136,7 +136,7 @@ pub fn extract_metadata(path: &Path) -> Result<VideoMetadata> {
 }
 
 /// Convert resolution to standard string (e.g., "2160p", "1080p").
-fn resolution_to_string(width: u32, height: u32) -> String {
+pub fn resolution_to_string(width: u32, height: u32) -> String {
     if height >= 2160 || width >= 3840 {
         "2160p".to_string()
     } else if height >= 1080 || width >= 1920 {
@@ -371,6 +371,30 @@ fn parse_audio_channels_from_filename(filename: &str) -> String {
     "unknown".to_string()
 }
 
+/// Parse the HDR format from a filename ("Dolby Vision", "HDR10+", "HDR10", "HDR", "HLG").
+pub fn parse_hdr_from_filename(filename: &str) -> Option<String> {
+    let lower = filename.to_lowercase();
+    let tokens: Vec<&str> = lower
+        .split(|c: char| !(c.is_alphanumeric() || c == '+'))
+        .collect();
+    let has = |token: &str| tokens.contains(&token);
+
+    let hdr = if lower.contains("dolby vision") || has("dolbyvision") || has("dv") || has("dovi") {
+        "Dolby Vision"
+    } else if has("hdr10+") || has("hdr10plus") {
+        "HDR10+"
+    } else if has("hdr10") {
+        "HDR10"
+    } else if has("hdr") {
+        "HDR"
+    } else if has("hlg") {
+        "HLG"
+    } else {
+        return None;
+    };
+    Some(hdr.to_string())
+}
+
 /// Merge two VideoMetadata, preferring values from primary, falling back to secondary.
 pub fn merge_metadata(primary: VideoMetadata, secondary: VideoMetadata) -> VideoMetadata {
     VideoMetadata {
@@ -443,6 +467,24 @@ mod filename_parser_tests {
         assert_eq!(parse_resolution_from_filename("电影.4k.mp4"), "2160p");
     }
 
+    #[test]
+    fn test_parse_hdr() {
+        assert_eq!(
+            parse_hdr_from_filename("Movie.2019.2160p.DV.HEVC.mkv").as_deref(),
+            Some("Dolby Vision")
+        );
+        assert_eq!(
+            parse_hdr_from_filename("Movie.2019.2160p.HDR10+.mkv").as_deref(),
+            Some("HDR10+")
+        );
+        assert_eq!(
+            parse_hdr_from_filename("movie-2160p-hdr-x265.mkv").as_deref(),
+            Some("HDR")
+        );
+        // Words containing "hdr" or "dv" are not HDR markers
+        assert_eq!(parse_hdr_from_filename("movie.720p.HDRip.DVD.mkv"), None);
+    }
+
     #[test]
     fn test_parse_format() {
         // Note: These functions expect lowercase input
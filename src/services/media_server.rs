@@ -0,0 +1,123 @@
+//! Media server library refresh client.
+//!
+//! Triggers targeted library scans after files were moved:
+//! - Jellyfin/Emby: `POST /Library/Media/Updated` with the changed folders
+//! - Plex: `GET /library/sections/{id}/refresh?path=...` per folder
+
+use crate::models::config::{MediaServerConfig, MediaServerKind};
+use crate::models::rollback::{Rollback, RollbackOpType};
+use crate::Result;
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// A refresh request, built before sending so it can be shown in dry runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefreshRequest {
+    /// HTTP method.
+    pub method: reqwest::Method,
+    /// Full URL, without credentials.
+    pub url: String,
+    /// JSON body, if any.
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct MediaUpdate {
+    path: String,
+    update_type: &'static str,
+}
+
+/// Folders that received files during an execution, deduplicated and sorted.
+pub fn affected_folders(rollback: &Rollback) -> Vec<PathBuf> {
+    let folders: BTreeSet<PathBuf> = rollback
+        .operations
+        .iter()
+        .filter(|op| op.executed && op.op_type == RollbackOpType::Move)
+        .filter_map(|op| op.to.parent().map(|p| p.to_path_buf()))
+        .collect();
+    folders.into_iter().collect()
+}
+
+/// Translate a local path to the path seen by the media server.
+pub fn server_path(server: &MediaServerConfig, path: &Path) -> PathBuf {
+    match (&server.local_root, &server.server_root) {
+        (Some(local), Some(remote)) => match path.strip_prefix(local) {
+            Ok(rest) => remote.join(rest),
+            Err(_) => path.to_path_buf(),
+        },
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Build the refresh requests for a set of folders.
+pub fn build_requests(
+    server: &MediaServerConfig,
+    folders: &[PathBuf],
+) -> Result<Vec<RefreshRequest>> {
+    if folders.is_empty() {
+        return Ok(Vec::new());
+    }
+    let base = server.url.trim_end_matches('/');
+
+    match server.kind {
+        MediaServerKind::Jellyfin | MediaServerKind::Emby => {
+            let updates: Vec<MediaUpdate> = folders
+                .iter()
+                .map(|f| MediaUpdate {
+                    path: server_path(server, f).display().to_string(),
+                    update_type: "Created",
+                })
+                .collect();
+            Ok(vec![RefreshRequest {
+                method: reqwest::Method::POST,
+                url: format!("{}/Library/Media/Updated", base),
+                body: Some(serde_json::json!({ "Updates": updates })),
+            }])
+        }
+        MediaServerKind::Plex => {
+            let section = server.section_id.ok_or_else(|| {
+                crate::Error::other(format!("Plex server {} has no section_id configured", base))
+            })?;
+            Ok(folders
+                .iter()
+                .map(|f| RefreshRequest {
+                    method: reqwest::Method::GET,
+                    url: format!(
+                        "{}/library/sections/{}/refresh?path={}",
+                        base,
+                        section,
+                        urlencoding::encode(&server_path(server, f).display().to_string())
+                    ),
+                    body: None,
+                })
+                .collect())
+        }
+    }
+}
+
+/// Send refresh requests to a media server.
+pub async fn send_requests(server: &MediaServerConfig, requests: &[RefreshRequest]) -> Result<()> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
+        .build()?;
+
+    for request in requests {
+        let mut builder = client.request(request.method.clone(), &request.url);
+        builder = match server.kind {
+            MediaServerKind::Jellyfin | MediaServerKind::Emby => {
+                builder.header("X-Emby-Token", &server.token)
+            }
+            MediaServerKind::Plex => builder.header("X-Plex-Token", &server.token),
+        };
+        if let Some(ref body) = request.body {
+            builder = builder.json(body);
+        }
+        builder.send().await?.error_for_status()?;
+    }
+    Ok(())
+}
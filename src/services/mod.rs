@@ -1,5 +1,6 @@
 //! External service clients.
 
 pub mod ffprobe;
+pub mod media_server;
 pub mod ollama;
 pub mod tmdb;
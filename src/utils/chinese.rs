@@ -1,10 +1,43 @@
 //! Chinese text utilities.
 
+use super::chinese_data::{EXTRA_INITIALS, PINYIN_INITIALS, SIMPLIFIED, TRADITIONAL};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
 /// Check if two strings are the same when normalized (handles Traditional/Simplified).
 pub fn titles_equivalent(a: &str, b: &str) -> bool {
-    // Basic normalization for now
-    // TODO: Implement proper Traditional/Simplified Chinese conversion
-    normalize(a) == normalize(b)
+    normalize(&to_simplified(a)) == normalize(&to_simplified(b))
+}
+
+/// Convert Traditional Chinese characters to Simplified.
+///
+/// Works character by character; other characters are kept as is.
+pub fn to_simplified(s: &str) -> String {
+    static TABLE: OnceLock<HashMap<char, char>> = OnceLock::new();
+    let table = TABLE.get_or_init(|| TRADITIONAL.chars().zip(SIMPLIFIED.chars()).collect());
+    s.chars().map(|c| *table.get(&c).unwrap_or(&c)).collect()
+}
+
+/// Pinyin initials of a Chinese character as a bit set (bit 0 = 'a').
+///
+/// Common polyphonic characters have several bits set. Returns 0 for
+/// characters without pinyin data.
+pub fn pinyin_initials(c: char) -> u32 {
+    static TABLE: OnceLock<HashMap<char, u32>> = OnceLock::new();
+    let table = TABLE.get_or_init(|| {
+        let mut table = HashMap::new();
+        for (initial, chars) in PINYIN_INITIALS {
+            let bit = 1 << (*initial as u32 - 'a' as u32);
+            for c in chars.chars() {
+                *table.entry(c).or_insert(0) |= bit;
+            }
+        }
+        for (c, initial) in EXTRA_INITIALS {
+            *table.entry(*c).or_insert(0) |= 1 << (*initial as u32 - 'a' as u32);
+        }
+        table
+    });
+    table.get(&c).copied().unwrap_or(0)
 }
 
 /// Normalize a string for comparison.
@@ -48,5 +81,25 @@ mod tests {
         assert!(titles_equivalent("Avatar", "avatar"));
         assert!(titles_equivalent("The Matrix", "the matrix"));
         assert!(!titles_equivalent("Avatar", "Titanic"));
+        assert!(titles_equivalent("復仇者聯盟", "复仇者联盟"));
+    }
+
+    #[test]
+    fn test_to_simplified() {
+        assert_eq!(to_simplified("臥虎藏龍"), "卧虎藏龙");
+        assert_eq!(
+            to_simplified("無間道 Infernal Affairs"),
+            "无间道 Infernal Affairs"
+        );
+        assert_eq!(TRADITIONAL.chars().count(), SIMPLIFIED.chars().count());
+    }
+
+    #[test]
+    fn test_pinyin_initials() {
+        let bit = |c: char| 1 << (c as u32 - 'a' as u32);
+        assert_eq!(pinyin_initials('复'), bit('f'));
+        // 长: chang / zhang
+        assert_eq!(pinyin_initials('长'), bit('c') | bit('z'));
+        assert_eq!(pinyin_initials('A'), 0);
     }
 }
@@ -0,0 +1,609 @@
+//! Character tables for Chinese title matching.
+//!
+//! - `TRADITIONAL`/`SIMPLIFIED`: character pairs that differ between the two
+//!   scripts, covering the characters common in film and TV titles. This is a
+//!   character-level table, not a full phrase dictionary such as OpenCC.
+//! - `PINYIN_INITIALS`: characters of GB2312 and Big5 grouped by the initial
+//!   letter of their pinyin readings, derived from the pinyin collation data of
+//!   Perl's Unicode::Collate 1.31, which lists one reading per character.
+//! - `EXTRA_INITIALS`: further readings of common polyphonic characters.
+
+/// Traditional characters, paired by position with `SIMPLIFIED`.
+pub(crate) const TRADITIONAL: &str =
+    "說話語讀誰請認識記計訂討訓設許論證評詞試詩該詳誠誤調談諾謀講謝謎謊議護讓變讚譯課\
+    誌諜謠訊託訪諸諒誼諧謹譜訴詐診詠誕諮謙譴讒訣詭誇諦謁訝詢誘諷譏訃詛謗讖誅鐵銀錢鋼\
+    錯鐘鍾鏡銅鋒鎖鑰鋪錦鑽針釘鈴鉛鍋錄鏈銷鎮鏢鐳錘鍊鑑鑒鋤銳鈞鈔釣鉤鐮鑄鐺鏽錫鈕銘鑼\
+    鎧鋸鏗鐸鍵鈣鋁鉀鉑銬鋅錨鎂鎢鐲鈍鉅銜錶鍛鎊鏟鐫鑲門問間開關閉閃閱闊闖闆閣閒閑聞悶\
+    闡闕閻闔闈閘鬧闌闢閨閥閏閩紅綠紙線結給經級約紀純納細終組絕統絲網緊練總繩織繼續纏\
+    維綜編緣縱縮績縫紛紋紳絡綁綱緒緩緝縣繞繪纖纜紡紹絨綿緯縛繳綺緋繡紗紮糾繫紂紐絞綢\
+    緞緻縈繽絢綻緬繃飯飲餓館餅飽飼餘饑飢饒餵饞饅餃飾餌饋餞餉饃馬騎駕驚驗騙驅駐驛騰驕\
+    驢駛騷驟罵媽嗎碼瑪螞駿驃驍馳駭驥騁驪駁駝騾魚鮮鯨鯊鯉鰻鱷鱗漁蘇鮑鱔鱈鮭鯽魷鮪鯛鳥\
+    鴨鵝鷹鳳鳴雞鴿鶴鵬鷗鷺鸚鵡鴉鷲島鴻鶯鸞鴕鵑鶇鷓貝財貨貧貴買賣費資賊賞賽贏賺購贈贊\
+    賭負責貢販貪貫賀貸貿賠賴賤賦質賬贖贓貞則側測廁賓賢貳貶貽賑賒贍賄賂贛頁頂項順須預\
+    領頭題顏願類顧額顯頻頸顛風顫頓頌頑頒頹顆顱頃頰顎顳颯颳颶颼飄飆車軍軌輪軟轉輕載較\
+    輸輩輛轎轟連運陣庫褲揮輝渾暈軒輯轄輔輿轍斬漸暫慚軋輓轅軸見視覺觀親規覽現覓覬國們\
+    來這個時會學對發後長東無為與過還進動實當點從樣兩裡裏體萬電愛戰聯復複讎聖龍華漢傳\
+    俠劍夢畫書寶歸亂亞惡黨蘭蘋葉藥藝節範築簡籃權歡樂機極樓槍檢標條構樹橋殺氣滅滿濃濕\
+    災燈營爐爺牆獨獵獎獸環產畢異療盡監盤眾礎確禮禍種稱穩窮競筆糧罰義習聲聽腦腳臉興舉\
+    舊莊蓋蒼蟲蠻術衛裝襲觸豐豬貓趕趨跡踐蹤躍軀遊達遠遲邊遙適選遺鄉鄰醫醜釋隊陽陰際隨\
+    險隱雙雜離難雲霧靈靜韓響飛髮鬥齊齒龜麗麥黃團圍圓圖場塊壓壞壯處備夠奪奮婦嬰孫寧審\
+    寫專將尋導屆屬歲嶺幣幫幹廣廳張強彈徑徵徹憶應懷懸戀戲擊據擁擇擔擴攝敗敵數斷於晝暢\
+    曆歷曉殘殼毀決沒況淚淺溝準滯漲潔潛澤濟濤灣灑煙熱爭狀狹猶獅瑣甦盜碩祕禦稅積穀籠罷\
+    羅職膽膠艦艱莖蕭薦藍號蟬蠟補襯趙農邏郵鄭醬釀陳陸隻雖靂颱臺檯髒鬆魯鹽麵黴齡龐劉勞\
+    勢勝區協單厲參嚴喬嘆歎噴嚇囑夥夾奧姦婁嬌孃寵寬尷屍層岡峽崗巖帥師帳帶幾廢廟廠彎彙\
+    匯彿憂惱態慘慣慶慮憐憑懶懼戶撥撲擋擠擬擺攔攜敘斂斃暉曠朧桿棄棟槓樁樺橫檔櫃欄歐殲\
+    毆氈氫沖衝涼淨淪渦湯溫滄滾漿潑澀濁濱瀏瀟灘烏煉煩爛牽犧獄瑩璽瓊甕畝疊瘋癡皚盞睜瞞\
+    矯礦禪窩竊筍箏簽籤粵係罈壇翹聰肅脅脈腎膚艙艷豔蓮蔣蕩蘆虛蝦蠶裊襪豎豈辭辦週遞遜鄧\
+    醞釐陝隸雛靚韻髏鬍鬱麼齣剛劃劇劑勁勵卻厭叢吳員啟喪嗚堅墮墳壺壽妝娛孿寢屜嶼巒幀廂\
+    彌恆愜慾懲擄擾攤暱梟棧榮槳樞橢檳櫻殤淵湧滲滸漣潰澗澆濺瀉熾燒燦燭爍犢狽猙獰獲穫瓏\
+    瘡癢皺盧矚硯礫祿禎稟穢窯竄箋篩簫籌羨聳脣脫腫膩臘臨臥萊蔔蕪薑薩蘊蘿虜虧螢蠅蠍衊褸\
+    蹌辮迴遷鄒釁隕霽靄韋韌鬢魘麩齋龕兒報偵爾倫傷別園價冊偽兇僅儀億優償儲傑傘傾僑僕僱\
+    儂儘兌內凱凍凜劊勸勻匱嗆嘍噸嚨囂囉圇執堯塗塵墊墜墻壘壩奐姍嬤嬪宮屢崢嶄巔幟廈廬弔\
+    彆恥悅愴憤懇拋捨捲掃揀損搖搶摯撐撓撫擰擲攏攪晉曬樸檻檸櫥洶淒減渙滬滷漬潤澱濫瀾燉\
+    燙獻瑤疇癒癮盃矇磚禱窪竅簾籬聶臍荊葦藹蛻蝸蠔踴蹺辯邁遼醃錐隴鞏韁鬨鹹鼴蟻殭";
+
+/// Simplified forms of `TRADITIONAL`.
+pub(crate) const SIMPLIFIED: &str =
+    "说话语读谁请认识记计订讨训设许论证评词试诗该详诚误调谈诺谋讲谢谜谎议护让变赞译课\
+    志谍谣讯托访诸谅谊谐谨谱诉诈诊咏诞谘谦谴谗诀诡夸谛谒讶询诱讽讥讣诅谤谶诛铁银钱钢\
+    错钟钟镜铜锋锁钥铺锦钻针钉铃铅锅录链销镇镖镭锤炼鉴鉴锄锐钧钞钓钩镰铸铛锈锡钮铭锣\
+    铠锯铿铎键钙铝钾铂铐锌锚镁钨镯钝巨衔表锻镑铲镌镶门问间开关闭闪阅阔闯板阁闲闲闻闷\
+    阐阙阎阖闱闸闹阑辟闺阀闰闽红绿纸线结给经级约纪纯纳细终组绝统丝网紧练总绳织继续缠\
+    维综编缘纵缩绩缝纷纹绅络绑纲绪缓缉县绕绘纤缆纺绍绒绵纬缚缴绮绯绣纱扎纠系纣纽绞绸\
+    缎致萦缤绚绽缅绷饭饮饿馆饼饱饲余饥饥饶喂馋馒饺饰饵馈饯饷馍马骑驾惊验骗驱驻驿腾骄\
+    驴驶骚骤骂妈吗码玛蚂骏骠骁驰骇骥骋骊驳驼骡鱼鲜鲸鲨鲤鳗鳄鳞渔苏鲍鳝鳕鲑鲫鱿鲔鲷鸟\
+    鸭鹅鹰凤鸣鸡鸽鹤鹏鸥鹭鹦鹉鸦鹫岛鸿莺鸾鸵鹃鸫鹧贝财货贫贵买卖费资贼赏赛赢赚购赠赞\
+    赌负责贡贩贪贯贺贷贸赔赖贱赋质账赎赃贞则侧测厕宾贤贰贬贻赈赊赡贿赂赣页顶项顺须预\
+    领头题颜愿类顾额显频颈颠风颤顿颂顽颁颓颗颅顷颊颚颞飒刮飓飕飘飙车军轨轮软转轻载较\
+    输辈辆轿轰连运阵库裤挥辉浑晕轩辑辖辅舆辙斩渐暂惭轧挽辕轴见视觉观亲规览现觅觊国们\
+    来这个时会学对发后长东无为与过还进动实当点从样两里里体万电爱战联复复仇圣龙华汉传\
+    侠剑梦画书宝归乱亚恶党兰苹叶药艺节范筑简篮权欢乐机极楼枪检标条构树桥杀气灭满浓湿\
+    灾灯营炉爷墙独猎奖兽环产毕异疗尽监盘众础确礼祸种称稳穷竞笔粮罚义习声听脑脚脸兴举\
+    旧庄盖苍虫蛮术卫装袭触丰猪猫赶趋迹践踪跃躯游达远迟边遥适选遗乡邻医丑释队阳阴际随\
+    险隐双杂离难云雾灵静韩响飞发斗齐齿龟丽麦黄团围圆图场块压坏壮处备够夺奋妇婴孙宁审\
+    写专将寻导届属岁岭币帮干广厅张强弹径征彻忆应怀悬恋戏击据拥择担扩摄败敌数断于昼畅\
+    历历晓残壳毁决没况泪浅沟准滞涨洁潜泽济涛湾洒烟热争状狭犹狮琐苏盗硕秘御税积谷笼罢\
+    罗职胆胶舰艰茎萧荐蓝号蝉蜡补衬赵农逻邮郑酱酿陈陆只虽雳台台台脏松鲁盐面霉龄庞刘劳\
+    势胜区协单厉参严乔叹叹喷吓嘱伙夹奥奸娄娇娘宠宽尴尸层冈峡岗岩帅师帐带几废庙厂弯汇\
+    汇佛忧恼态惨惯庆虑怜凭懒惧户拨扑挡挤拟摆拦携叙敛毙晖旷胧杆弃栋杠桩桦横档柜栏欧歼\
+    殴毡氢冲冲凉净沦涡汤温沧滚浆泼涩浊滨浏潇滩乌炼烦烂牵牺狱莹玺琼瓮亩叠疯痴皑盏睁瞒\
+    矫矿禅窝窃笋筝签签粤系坛坛翘聪肃胁脉肾肤舱艳艳莲蒋荡芦虚虾蚕袅袜竖岂辞办周递逊邓\
+    酝厘陕隶雏靓韵髅胡郁么出刚划剧剂劲励却厌丛吴员启丧呜坚堕坟壶寿妆娱孪寝屉屿峦帧厢\
+    弥恒惬欲惩掳扰摊昵枭栈荣桨枢椭槟樱殇渊涌渗浒涟溃涧浇溅泻炽烧灿烛烁犊狈狰狞获获珑\
+    疮痒皱卢瞩砚砾禄祯禀秽窑窜笺筛箫筹羡耸唇脱肿腻腊临卧莱卜芜姜萨蕴萝虏亏萤蝇蝎蔑褛\
+    跄辫回迁邹衅陨霁霭韦韧鬓魇麸斋龛儿报侦尔伦伤别园价册伪凶仅仪亿优偿储杰伞倾侨仆雇\
+    侬尽兑内凯冻凛刽劝匀匮呛喽吨咙嚣啰囵执尧涂尘垫坠墙垒坝奂姗嬷嫔宫屡峥崭巅帜厦庐吊\
+    别耻悦怆愤恳抛舍卷扫拣损摇抢挚撑挠抚拧掷拢搅晋晒朴槛柠橱汹凄减涣沪卤渍润淀滥澜炖\
+    烫献瑶畴愈瘾杯蒙砖祷洼窍帘篱聂脐荆苇蔼蜕蜗蚝踊跷辩迈辽腌锥陇巩缰哄咸鼹蚁僵";
+
+/// Characters by pinyin initial.
+pub(crate) const PINYIN_INITIALS: &[(char, &str)] = &[
+    (
+        'a',
+        "阿呵锕嗄啊哎哀唉埃娭挨欸溾嗳锿噯啀捱皑溰敳皚癌騃毐昹娾矮蔼濭藹霭靄艾爱砹隘嗌嫒愛\
+        碍暧瑷閡僾壒嬡薆鴱曖璦餲礙譪譺鑀靉安侒峖桉氨庵菴谙媕萻痷腤鹌鞍盦諳馣鵪韽玵啽雸儑\
+        垵俺唵埯铵揞銨犴岸按洝荌案胺豻婩晻暗錌闇黯肮骯卬昂盎醠凹柪爊敖隞嗷廒滶蔜遨摮熬獒\
+        璈磝翱聱螯謷鳌鏖鰲鼇芺拗袄镺媪媼襖岙扷坳岰傲奡奥奧骜隩墺嶴懊澳擙鏊驁",
+    ),
+    (
+        'b',
+        "八仈扒朳岜芭峇柭疤捌粑蚆豝犮拔茇癹胈菝詙跋軷魃鼥把钯鈀靶坝弝爸耙跁鲅矲霸壩灞巴叭\
+        吧笆罢罷挀掰擘白百佰柏捭粨絔摆擺襬庍败拜敗猈稗粺扳攽班般颁斑搬斒頒瘢鳻褩癍阪坂昄\
+        板版钣粄舨鈑蝂魬闆办半伴坢姅怑拌绊柈湴絆鉡靽辦瓣扮螁邦垹帮梆浜縍幫鞤绑綁榜牓膀玤\
+        蚌傍棒棓谤塝搒蒡磅镑艕謗鎊勹包孢苞枹胞笣煲龅蕔褒齙窇雹薄宝怉饱保鸨堡媬葆飽褓駂鳵\
+        鴇寶报抱豹趵菢袌報鉋鲍暴髱虣鮑儤爆忁鑤鸔陂卑杯盃桮悲揹椑碑鹎藣鵯北贝孛狈貝邶备牬\
+        背郥钡倍悖狽被偝梖珼鄁備惫焙琲辈碚蓓犕褙誖鞁骳輩鋇憊糒鞴鐾呗唄奔泍贲栟锛錛本苯畚\
+        翉坋坌渀笨伻祊崩絣閍傰痭嘣甭埲绷菶琣琫繃泵迸塴甏蹦蠯屄偪逼楅豍鵖鎞荸鼻匕比朼吡妣\
+        沘疕彼柀秕俾笔舭筆鄙箄貏币必毕闭佖坒庇邲妼怭畀苾哔柲毖珌疪荜陛毙狴畢笓粊铋婢庳敝\
+        梐萆閉堛弼愊愎湢皕筚詖賁嗶彃滗滭痹痺腷蓖蓽蜌裨跸鉍閟飶幣弊熚獙碧箅綼蔽鄪馝潷獘罼\
+        駜髲壁嬖廦篦篳縪薜觱避鮅斃濞臂蹕髀奰璧鄨鏎繴襞襣躄驆贔鷝鷩鼊边砭笾揙猵编煸甂箯編\
+        蝙鳊邊鞭籩贬扁窆匾貶惼萹碥稨褊鴘卞弁忭抃汳汴苄釆变便缏遍閞緶艑辨辩辫辮辯變灬杓标\
+        飑髟淲彪猋颩墂摽滮蔈颮骠標熛膘瘭镖飙飚儦瀌藨謤爂臕贆鏢穮镳飆驃鑣驫表婊裱諘褾錶檦\
+        俵鳔鰾憋蟞鳖鱉虌別别咇蛂徶襒蹩瘪癟彆汃邠玢砏宾彬傧斌椕滨缤槟豩賓镔儐濒濱虨豳檳璸\
+        瀕霦繽鑌摈殡膑擯殯臏髌鬓髕鬢冫冰兵掤丙邴陃怲秉苪昺柄炳饼窉蛃摒禀稟鉼餅鞞并並併庰\
+        病偋寎誁帗拨波玻剝剥哱砵袚钵饽缽菠碆僠嶓撥播餑蹳鱍伯驳帛瓝勃胉郣亳挬浡瓟钹铂脖舶\
+        袹博渤葧鹁搏猼鈸鉑僰箔蔔駁踣鋍馞駮襏豰嚗懪礡簙鎛餺鵓犦髆欂襮礴鑮跛簸檗糪譒蘗卜啵\
+        膊峬逋晡鈽誧鳪轐醭卟补哺捕補鵏不布佈吥步咘怖抪钚埔捗部钸埠瓿蔀踄餔篰簿",
+    ),
+    (
+        'c',
+        "嚓擦攃礤囃偲婇猜才材财財裁纔采啋寀彩採睬跴綵踩埰菜棌蔡縩参參骖嬠餐驂残蚕惭殘慚蠶\
+        惨朁慘憯穇篸黪黲灿掺孱粲摻澯燦璨仓伧沧苍倉舱傖嵢滄獊蒼艙鶬藏鑶操糙曹嘈嶆漕槽艚螬\
+        艸草懆騲肏鄵襙艹冊册侧厕恻拺测畟側粣廁惻測策萴筴蓛憡簎嵾岑涔笒梣噌层曾層嶒驓蹭叉\
+        扠杈臿偛嗏插馇锸艖疀鍤秅垞查茬茶搽猹槎察碴檫衩蹅镲奼汊岔侘诧姹差紁詫拆钗釵侪柴豺\
+        祡喍儕齜虿袃瘥蠆囆辿觇梴搀覘裧鉆鋓幨襜攙婵谗棎湹禅馋煘缠僝獑蝉鋋儃嬋廛潺澶磛禪毚\
+        镡瀍蟬儳劖蟾酁嚵巉瀺欃纏躔艬讒鑱饞产旵丳谄產铲阐蒇剷嵼滻嘽幝蕆諂骣燀簅冁繟譂辴鏟\
+        闡囅灛忏摲颤懺羼顫伥昌倀娼淐猖菖阊琩裮錩閶鲳鯧鼚肠苌尝偿常徜萇腸嘗嫦鋿償嚐鱨厂场\
+        昶惝場廠氅鋹怅畅倡鬯唱悵瑒暢韔敞抄弨怊钞訬焯超鈔勦牊晁巢朝鄛漅嘲樔潮窲罺轈吵炒眧\
+        焣耖车車砗莗硨扯撦屮彻坼迠烢掣硩徹撤澈抻郴捵琛嗔綝瞋諃賝縝謓尘臣忱沈沉辰陈茞宸莐\
+        陳谌愖揨鈂煁蔯塵樄螴諶麎鷐碜墋磣踸鍖贂衬疢龀趁榇齔儭嚫谶櫬襯讖晨柽棦浾琤称偁蛏湞\
+        牚稱撐撑橕瞠赬檉竀蟶鏿饓丞成朾呈承枨诚郕城宬峸荿乘埕晟脀掁珵碀脭铖惩棖程裎塍溗誠\
+        酲鋮澂澄橙懲騬悜逞骋庱騁秤吃哧胵蚩鸱瓻眵笞喫嗤媸摛痴絺瞝誺螭鴟癡魑齝黐弛池驰迟坻\
+        茌持荎歭蚳貾趍馳箎墀漦踟遲篪謘尺呎侈齿胣恥耻蚇欼袲鉹褫齒彳叱斥赤饬抶炽翅敕啻湁飭\
+        傺痸跮鉓瘈遫瘛翨熾懘饎鶒鷘麶充冲忡沖茺浺珫翀舂憃憧衝罿艟蹖虫崇隀緟蝩蟲爞宠寵铳揰\
+        銃抽婤搊瘳篘犨仇怞俦帱栦惆紬绸菗椆畴絒愁稠筹裯綢踌儔雔嬦幬懤薵燽雠疇籌躊讎丑吜杻\
+        杽偢瞅醜臭殠酬出初摴樗貙齣刍除芻厨滁蒢锄媰耡蜍趎鉏雏犓廚篨鋤橱幮藸躇雛櫥蹰鶵躕杵\
+        础储楮褚濋儲檚礎鸀亍处怵绌豖柷俶敊畜埱珿絀處琡鄐搐滀蓫触踀儊諔憷斶歜臅黜觸矗楚欻\
+        揣搋膗啜嘬膪踹巛川氚穿猭瑏伝传舡船圌遄傳椽暷輲舛荈喘歂踳汌串玔钏釧賗鶨疮窗摐瘡床\
+        幢闯傸磢闖创怆刱凔創愴吹炊垂倕陲捶菙搥棰椎腄槌锤箠錘鎚杶春媋暙椿蝽橁輴鰆鶞纯唇純\
+        莼淳脣犉漘蓴醇錞鯙偆萶惷賰蠢鹑鶉逴踔戳辶娕娖婼惙绰腏辍綽趠輟龊擉磭歠嚽齪呲疵趀偨\
+        跐縒骴髊蠀齹词柌祠茈茨堲瓷詞慈辞磁雌鹚糍飺餈嬨濨薋辭鶿此佌泚玼皉鮆朿次伺佽刺庛茦\
+        栨莿絘蛓赐賜匆囪囱苁枞棇葱蓯蔥骢暰樅熜瑽璁聪瞛聰蟌鏦驄从丛婃孮徖從悰淙琮慒漎潀賨\
+        藂叢欉謥凑湊腠辏輳粗觕麤徂殂促猝酢蔟噈憱踧醋瘯簇蹙鼀蹴顣汆撺鋑镩蹿攛躥巑欑窜篡竄\
+        爨崔催凗墔嶉慛摧榱磪縗鏙漼璀趡倅脆啐悴淬萃毳焠脺瘁粹綷翠膵膬濢竁村皴踆存拵刌忖寸\
+        吋籿搓瑳遳磋撮蹉醝嵯痤矬蒫蔖鹾鹺躦脞剉剒厝夎挫莝措斮棤锉蓌错銼錯",
+    ),
+    (
+        'd',
+        "咑哒耷荅笚嗒搭褡噠撘鎝达迖呾妲怛沓炟羍匒畣笪答達靼薘鞑鎉躂鐽韃龘打大汏瘩繨呆呔獃\
+        歹逮傣代岱甙绐迨骀带待怠柋殆玳贷軑埭帶紿袋貸軩廗戴艜黛蹛瀻襶靆丹单担眈砃耽郸聃單\
+        殚瘅匰箪鄲儋勯擔殫甔癉襌簞聸伔刐抌玬胆衴疸紞掸赕亶撢撣澸黕膽黮旦但帎沊狚诞柦啖啗\
+        弹惮淡萏蛋啿氮蜑觛窞誕僤噉髧嘾彈憚憺暺澹禫鴠癚嚪繵贉霮泹当裆當噹澢璫襠簹艡蟷挡党\
+        谠擋黨攩欓讜凼宕砀荡档菪婸愓瓽逿嵣潒碭儅蕩檔璗盪礑簜闣铛鐺刀刂叨忉氘舠魛捯导岛島\
+        捣祷禂搗導壔擣蹈禱到倒悼焘盗菿盜道箌翢稻檤燾翿瓙纛淂锝德地的得扥扽灯登豋噔嬁燈璒\
+        簦蹬等戥邓凳鄧墱嶝瞪磴镫鐙氐低彽袛羝隄堤滴樀镝磾鍉鞮狄籴苖迪敌涤荻梑笛觌靮滌馰嘀\
+        嫡翟蔋敵篴嚁藡蹢鬄鏑糴覿鸐厎诋邸阺呧底弤抵柢牴砥菧觝詆軧聜骶弟旳杕玓帝娣递梊焍珶\
+        眱第菂谛釱棣渧睇缔蒂禘遞墑墬碲蔕蝃遰甋締諦踶螮嗲甸敁掂傎厧滇槙瘨颠蹎巅顛癫巔癲齻\
+        典点婰跕碘蒧蕇踮點电佃阽坫店垫扂玷钿婝惦淀奠殿電墊壂橝澱靛癜簟驔刁叼虭凋彫蛁琱貂\
+        碉鳭殦瞗雕鲷鯛鵰扚屌弔伄吊钓调掉釣铞铫蓧雿魡調窵藋爹跌苵迭垤峌挕昳胅瓞眣谍喋堞惵\
+        揲絰耋臷詄镻叠牒嵽碟蜨褋艓蝶諜蹀鲽鰈疊丁仃叮帄玎疔盯钉耵虰酊釘靪顶頂鼎嵿濎薡订定\
+        訂飣啶铤椗腚碇锭碠鋌錠顁萣丟丢铥銩东冬咚岽東苳氡鸫埬崠涷菄蝀鼕鶇董墥嬞懂蕫动冻侗\
+        垌姛峒恫挏栋洞胨迵凍戙胴動硐棟湩絧駧霘唗都兜蔸篼抖枓陡蚪鈄斗豆郖浢荳逗鬥梪脰酘痘\
+        窦餖竇剢嘟督醏闍毒独读渎椟牍犊碡裻蝳獨錖瀆櫝殰牘犢皾黩讀贕韣髑韇韥黷讟笃堵帾赌睹\
+        賭篤芏妒杜肚度秺渡镀鍍蠹耑偳剬媏端短段断缎葮椴煅腶碫锻緞毈簖鍛斷躖籪堆痽鴭頧队对\
+        兌兑祋怼隊碓對憝濧薱镦懟瀩譈鐓吨惇敦蜳墩噸撉橔礅蹲驐盹趸躉伅囤庉沌炖盾砘钝顿遁鈍\
+        楯頓遯潡燉多咄哆剟掇毲裰夺铎剫敓喥敪奪踱鮵鐸哚垛埵缍椯趓躲綞鍺嚲奲剁陊陏柁柮堕舵\
+        惰跢跺墮嶞墯鵽朵",
+    ),
+    (
+        'e',
+        "婀屙钶痾讹吪囮迗俄娥峨涐莪珴訛皒睋锇鹅蛾额魤額鵝枙噁厄岋阨呃扼苊砐轭咢垩姶峉匎恶\
+        砨蚅饿堊掠略谔軛鄂阏堮崿惡愕萼豟軶遏廅搤搹腭詻蝁锷鹗頞颚餓噩諤閼餩鍔鳄歞顎櫮鶚齃\
+        鑩齶鱷诶誒奀恩蒽峎摁鞥儿而侕兒陑峏洏荋栭胹唲鸸聏輀鲕鮞鴯尒尔耳迩洱饵栮毦珥铒爾餌\
+        駬薾邇二佴刵咡贰衈貳鉺樲",
+    ),
+    (
+        'f',
+        "发沷發醱乏伐垡疺罚茷阀砝筏瞂罰閥藅法珐琺髮帆番勫嬏幡蕃旛繙翻藩轓颿籓鱕凡杋柉矾籵\
+        钒烦笲渢煩墦樊橎燔璠膰薠繁羳蹯瀪瀿礬蘩鐇蠜鷭反返釩氾犯奿汎泛饭范贩畈軓梵笵販軬飯\
+        滼範匚方邡汸芳枋钫淓鈁防妨房肪鲂魴仿访彷纺昉瓬倣紡舫訪髣鶭放坊飞妃非飛啡婓绯菲扉\
+        裶緋蜚霏鲱餥馡騑騛肥淝腓蜰蟦朏匪诽奜悱斐棐榧翡誹篚吠芾废沸狒肺昲胇费俷剕厞疿陫屝\
+        萉費痱镄廢曊癈鼣濷櫠鯡鐨分吩纷芬昐氛衯紛翂兝棻酚鈖雰饙坟妢汾枌炃羒蚡梤棼焚蒶墳幩\
+        濆蕡魵橨鼢羵鼖豶轒鐼黂粉黺份弅奋忿秎偾愤粪僨憤奮膹糞鲼瀵丰风妦沣枫封疯砜風峰偑桻\
+        烽崶猦葑锋楓犎蜂瘋鋒檒豐酆灃蘴飌麷冯夆捀逢堸馮摓艂讽覂唪諷凤奉甮俸焨缝鳳縫賵覅坲\
+        紑缶否缹殕鴀伕邞呋姇肤怤柎砆荂衭垺尃荴紨趺麸痡稃跗鈇綒鄜孵敷膚鳺麩糐巿弗伏凫佛冹\
+        刜孚扶芙芣咈岪彿怫拂服枎泭绂绋苻茀俘垘柫氟洑玸祓罘茯郛浮砩莩蚨匐桴涪烰琈符笰紱紼\
+        翇艴菔虙幅棴罦葍福綍艀蜉辐鳧榑箙韍幞澓蝠鴔諨踾輻襆黻鵩鶝呒抚府弣拊斧俛胕郙俯釜辅\
+        腑滏蜅腐輔嘸撫頫簠黼阝父讣付妇负附阜驸复峊祔訃負赴蚥偩副婦蚹富復萯蛗赋缚腹鲋複褔\
+        赙緮蕧蝜蝮賦駙嬔縛輹鮒賻鍑鳆覆馥鰒夫甫咐袱傅",
+    ),
+    (
+        'g',
+        "旮呷嘎钆尜噶尕尬侅该陔垓峐荄赅祴絯該豥賅改丐阣杚钙盖摡溉鈣隑戤概蓋賌瓂甘杆玕肝坩\
+        泔矸苷柑竿疳酐乾尴筸漧鳱尷扞皯秆赶敢桿笴稈感澉趕橄擀簳鰔干旰盰绀凎淦紺詌骭幹榦赣\
+        贛灨冈刚杠纲肛岡疘矼缸钢剛罡堈釭棡犅堽綱鋼岗崗港筻槓戆皋羔高滜槔睾膏篙糕櫜鷎鼛杲\
+        搞缟暠槁稿镐縞藁檺告勂诰郜祰锆煰筶誥鋯韟戈仡圪犵纥肐疙咯哥胳袼鸽割搁滒歌鴐擱鴿佮\
+        挌茖阁革格鬲愅葛隔嗝塥滆觡搿膈閣閤獦镉鞈韐骼輵鮯轕鞷騔哿舸个各虼個硌铬箇给給根跟\
+        哏艮亘亙茛揯庚浭耕菮赓緪賡羹鶊郠哽埂挭绠耿梗綆鲠骾鯁更堩工弓公功攻供肱宫宮恭躬龚\
+        匑塨幊愩觥龔廾巩汞拱拲栱珙輁鞏共贡羾貢蚣勾佝沟钩袧缑溝鉤緱篝鞲韝芶岣狗苟枸耇笱蚼\
+        构诟购垢姤茩冓够夠媾彀搆詬遘雊構觏覯購估呱姑孤沽泒柧轱罛鸪菰蛄觚軱辜酤鈲箍箛嫴橭\
+        鮕鴣鶻夃古扢汩诂谷股牯骨唃罟羖钴啒淈蛊蛌尳愲蓇詁鹄榾毂鈷鼓嘏榖鹘穀縎薣濲臌轂瀔盬\
+        瞽蠱固故凅顾堌崮梏牿棝雇痼稒锢僱錮鲴顧咕菇瓜刮胍栝鸹聒趏劀緺颳鴰騧剐剮寡卦诖挂掛\
+        罣絓罫褂詿乖掴摑拐枴柺夬怪关观官冠倌棺瘝鳏關鰥觀鱞莞馆琯痯筦管錧館毌丱贯悺惯掼涫\
+        貫悹祼慣摜盥雚灌爟瓘矔鹳罐鑵鱹鸛光咣垙洸茪桄烡胱銧广犷廣獷臩俇珖逛臦撗炚归圭妫龟\
+        规邽皈茥闺珪傀硅窐規媯廆瑰嫢摫閨鲑槻槼螝鮭龜巂歸騩宄氿朹轨庋佹匦诡垝姽癸軌鬼庪祪\
+        匭晷湀蛫觤詭厬瞡簋蟡刽刿昋柜炔贵桂桧筀貴跪匱劊劌嶡撌槶檜瞶禬簂櫃癐襘鳜鱖丨衮惃绲\
+        袞袬辊滚滾緄蔉磙輥鲧鯀棍睔璭呙咼埚郭堝崞锅墎瘑嘓彉濄蝈鍋蟈国國帼幗慖漍聝蔮膕虢馘\
+        果惈淉猓椁槨粿蜾裹輠錁鐹过過",
+    ),
+    (
+        'h',
+        "哈铪蛤咍咳嗨还孩頦骸還海胲烸醢亥妎骇害氦嗐餀駭顸蚶酣頇嫨谽憨歛鼾邗含邯函咁肣虷唅\
+        晗梒涵焓琀寒嵅韩甝蜬鋡魽韓罕喊蔊阚豃鬫汉汗旱哻悍捍涆晥焊菡釬閈睅颔馯撖漢蜭暵熯銲\
+        鋎憾撼翰螒頷顄駻譀雗瀚鶾夯苀迒斻杭绗珩笐航蚢颃貥絎頏魧沆茠蒿嚆薅薧蚝毫嗥貉豪諕嚎\
+        壕濠籇蠔譹好郝号昊昦秏哠悎浩耗淏皓鄗滈號澔皜皞薃鎬颢灏顥鰝灝诃欱喝訶嗬禾合何劾厒\
+        和姀河郃峆曷籺紇阂敆核盉盍荷涸渮盒菏蚵颌楁毼澕輅鉌阖熆麧頜篕翮螛魺礉闔鞨齕覈鶡鑉\
+        龢佫贺賀嗃煂熇褐赫鹤翯壑謞鶴黑嘿潶拫痕鞎佷很狠恨亨哼啈脝姮恆恒桁烆胻横橫衡蘅鑅噷\
+        吽灴轰哄訇烘揈渹焢谾薨輷嚝鍧轟仜弘妅红吰宏汯玒闳泓苰洪竑紅荭虹浤紘翃耾谹鸿渱竤葒\
+        鈜閎谼鞃魟鋐彋蕻霐黉霟鴻黌嗊讧訌澋澒鬨齁侯鄇喉猴葔瘊篌糇翭骺翵鍭餱鯸吼后郈厚垕後\
+        洉逅堠鲎鱟候匢虍呼垀忽昒曶烀轷唿惚淴虖寣滹幠歑膴謼囫抇弧狐胡壶斛焀壺媩搰湖猢葫楜\
+        煳瑚嘝鹕槲蝴衚魱縠螜醐觳餬鵠瀫鬍鰗鶘鶦汻虎浒琥滸互戶户冱芐护沍沪岵怙戽昈枑祜笏婟\
+        扈瓠楛嗀綔鄠雽嫭嫮滬蔰槴熩鍙鹱護韄頀乎唬糊花哗嘩錵华姡骅華釪釫铧滑猾搳螖鋘譁鏵驊\
+        鷨化划杹画话崋桦畫嬅觟話劃摦樺嫿槬澅繣怀徊淮槐褢踝褱懷瀤櫰蘹坏咶諙壞蘾犿鴅酄嚾懽\
+        獾讙驩环郇峘洹狟荁桓萑雈綄羦貆锾圜嬛寰澴缳環豲鍰繯轘鐶闤鬟瓛缓緩攌幻奂肒奐宦唤换\
+        浣涣患焕逭喚換渙痪睆煥瑍豢漶瘓鲩擐澣鯇欢瞣歡巟肓荒衁慌皇偟凰隍黄喤堭媓崲徨惶湟葟\
+        遑黃楻煌潢獚熿璜篁篊艎蝗癀磺穔諻簧蟥鍠餭鳇趪韹騜鰉鷬怳恍炾谎幌熀謊滉榥皝鎤晃灰诙\
+        咴恢拻挥洃虺晖珲豗揮辉隓暉楎煇禈詼褘噅撝翬輝麾徽隳蘳鰴回佪恛洄茴迴烠蚘痐蛔悔毀毁\
+        毇檓燬譭卉汇会讳哕浍绘芔荟诲恚烩贿彗晦秽喙惠缋匯彙會賄嘒瘣蔧誨圚慧憓槥潓蕙噦徻橞\
+        澮濊獩薈薉諱燴璯篲藱餯瞺穢繢蟪繪翽譓鏸闠孈鐬譿顪昏荤婚惛涽阍棔殙葷睧睯閽浑梡馄渾\
+        琿魂餛繉轋鼲诨倱圂掍混溷慁觨諢吙耠锪劐嚄豁攉騞佸活秮火伙钬鈥漷夥沎或货砉捇眓获掝\
+        祸貨惑湱禍濩獲霍檴謋穫镬嚯瀖藿蠖曤臛矐鑊靃",
+    ),
+    (
+        'j',
+        "丌讥击刉叽饥乩圾机玑肌芨矶鸡枅咭迹剞唧姬屐积笄飢基绩嵇敧犄缉赍勣畸稘跡跻毄箕銈嘰\
+        畿稽緝觭齑墼機激璣禨積錤隮擊磯簊績羁鄿櫅蹟雞譏鐖饑躋鞿齎羇虀鑇齏羈鸄及伋吉岌彶忣\
+        汲级即极亟佶诘姞急狤笈級揤疾脊觙偮庴谻戢棘極殛湒集塉嫉楫蒺趌槉耤膌銡嶯潗濈瘠蕀蕺\
+        踖橶螏藉襋蹐籍轚鏶霵鶺鷑几己丮妀泲虮挤掎幾戟嵴麂撠擠穖蟣魕彐旡计记伎纪妓忌技芰际\
+        剂季哜既洎济紀茍茤荠計紒继觊記偈寂寄徛悸祭塈惎臮蔇痵蓟裚際鬾暨漃漈穊誋跽霁鲚暩稷\
+        諅鲫冀劑穄薊髻嚌檕濟罽薺覬鵋齌懻癠穧骥鯚瀱繼蘮鱀蘻霽鰶鰿鱭驥辑輯癪加夹伽夾抸佳泇\
+        茄迦枷毠浃珈家浹痂梜笳耞袈傢猳葭跏犌嘉鉿镓豭貑鎵麚扴郏荚郟唊恝莢戛袷铗蛱裌颊蛺跲\
+        鞂鋏頰鴶鵊甲岬玾胛贾钾假婽徦斝椵賈鉀榎瘕檟价驾架嫁幏價駕稼戋奸尖幵坚歼间戔肩艰姦\
+        兼监堅惤猏笺菅菺豜湔牋犍缄葌間搛椷煎瑊缣蒹監箋熞緘蕑鲣鳽鹣熸縑艱鞬餰麉鞯礛鵳瀸櫼\
+        殲鶼鰹虃鑯韉囝拣枧俭柬茧挸捡笕减剪检湕趼堿揀揃減睑硷裥锏暕瑐筧简谫戩戬碱儉翦撿檢\
+        藆襉謇蹇瞼簡繭鬋鰎劗籛譾襺鹼见件見建饯剑洊牮荐贱俴健栫涧珔舰渐袸谏寋楗毽溅腱葥践\
+        鉴键僭榗漸蔪劍澗箭糋諓賤踐踺薦諫鋻鍵餞瞷螹濺瀳艦轞鐱鑑鑒鑳江姜将茳浆豇將翞僵漿缰\
+        薑橿殭螿礓疆韁讲奖桨傋蒋蔣槳獎耩膙講顜降洚绛弶袶絳酱嵹彊犟糨醬謽匠艽交郊姣娇浇茭\
+        骄胶椒焦蛟跤僬嘄鲛嬌嶕憍澆膠蕉燋膲礁穚鮫鵁鹪簥蟭轇鐎鷍驕鷦鷮角佼侥恔挢狡绞饺捁皎\
+        矫脚铰搅湫絞剿敫煍腳僥摷鉸餃儌劋撟徼憿敿缴曒矯皦蟜繳譑攪灚鱎叫峤挍珓窌轿较教窖滘\
+        較嘂斠酵噍嶠潐噭嬓獥趭轎醮譥皭釂阶疖皆接痎秸菨階喈嗟揭椄湝街蝔癤謯鶛卩孑尐节讦劫\
+        岊昅劼杰疌衱拮洁结迼倢桀訐婕崨捷袺傑結絜颉嵥楬楶滐睫節蝍詰鉣截榤碣竭鲒潔羯踕鞊幯\
+        鍻鮚巀蠽媎解檞介吤岕戒芥屆届玠界疥砎诫借悈蚧蛶骱犗誡褯躤姐巾今斤钅金津矜衿觔埐珒\
+        紟惍堻筋釿嶜襟仅尽卺巹紧堇菫僅谨锦廑盡緊蓳馑槿瑾儘錦謹饉伒劲妗近进枃勁荩晉晋浸烬\
+        赆祲進寖搢溍禁缙靳墐僸殣璡觐噤縉賮嚍嬧濜藎燼璶覲贐巠京泾经茎荆荊涇莖婛惊旌旍菁晶\
+        腈粳經兢精鲸鵛鯨鶁鶄鼱驚麠井丼阱刭汫肼剄颈景儆幜憬憼暻燛璟璥頸蟼警净弪径迳俓胫倞\
+        凈弳徑痉竞逕婧桱淨竫脛竟敬痙靓靖境獍静靚镜靜鏡競睛冂坰扃絅駉冏囧泂炅迥炯浻煚窘熲\
+        褧勼纠朻牞究鸠糾赳阄萛啾揂揪揫鳩摎樛鬏鬮九久灸玖韭酒旧臼咎疚柩捄桕厩救就廄舅僦慦\
+        殧舊鹫鯦麔鷲匊居拘泃狙苴驹挶疽痀砠罝陱娵崌掬梮涺菹椐琚腒趄锔裾雎蜛踘踙鋦駒鮈鞠鞫\
+        鶋局侷狊桔淗焗菊郹椈湨犑輂僪跼閰趜橘駶鵙鵴蘜鶪鼳驧咀沮举莒椇筥榉榘蒟龃舉踽櫸齟巨\
+        句讵姖岠拒洰苣具怐怚炬秬钜俱倨剧粔耟蚷袓埧惧据詎距犋跙鉅飓虡豦锯寠窭聚駏劇屦踞壉\
+        懅據澽窶遽鋸屨颶貗躆醵懼鐻矩娟捐涓焆脧裐鹃镌鵑鐫蠲卷帣捲菤锩臇錈倦桊狷绢隽眷鄄睊\
+        絭罥雋睠絹蔨獧縳噘撅屩蹻孓决诀抉決芵泬玦玨珏绝虳觉倔捔蚗崛掘桷殌觖訣赽趹傕厥絕趉\
+        鈌劂谲駃憰獗瘚蕝蕨鴃噱橛爵臄镢蟨譎蹶鶌匷嚼矍覺鐍爝觼彏戄攫玃鷢貜躩钁军君均袀軍钧\
+        莙蚐桾皲菌鈞碅皸麇鍕鮶呁俊郡峻捃浚骏晙焌珺棞畯竣箘蜠寯餕燇濬駿鵔鵘",
+    ),
+    (
+        'k',
+        "咔咖喀擖卡佧胩鉲开揩锎開凯剀垲恺铠凱剴慨蒈塏愷楷輆暟锴鍇鎧闓颽忾欬烗勓愒愾鎎刊勘\
+        龛堪嵁戡龕坎侃砍莰埳欿歁槛檻顑竷轗看衎崁墈瞰磡闞矙闶康嫝慷漮糠鏮扛摃亢伉匟邟囥抗\
+        犺炕钪鈧閌尻攷考拷洘栲烤铐犒銬靠匼苛柯牁珂科轲疴砢趷棵軻颏嗑搕犐稞窠鈳榼薖颗樖瞌\
+        磕蝌錒顆髁壳殼翗可坷岢嵑敤渴嶱克刻剋勀客恪课堁氪骒缂溘锞緙艐課騍嵙肯垦恳啃豤龈墾\
+        錹懇齦掯裉阬吭坑挳牼硜铿硻誙銵鍞鏗空倥崆悾涳硿箜錓孔恐控鞚抠芤眍彄摳口叩扣寇釦筘\
+        滱蔻瞉簆鷇刳矻枯胐哭桍堀跍窟骷狜苦库绔庫趶喾裤瘔酷褲嚳夸姱誇侉垮銙挎胯跨骻舿蒯块\
+        快侩郐哙狯脍塊筷儈鄶噲廥獪膾旝鱠宽寬臗髋髖款窾匡劻诓哐恇洭框筐誆狂诳誑鵟夼懭邝圹\
+        纩况旷況矿贶眶絖貺軦鄺壙曠爌矌礦穬纊亏刲岿悝盔窥聧窺虧顝闚巋蘬奎逵鄈隗頄馗喹揆葵\
+        戣暌楏楑魁睽蝰頯櫆藈鍷騤夔犪躨煃跬頍蹞匮欳喟媿愦愧溃腃蒉馈嘳嬇憒潰篑聩蕢樻餽簣聵\
+        鐀饋坤昆崑晜猑菎焜琨锟髡褌醌錕鲲騉鯤鶤悃捆阃梱硱裍壼稛綑閫齫困涃睏扩括筈萿葀蛞阔\
+        廓髺擴闊懖霩鞹鬠韕",
+    ),
+    (
+        'l',
+        "垃拉柆翋菈搚邋旯剌砬喇藞腊揧楋瘌蜡辣臘鬎蠟鑞啦鞡来來崃徕涞莱郲崍庲徠淶萊棶铼箂錸\
+        騋鯠鶆唻赉睐睞赖賚濑賴癞瀨籁藾癩籟兰岚拦栏婪惏嵐阑蓝谰澜褴斓篮藍镧闌襤譋攔瀾灆籃\
+        蘭斕欄襴灡籣欗讕躝览浨揽缆榄漤罱醂壈懒嬾懶覽攬灠欖顲纜烂滥嚂濫爁爛鑭糷爦啷郎欴狼\
+        阆廊桹琅榔瑯硠稂锒筤蜋螂鋃鎯駺朗烺塱誏埌崀浪莨蒗閬捞撈劳牢唠崂浶勞痨铹僗嘮嶗癆簩\
+        蟧醪鐒顟老佬咾姥恅狫荖栳铑銠潦橑轑涝烙耢酪嫪澇橯軂肋仂阞乐叻扐氻泐竻砳樂鳓鰳了勒\
+        雷嫘缧蔂畾擂檑縲礌镭櫑瓃羸礧纍罍蘲蠝鐳轠儽壨虆欙耒诔垒絫誄樏磊磥蕾儡壘癗藟櫐礨灅\
+        讄鑸鸓泪类淚累酹銇頛類纇蘱禷嘞崚塄棱楞稜輘薐冷倰堎愣睖踜杝厘离骊梨梩犁琍菞喱鹂剺\
+        漓筣缡蓠蜊嫠孷樆璃貍糎蔾褵鲡黎篱縭罹謧醨藜釐離斄鯬鵹黧攡灕蘺蠡騹孋廲劙鑗籬纚驪鱺\
+        鸝礼里俚峛娌浬逦理锂粴裏豊鋰鲤兣澧禮鯉醴鳢邐鱧欚力历厉屴立吏朸丽利励呖坜沥苈例岦\
+        戾枥沴疠苙隶俐俪栎疬砅荔赲轹郦唎栗猁砺砾秝莅莉唳笠粒粝蚸蛎傈厤痢詈跞雳塛慄搮溧蒚\
+        蒞厲瑮綟蜧蝷勵曆歷篥鴗濿癘磿隸鬁儮曞犡禲鎘嚦壢櫟瀝瓅礪藶麗櫪皪盭礫糲蠣儷蠫酈鷅麜\
+        攦觻躒轢欐讈轣攭瓥靂鱱鱳靋李哩娳狸裡俩倆奁连帘怜涟莲連联裢嗹廉溓漣蓮奩槤覝憐磏褳\
+        鲢濂縺翴薕聯臁謰蹥鎌镰簾蠊鬑鐮鰱敛琏脸裣摙璉蔹嬚斂臉鄻襝羷蘞练炼恋浰殓僆堜湅萰链\
+        楝煉潋練澰殮鍊鏈瀲戀良俍凉梁涼椋粮粱墚綡踉樑輬糧两兩啢裲緉魉魎亮悢谅辆喨晾湸量諒\
+        輛撩辽疗聊僚寥嵺憀漻膋嘹嫽寮嶚敹獠缭遼暸燎膫療鹩屪簝繚蟟豂鐐飉鷯钌釕鄝蓼憭瞭镽尥\
+        炓料廖撂窷镣列劣冽姴洌茢迾哷埒栵烈捩猎脟蛚裂睙趔颲儠鮤鴷擸獵犣躐鬣咧拎邻林临啉淋\
+        琳痳碄箖粼鄰嶙潾遴暽燐璘辚霖瞵磷臨繗翷轔壣鏻鳞驎鱗麟菻亃凛凜廩廪懍懔檁檩吝赁焛賃\
+        僯蔺橉甐膦閵疄藺蹸躏躪灵囹坽夌姈彾泠狑苓昤柃玲瓴凌皊砱铃陵婈棂淩琌笭绫羚翎聆舲菱\
+        蛉詅軨裬鈴零龄綾蔆駖澪蕶錂鲮鴒霝酃鯪蘦齡櫺醽靈欞爧岭领領嶺令另呤炩伶溜熘蹓刘浏流\
+        留琉硫裗媹嵧旒蓅遛馏骝榴劉瘤镏蟉懰瀏藰鎏鎦麍鏐飀騮鰡鶹柳珋绺锍鉚飹綹罶嬼六翏塯廇\
+        磟鹨霤餾雡飂鷚龙咙泷茏栊珑胧砻笼聋隆癃龍嚨巃瀧簼蘢鏧曨朧櫳瓏矓礱襱龒籠聾蠪蠬豅躘\
+        鑨靇鸗陇垄垅拢篢儱隴壟攏哢梇徿窿瞜娄偻婁蒌僂楼廔慺漊蔞樓熡耧蝼耬艛螻謱髅鞻髏嵝搂\
+        塿嶁摟篓簍陋漏瘘镂瘺鏤喽嘍噜撸卢庐芦垆泸炉栌胪轳鸬玈舻颅鲈盧櫚嚧壚廬攎瀘璷蘆櫨爐\
+        臚籚纑罏艫蠦轤鑪顱鱸鸕卤虏掳鹵鲁虜滷蓾魯擄橹磠镥嚕擼櫓氌艣鏀鐪甪陆坴彔录赂辂陸淕\
+        淥渌硉菉逯鹿琭禄祿僇盝睩碌稑賂路塶廘摝漉蔍戮熝踛辘醁潞穋蕗錄錴璐簏螰蹗轆騄鹭簬鏕\
+        鯥鵱麓露籙鷺氇驴郘闾榈閭氀膢藘鷜驢吕呂侣侶挔捋旅梠祣稆铝屡絽缕屢膂褛鋁履褸儢縷穭\
+        垏律虑率绿嵂氯葎滤綠慮膟勴繂濾鑢娈孪峦挛栾鸾脔滦銮奱孌孿巒攣曫欒羉臠圞灤鑾癵鸞卵\
+        乱亂锊鋝擽抡掄仑伦囵沦纶侖轮倫陯圇崙惀淪菕棆綸蜦踚輪錀碖稐论溣論罗啰囉猡脶萝逻椤\
+        腡锣箩骡镙螺羅覶鏍儸騾玀蘿邏欏鸁籮鑼剆倮蓏裸瘰蠃臝泺峈洛络荦骆珞絡落摞漯犖鉻雒駱\
+        鮥鵅濼纙",
+    ),
+    (
+        'm',
+        "呣妈孖媽嬤嬷麻痲犘马玛码蚂馬溤瑪碼螞鎷鷌犸杩骂唛傌嘜榪禡罵礣鬕吗嗎嘛蟆埋薶霾买荬\
+        買嘪鷶劢迈麦卖脉脈麥勱賣邁霢嫚颟姏悗蛮僈谩慲馒樠瞒瞞鞔謾饅鳗顢鬗鬘鰻蠻屘睌满滿螨\
+        矕曼鄤墁幔慢漫獌缦蔓槾熳镘縵鏝邙忙汒芒尨杗氓盲笀茫哤娏庬牻硭痝蛖鋩駹莽硥茻壾漭蟒\
+        猫貓毛矛枆牦茅茆旄罞兞渵軞酕堥锚嫹髦氂犛蝥髳錨蟊鶜冇卯峁泖昴铆蓩芼茂冒眊贸耄袤媢\
+        帽萺貿鄚楙毷瑁瞀貌鄮蝐懋么麼嚜呅沒没枚玫苺眉莓梅脢郿堳媒嵋湄猸楣煤瑂禖塺酶镅鹛鋂\
+        霉穈徾攗蘪黴每美挴浼媄渼媺腜镁燘鎂妹沬昧袂眛媚寐痗煝韎魅蝞门扪钔門捫菛璊鍆亹虋闷\
+        焖悶暪燜懑懣们們甿虻莔萌盟蒙甍儚瞢蕄鄳鄸幪懞濛曚朦檬氋矇礞艨蘉霿饛鼆鸏勐猛瓾锰艋\
+        蜢錳懵蠓孟梦夢霥咪眯瞇冞弥祢迷猕谜謎醚彌糜縻麊麋禰靡瀰獼麛攠瓕蘼爢醾鸍米芈羋弭敉\
+        脒渳葞蔝銤濔灖冖糸汨沕宓泌觅峚祕秘密覓幂谧塓幎覛嘧榓滵漞熐蔤蜜鼏冪幦謐羃宀眠婂绵\
+        媔棉綿蝒櫋矊矏丏免沔黾勉眄娩偭冕渑喕愐湎缅葂絻腼黽緬澠鮸面麵喵苗媌描瞄鹋鱙杪眇秒\
+        淼渺缈篎緲藐邈妙庙玅廟乜咩灭覕搣滅蔑薎幭懱篾蠛衊鱴民岷忞怋旻旼苠珉罠崏缗敯痻鈱緡\
+        錉皿刡闵抿泯敃闽悯敏笢湣閔愍暋閩僶憫潣簢鳘蠠名明鸣洺茗冥眳铭鄍嫇溟蓂暝榠銘鳴瞑螟\
+        覭姳慏酩命詺谬謬摸谟嫫馍摹模膜麽摩磨糢謨饃蘑髍魔劘抹末妺歾歿殁沫茉陌枺秣莈莫眽粖\
+        蛨嗼塻寞漠蓦貊暯銆靺瘼瞙镆墨默瀎貘藦蟔鏌爅驀纆耱哞牟侔洠眸谋蛑缪鉾謀瞴繆鍪鴾麰某\
+        毪氁母亩牡坶姆峔牳畝砪鉧踇木仫目沐炑牧苜毣莯蚞钼募墓幕幙慔楘睦鉬慕暮艒霂穆縸鞪拇",
+    ),
+    (
+        'n',
+        "嗯拏拿挐镎哪那妠纳肭娜衲钠納捺笝豽軜貀鈉魶摨孻乃奶艿氖妳迺倷釢嬭奈柰耏耐萘渿鼐褦\
+        螚錼囡男南柟莮难喃暔楠諵難赧揇湳萳腩蝻戁婻囔譨囊蠰鬞馕擃曩攮灢齉孬呶怓挠峱硇铙猱\
+        蛲碙撓嶩蟯夒譊鐃垴恼脑惱瑙腦獶獿闹婥淖鬧臑疒讷眲訥吶呐呢娞馁腇餒內内氝恁嫩能妮尼\
+        坭怩泥倪屔秜郳铌婗淣猊跜蜺觬輗霓鲵鯓鯢麑齯臡你拟抳狔苨柅旎晲鈮馜儗隬擬薿檷伲迡昵\
+        逆匿堄惄嫟溺睨腻暱縌誽膩拈蔫年鲇鲶黏鯰涊捻淰跈辇辗撚撵碾輦簐蹍攆躎廿念姩唸埝鼰哖\
+        孃酿釀娘鸟茑袅鳥嫋裊蔦嬝褭嬲尿脲捏苶陧涅聂臬啮隉喦敜嗫嵲踂摰槷踗镊镍嶭篞臲颞蹑嚙\
+        聶鎳闑孽櫱蘖囁齧糱蠥鑈囓讘躡鑷顳钀巕您拰宁咛拧狞苧柠聍寍甯寧儜凝嚀嬣擰獰薴檸聹鑏\
+        鸋佞泞濘妞牛忸扭狃纽炄钮紐莥鈕农侬哝浓脓農儂噥濃膿穠襛醲繷弄齈羺耨獳檽鎒奴孥驽笯\
+        駑努弩砮胬怒女钕籹釹恧朒衄奻渜暖煖餪疟虐瘧挪梛傩儺橠诺喏逽搦锘諾糑懦懧糯",
+    ),
+    (
+        'o',
+        "喔噢哦讴沤欧殴瓯鸥漚歐毆熰甌櫙謳鏂鷗齵吘呕偶腢嘔耦蕅藕怄慪藲",
+    ),
+    (
+        'p',
+        "趴舥啪葩杷爬掱琶筢帊帕怕袙拍俳徘排棑牌簰犤哌派湃蒎鎃眅潘攀爿洀盘跘媻幋蒰搫槃盤磐\
+        縏磻蹒瀊蟠蹣鞶判沜拚泮叛牉盼畔袢詊溿頖襻乓雱滂庞厖逄旁舽徬螃龐嗙耪胖抛拋脬刨咆庖\
+        狍炰袍匏鞄麃跑奅泡炮疱皰砲麭呸怌柸胚衃醅陪培毰赔锫裴賠駍俖伂沛佩帔姵旆浿珮配辔霈\
+        轡喷噴歕盆湓葐呠翸匉怦抨恲砰烹軯閛漰嘭澎磞芃朋倗堋弸彭棚硼蓬鹏樥憉輣篣膨錋韸髼蟛\
+        鬅鵬鑝捧皏剻掽椪碰踫篷丕伓伾批纰邳坯披狉砒秠紕铍翍鈚鈹銔劈駓髬噼錍魾憵礔霹皮阰芘\
+        岯枇毞毗毘疲蚍郫陴啤埤崥蚽蚾琵脾罴膍蜱螷貔鵧羆鼙匹庀疋仳圮痞銢諀鴄擗癖嚭屁淠揊釽\
+        媲睥辟潎稫僻澼甓譬闢鷿偏媥犏篇翩鶣骈胼楄楩跰骿蹁駢谝貵諞片骗騙剽慓缥飘旚翲螵犥飄\
+        魒嫖瓢薸殍彯瞟篻縹醥皫顠票僄嘌漂氕撇瞥丿苤嫳姘拼礗驞玭贫貧嫔频頻嬪嚬矉蠙颦顰品榀\
+        牝聘乒甹俜娉涄砯艵竮頩平评凭呯坪泙苹郱屏帡枰洴玶荓瓶淜萍缾蓱蛢評軿鲆憑蘋钋坡岥泊\
+        颇頗鏺婆鄱皤謈櫇叵钷笸岶迫昢珀烞破釙粕蒪魄泼桲潑剖抔捊掊裒婄仆攴扑噗撲潽鯆匍莆脯\
+        菩葡蒱蒲僕酺墣獛璞濮瞨镤纀鏷朴圃浦烳普溥谱樸氆镨譜蹼鐠铺舖鋪瀑曝駇",
+    ),
+    (
+        'q',
+        "七迉沏妻柒倛凄栖桤郪娸悽桼淒萋攲期棲欺蛣僛嘁榿漆緀慼槭諆諿霋蹊魌鏚鶈亓祁齐圻岐岓\
+        忯芪其奇歧祇祈肵俟疧旂耆脐蚑蚔蚚颀埼崎掑淇畦萁跂軝釮骐骑棋琦琪祺蛴碕頎鬿旗綦蜞齊\
+        蕲踑錡懠濝藄臍騎騏鳍蘄鯕鵸鶀麒艩蠐鬐鰭玂麡乞邔企屺岂芑启呇杞豈起婍啟绮棨綮綺气讫\
+        忔汔迄弃汽芞泣盵咠契砌栔氣訖唭棄湆湇葺碛甈器憩磧磩罊蟿鼜戚掐葜酠冾帢恰洽殎硈愘髂\
+        千仟阡奷扦芊迁佥岍汧瓩钎牵粁兛悭铅婜牽掔谦鈆雃僉愆签鉛骞慳搴遷褰謙顅檶攐櫏簽孅攓\
+        騫籤韆仱岒忴扲拑前钤虔钱钳掮揵軡媊鈐靬鉗墘榩箝潛潜羬蕁錢黔黚騝騚灊鰬凵浅肷淺脥嗛\
+        遣槏蜸谴缱繾譴欠芡俔茜倩堑傔嵌棈椠慊蒨塹歉綪槧輤篟縴鰜呛羌戕戗斨枪跄椌腔嗆蜣锖嶈\
+        戧槍牄瑲锵錆謒蹌镪蹡鎗鏘丬強强墙嫱蔷樯漒墻嬙廧薔檣牆蘠抢羟搶羥墏繈襁鏹炝唴熗羻悄\
+        硗郻跷鄡劁敲毃踍锹墝頝骹墽幧橇燆缲磽鍬繑趬蹺鐰乔侨荍荞桥菬喬僑谯嘺嫶憔蕎鞒樵橋瞧\
+        礄趫巧愀髜俏诮峭帩窍翘誚撬撽鞘竅翹譙躈犞癿且切妾怯郄窃挈洯惬愜蛪朅箧緁锲篋踥藒鍥\
+        鯜鐑竊亲侵钦衾欽綅嶔親顉駸庈芩芹埁秦耹蚙菳琴禽鈙雂勤嗪嫀溱靲慬噙擒斳鳹檎澿瘽螓懃\
+        鬵坅昑笉梫寑锓寝寢鋟螼吣沁唚菣揿撳瀙藽狅青氢轻倾卿郬圊埥氫清傾蜻輕鲭鑋勍情殑晴氰\
+        葝擏樈擎檠黥苘顷请頃廎漀請庆凊掅碃箐靘慶磬罄謦芎邛穷穹茕桏笻筇赹惸琼舼蛩蛬煢跫銎\
+        瞏窮憌橩璚藑瓊藭瓗丘邱坵恘秋蚯媝萩楸蓲緧蝵趥鳅蟗鞦鰍鶖蠤囚犰求虬泅虯俅觓訄酋釓唒\
+        紌莍逑釚梂殏毬球赇崷巯盚遒煪絿蛷裘巰觩賕璆蝤銶鮂鼽鯄鰽糗区曲佉岖诎阹驱屈岨抾浀祛\
+        胠袪區紶蛆躯蛐詘趋嶇敺镼髷魼趨軀麴黢驅鱋佢劬斪朐胊菃鸲淭渠絇翑葋軥蕖磲螶鴝璩蟝瞿\
+        鼩蘧灈欋氍籧臞癯蠷衢躣蠼取竘娶蝺龋齲去刞呿阒觑趣麮闃覷鼁弮恮悛圈棬駩鐉全权佺诠姾\
+        泉荃拳牷辁埢婘惓痊铨犈筌絟瑔觠詮跧輇蜷銓踡縓醛鬈巏權齤蠸颧顴犬汱畎烇绻綣虇劝券牶\
+        韏勸犭缺蒛阙瘸却卻埆悫雀硞确阕塙搉皵碏愨榷確碻闋礐闕礭鹊鵲囷峮逡宭裙群",
+    ),
+    (
+        'r',
+        "呥袡蚺然髯燃冉姌苒染珃橪儴勷瀼獽蘘禳瓤穰躟鬤嚷壤攘爙纕让懹讓娆荛饶桡嬈蕘橈襓饒扰\
+        隢擾绕遶繞惹热熱人亻仁壬芢銋鵀忍荏栠荵棯稔刃认仞任屻纫妊牣肕轫韧饪紉衽紝訒軔軠腍\
+        葚韌飪認扔仍礽陾日鈤馹茸戎肜狨绒茙荣容毧烿媶嵘絨羢嫆榵溶蓉榕榮熔瑢蝾褣融駥髶嶸爃\
+        鎔巆瀜蠑冗傇軵氄厹禸柔媃揉葇煣糅蝚蹂輮鍒鞣騥鰇鶔粈楺韖肉如侞帤茹袽铷筎蒘銣蕠蝡儒\
+        嚅嬬孺濡薷鴽曘燸襦蠕颥醹汝乳辱鄏擩入洳嗕媷溽缛蓐褥縟挼堧撋壖阮朊软耎軟緛瓀礝婑桵\
+        緌蕤蕊橤繠汭芮枘蚋锐瑞睿銳叡闰润閏潤橍捼若偌弱鄀渃楉蒻箬篛爇鶸",
+    ),
+    (
+        's',
+        "仨挲撒洒靸灑躠卅泧飒脎萨鈒摋馺颯薩隡毢揌塞毸腮噻鳃鰓赛僿賽三叁毵毿鬖伞傘糁馓糝繖\
+        鏒鏾霰帴悷散閐桑嗓搡磉褬颡鎟顙丧喪慅搔溞骚缫繅臊鳋騷鱢扫掃嫂埽瘙氉矂色栜涩啬铯嗇\
+        瑟銫濇穑澀璱穡轖鏼譅飋濏森槮襂僧鬙杀沙纱刹剎砂唦殺猀紗莎铩痧硰煞蔱裟樧魦鲨鎩鯊傻\
+        唼啑啥萐厦喢廈歃翜箑翣霎繌筛酾篩簁釃繺晒閷曬山彡删刪杉芟姍姗苫衫钐埏挻柵狦珊舢痁\
+        笘跚剼搧幓煽潸縿膻羶闪陕陝閃晱煔睒覢讪汕疝剡扇訕赸掞釤善骟鄯墠墡潬缮嬗擅樿膳謆赡\
+        繕蟮蟺贍騸鳝灗鱔伤殇商觞傷墒漡蔏殤熵螪觴謪鬺垧晌赏賞上尚恦绱鞝仩裳弰捎烧莦梢稍旓\
+        筲艄蛸輎燒髾鮹勺芍苕玿韶少劭卲邵绍哨袑紹睄潲蕱奢猞赊畬畲輋賒檨舌佘蛇舍捨厍设社厙\
+        射涉涻設赦慑摄滠慴摵蔎歙蠂韘騇懾攝灄麝申屾伸身侁呻妽绅诜姺柛氠珅籸娠峷甡眒砷莘深\
+        紳兟棽葠詵蔘燊駪什甚神邥审哂矧谂婶渖審諗曋瞫嬸瀋覾讅肾侺胂渗祳脤腎慎椹罧蜃蜄滲鋠\
+        堔升生呏声昇泩狌牲陞笙湦焺甥鉎聲鼪鵿绳憴繩譝省眚偗渻圣胜盛剩勝貹嵊聖賸尸失师虱诗\
+        邿屍施狮師絁湤湿葹溮溼獅蓍詩鉈鳲蝨褷鲺濕鰤鶳襹十饣石时实姼炻祏蚀食埘時莳寔湜塒溡\
+        蒔鉐實榯蝕鲥鼫鼭鰣史矢豕使始驶屎駛士氏礻世仕市示似式忕戺事侍势视试饰室恀恃拭是柿\
+        贳适栻烒舐轼逝铈視豉媞崼弑徥揓谥貰释勢嗜弒筮觢試軾鈰飾誓適鉽奭銴噬澨諟諡遾螫謚簭\
+        襫釋识拾匙識收手守首艏寿受狩兽售授绶壽瘦綬獸扌书殳抒纾叔杸枢陎姝倏書殊紓梳淑焂菽\
+        軗鄃疏舒摅毹綀输瑹跾樞蔬輸橾鮛儵攄鵨秫婌孰赎塾熟贖属暑黍署蜀鼠潻薯曙癙藷襡襩屬钃\
+        朮术戍束沭述咰怷树竖恕庶術隃尌裋数腧鉥墅漱數澍豎樹鏣鶐虪糬刷唰耍衰摔甩帅帥蟀闩拴\
+        閂栓涮双霜雙孀孇礵鷞艭驦爽塽慡漺樉縔灀谁脽誰水帨涗稅税裞睡氵吮顺舜順蕣瞚瞬鬊说說\
+        妁烁朔铄欶硕搠蒴槊獡碩箾鎙爍鑠厶纟丝司私咝泀思虒鸶斯絲缌蛳楒禗鉰凘厮榹禠罳蜤锶嘶\
+        廝撕澌磃緦蕬螄蟴颸鷥鼶死巳四寺汜兕姒祀泗饲驷柶洍涘肂笥耜覗嗣肆貄鈶飼駟蕼儩俬忪松\
+        娀倯凇崧淞菘嵩硹蜙濍鍶鬆怂悚耸竦傱愯嵷慫聳駷讼宋诵送颂訟頌誦鄋嗖廋搜溲獀蒐馊飕锼\
+        艘螋醙鎪餿颼颾騪叟嗾瞍擞薮擻藪籔嗽苏甦酥稣窣穌蘇櫯囌俗玊夙泝肃洬涑素莤速宿梀傃粟\
+        谡嗉塑嫊愫溯肅僳愬榡膆蔌觫憟樕鋉餗潚縤橚簌藗謖蹜驌鱐鷫诉訴狻痠酸匴筭蒜算攵虽倠哸\
+        浽荽荾眭葰滖睢綏濉雖绥隋随隨瀡髓岁祟谇埣遂歲睟碎隧誶賥檖燧璲禭檅穗穟繀襚邃旞繐繸\
+        鐆鐩孙狲荪孫飧搎猻蓀槂蕵薞损笋隼筍損榫簨鎨唆娑莏傞桫梭睃嗍羧蓑摍缩趖簑縮所唢索琐\
+        惢锁嗩瑣璅鎈鎍鎖逤溹嗦",
+    ),
+    (
+        't',
+        "他它她牠祂趿铊塌榙溻褟嚃塔獭鳎獺鰨拓挞闼涾搨遝遢榻毾禢撻誻踏錔濌蹋鞜闒鞳闥胎台旲\
+        邰抬苔炱跆鲐箈臺颱駘儓鮐嬯薹檯籉太汰态肽钛泰酞鈦溙態燤坍抩贪怹痑舑貪摊滩瘫攤灘癱\
+        坛昙倓谈郯婒惔覃榃痰锬谭憛潭談壇曇燂錟餤檀磹顃罈藫譚貚醰譠忐坦袒钽菼毯鉭嗿憳醓襢\
+        叹炭埮探傝湠僋嘆碳舕歎賧汤铴湯耥羰蝪薚镗蹚鏜鐋鼞饧唐堂棠塘搪溏蓎榶漟煻瑭樘磄膛橖\
+        糖螗踼螳赯醣餳鎕闛鶶帑倘淌傥躺鎲儻戃曭爣矘钂烫摥趟燙弢涛绦掏絛嫍幍慆搯滔槄韬縚濤\
+        韜饕匋咷洮逃桃陶啕淘萄祹綯蜪鞀醄鋾錭駣檮騊鼗讨討套忑忒特貣铽慝鋱螣蟘熥疼痋腾誊滕\
+        邆縢謄藤騰籐霯剔梯锑踢擿鷈厗荑绨偍啼崹惿提稊缇鹈綈褆徲緹蕛蝭銻题趧蹄醍謕鍗題鵜騠\
+        鯷鶗鶙鷤体體剃洟倜悌涕逖悐惕揥替楴裼褅殢薙嚏瓋籊趯屉屜天婖添酟田屇沺恬畋盷甜菾湉\
+        填搷鈿阗緂磌窴闐鷏忝殄倎悿淟晪琠腆痶舔餂賟錪靦掭睼佻庣恌挑祧芀条岧迢祒條笤蓨趒龆\
+        蜩鞗髫鲦鯈鎥齠鰷宨朓脁窕誂斢窱嬥眺粜絩覜跳糶帖怗贴萜聑貼铁蛈僣銕鋨鴩鐵驖呫餮厅汀\
+        艼听町烃桯烴綎聽廳廷亭庭莛停婷嵉渟筳葶蜓楟榳閮霆聤蝏鼮圢侹娗挺梃烶珽脡艇頲炵通痌\
+        嗵蓪仝同佟彤哃狪茼桐浵烔砼铜童粡筩詷赨酮鉖僮銅餇潼獞曈朣橦氃犝膧瞳鮦统捅桶筒統恸\
+        痛衕慟偷媮鋀亠头投骰緰頭妵钭紏敨黈蘣透凸禿秃怢突唋涋捸湥葖嶀鵚鼵图峹徒悇捈荼途屠\
+        梌菟稌塗嵞瘏筡腯蒤圖廜潳跿酴鍎駼鵌鶟鷋鷵土吐钍釷兔堍鵵涂湍煓貒团抟剸團慱摶漙槫篿\
+        鏄糰鷒鷻疃彖褖推蓷藬弚颓隤頹魋穨蹪俀腿僓侻退煺蛻蜕褪駾吞涒啍焞暾屯坉忳芚饨豚軘飩\
+        魨臀氽畽旽乇托扡侂拖沰挩莌託脫脱飥魠驮佗陀坨岮沱沲砣袉鸵紽堶跎酡碢馱橐鮀鴕鼍驒鼉\
+        妥庹椭嫷橢鬌柝毤唾跅毻箨蘀籜驼駝",
+    ),
+    (
+        'w',
+        "穵挖洼娲窊媧蛙溛漥窪娃瓦佤袜嗢腽膃襪哇歪喎崴外弯剜婠湾蜿潫豌彎灣丸刓汍纨芄完岏抏\
+        玩紈捖顽烷頑翫宛倇挽婉惋晚绾脘菀晼琬皖畹睕碗綩綰輓踠鋄万卍妧忨捥脕萬腕輐澫薍蟃尪\
+        汪亡王莣网往罔惘菵暀辋網輞瀇魍妄忘迋旺盳望朢枉危威烓偎萎逶隇隈愄揋渨葨葳微椳溦煨\
+        詴蜲蝛覣薇燰巍囗韦圩围帏沩违闱峗峞洈韋桅涠唯帷惟维喡圍嵬幃湋溈違潍維蓶鄬潿磑醀濰\
+        鍏闈鮠癓覹犩霺欈伟伪尾纬芛苇委炜玮洧娓浘诿偉偽崣痏硊徫猥葦蒍骫暐椲煒瑋痿腲艉韪撱\
+        磈鲔寪緯諉踓頠薳儰濻鍡鮪壝瀢韙韡蘤斖卫为未位味為畏胃軎尉菋谓喂媦渭煟碨蔚蜼慰犚衛\
+        罻謂餧鮇褽餵魏藯鏏霨蘶饖讆躗猬蝟塭温殟溫瘟豱轀文纹芠炆玟闻紋蚊阌琝雯聞魰鳼閺閿闅\
+        鼤刎吻抆呡紊桽稳穩问妏汶問搵顐璺翁嗡滃螉鶲塕蓊暡瞈聬瓮蕹甕罋齆挝倭涡莴渦猧萵窝窩\
+        蜗撾蝸踒我婐捰仴沃肟卧臥偓涴幄握渥焥硪腛斡瞃擭濣瓁臒雘龌齷乌圬汙污邬呜巫杇屋洿诬\
+        钨烏剭窏鄔嗚歍誣螐鴮鎢无毋吳吴吾芜郚唔洖浯莁梧珸無蜈蕪鯃譕鼯鷡五午仵妩庑忤怃武玝\
+        侮俉倵捂啎牾摀碔鹉舞嫵廡憮潕橆甒鵡躌兀勿戊阢屼扤坞岉杌芴迕物矹卼误悟粅逜晤焐婺痦\
+        靰骛塢嵨雺雾寤誤鹜遻鋈霧齀蘁騖鶩务伍務",
+    ),
+    (
+        'x',
+        "夕兮吸扱汐希昔析穸肸俙徆恓郗唏奚屖悕氥浠牺狶莃悉惜晞桸欷淅烯琋硒菥釸傒惁晰焟犀睎\
+        稀粞翕舾鄎徯溪皙蒠锡僖榽熄熙緆蜥豨嘻嬉嶲潝瘜磎膝凞樨橀熹窸羲螅螇錫燨蟋谿豯貕雟鵗\
+        譆醯巇曦爔犧酅鼷蠵鸂觿鑴习郋席習袭觋媳蓆嶍覡槢薂隰檄謵鎴霫飁騱騽襲鰼驨枲洗玺徙铣\
+        喜葸屣漇蓰歖禧諰縰謑蟢蹝璽囍卌戏系饩呬忥怬矽细係咥恄盻郤欯細釳阋舄隙慀滊禊綌赩熂\
+        潟蕮覤黖戲虩餼鬩繫闟屭衋西息礂虾煆颬瞎蝦匣侠狎俠峡柙狭陜峽烚狹珨祫硖舺硤遐暇瑕舝\
+        碬辖磍蕸縖赮魻轄鍜霞黠騢鶷閜下吓夏嚇罅鏬仚屳先奾纤佡忺氙杴祆秈枮籼珗莶掀跹酰锨僊\
+        嘕銛鲜暹嬐憸薟褼韱鮮馦廯攕鶱襳躚纖伭闲妶弦贤咸唌涎胘娴娹絃舷蚿衔啣痫蛝閑閒鹇嫌銜\
+        嫻憪撏澖誸賢燅諴癇礥鹹麙鷳鷴冼显险毨烍猃蚬赻筅尟搟禒跣銑險嶮獫獮藓鍌燹幰攇蘚譣玁\
+        韅顯灦县岘苋现线限姭宪娊峴涀莧陷晛現硍馅睍羡献粯羨腺蜆僩撊線鋧憲縣錎餡壏豏瀗獻糮\
+        鼸仙乡芗相香厢鄉廂湘缃葙薌箱緗膷襄忀骧欀瓖镶鑲驤瓨详庠祥翔詳享响饷晑飨想餉鲞曏蠁\
+        鯗響饗饟向姠巷项象缿萫衖項像銗橡襐嚮蟓闀鐌鱌灱呺枭哓枵骁哮宵庨消绡虓逍梟猇萧痚痟\
+        硝窙翛萷销揱綃嘐歊潇箫踃嘵憢獢銷霄膮蕭魈鴞穘藃蟂嚣瀟簫髇櫹囂髐蠨驍毊虈洨笅郩崤淆\
+        殽筊小晓筱皛曉篠謏孝肖效校涍笑啸傚詨嘯熽些揳猲楔歇蝎蠍劦协邪協胁垥奊拹挟挾脅偕斜\
+        谐嗋愶携瑎熁勰撷緳缬蝢鞋頡諧燲擷襭攜纈龤写寫伳灺泄泻祄绁卸洩屑偞偰械焎紲亵媟渫絏\
+        谢僁榍榭褉屧嶰廨懈澥獬薢薤邂韰燮褻謝駴瀉鞢瀣繲蟹齘齛齥齂躞心妡忻芯辛昕杺欣炘盺訢\
+        鈊锌新歆廞鋅薪馨鑫馫枔襑鐔伈阠囟信衅焮煡舋釁忄星垶惺猩煋瑆腥蛵箵觲騂刑行邢形陉侀\
+        型洐荥陘娙硎鈃滎鉶鋞醒擤兴杏姓幸性荇倖莕婞悻涬興凶兄兇匈忷汹哅恟洶胸雄熊詗敻休修\
+        咻庥烋羞脩脙鸺臹貅馐樇銝髹鎀鵂饈苬朽滫糔秀岫珛绣袖琇锈嗅溴褎螑繡鏽吁戌旴盱欨胥须\
+        晇訏顼虚幁揟湑虛須楈窢頊嘘墟需魆噓嬃歔縃蕦蝑諝繻魖驉鑐鬚徐许呴姁诩冔栩珝許暊詡稰\
+        鄦糈醑旭序芧侐卹怴沀叙恤昫洫垿殈烅勖敘勗烼绪续酗喣婿溆絮訹慉煦蓄漵瞁緒銊獝藇瞲藚\
+        續鱮蓿轩昍宣軒梋谖喧媗愃愋揎萱萲暄煊瑄蓒儇禤蝖鋗懁諠諼鍹駽矎翾蠉玄玹痃悬旋琁蜁嫙\
+        漩璇璿懸咺选晅烜選顈癣癬泫昡炫绚眩袨铉琄眴衒渲絢楥楦鉉碹蔙镟鞙縼鏇讂贙削疶蒆靴薛\
+        穴学岤泶袕踅壆學嶨澩燢觷鷽雪鳕鱈血吷狘谑趐謔勋埙焄勛塤熏窨蔒勳壎獯薰曛燻臐矄纁醺\
+        寻旬巡驯询峋恂洵浔紃荀荨栒珣尋循揗槆潃詢馴鄩鲟噚潯攳燖璕蟳鱘灥讯汛迅侚徇迿逊殉訊\
+        巽稄遜愻噀潠蕈训訓",
+    ),
+    (
+        'y',
+        "丫压吖押枒垭鸦桠鸭埡孲椏鴉錏鴨壓牙伢岈芽玡琊蚜堐崖涯睚衙齖厊庌哑啞痖雅蕥圠轧亚襾\
+        讶犽迓亞軋娅砑氩婭掗訝揠氬猰聐圔窫齾呀恹烟珚胭偣崦淊淹焉菸阉湮猒腌煙鄢嫣漹醃閹嬮\
+        懨臙黫讠延严妍芫言岩沿炎郔狿研莚娮盐閆阎嵒筵綖蜒揅楌碞颜虤閻檐顏嚴壛簷壧孍巖礹鹽\
+        抁沇兖奄俨兗匽弇衍偃厣掩眼萒郾酓嵃愝扊揜棪渰琰隒椼罨裺演褗戭蝘魇噞躽縯厴甗鰋鶠黤\
+        齞龑儼黭顩鼴巘曮魘鼹齴黶厌闫牪咽姲彥彦砚唁宴晏烻艳验谚喭堰敥焰焱硯雁傿椻溎滟厭酽\
+        谳餍鴈燄燕諺赝鬳鴳酀嚥嬿曣爓騴鷃贗觾讌醼饜驗鷰艷釅驠讞豔灩央姎抰泱殃胦秧鸯鉠雵鞅\
+        鴦扬羊阳杨炀佯疡垟徉昜洋烊珜眻陽崵崸揚蛘暘楊煬禓瘍鍚颺鐊鸉仰佒坱岟养柍炴氧痒紻軮\
+        蝆養懩攁癢怏恙样羕漾樣瀁幺夭吆妖殀祅訞喓葽腰邀爻尧肴垚姚轺烑珧窑傜堯谣軺嗂媱徭愮\
+        搖摇猺遙遥榣瑤瑶銚嶢窯餚繇謠鳐颻顤鰩宎岆抭杳苭咬柼眑窅窈舀偠崾溔蓔榚鴢騕鷕穾药要\
+        钥袎窔筄葯詏覞靿獟鹞曜燿艞藥耀鷂鑰掖椰暍噎蠮耶揶铘擨也冶埜野漜业叶曳页邺夜抴頁晔\
+        烨液谒堨殗腋葉楪業曄歋燁擛瞱鄴靥嶪澲謁擫鍱鎑饁鐷靨爷爺一伊衣医依咿洢猗郼铱壹揖欹\
+        蛜禕嫛漪稦銥噫瑿繄檹毉醫黟鷖黳仪匜圯夷迆沂诒侇怡沶狋衪迤饴咦姨峓恞拸柂珆瓵贻迻宧\
+        栘桋眙胰袘訑貤痍移耛萓羠蛦詑詒貽遗媐暆椸誃跠颐飴疑儀熪箷遺嶬螔頤寲嶷簃顊彝謻鏔觺\
+        鸃乙已以钇佁矣苡舣蚁釔倚扆酏偯崺椅鉯鳦旖踦敼螘檥礒艤蟻顗轙齮乂义亿弋刈忆艺肊议亦\
+        屹异芅伿佚劮呓役抑杙耴译邑佾峄怿易枍欥泆绎诣驿俋奕帟帠弈枻浂玴疫羿衵轶唈垼悒挹捙\
+        栺欭浥益谊陭埶埸掜異羛翊翌逸釴隿晹殔焲詍跇軼亄意溢睪缢義肄裔裛詣勩嫕廙潩瘗膉蓺蜴\
+        靾億槸毅熠熤熼瘞誼镒黓劓圛墿嶧憶懌曀殪澺燚瘱縊艗薏寱斁檍燡燱翳翼臆賹鮨癔藙藝贀鎰\
+        镱繶繹豷霬鶂瀷蘙譯議醳醷饐囈鐿鷁鷊懿襼驛鷾齸衤宜因阴垔姻洇茵荫音殷氤陰凐裀铟喑堙\
+        愔絪歅禋蔭慇摿瘖銦諲霒駰噾闉霠韾冘吟苂烎垠圁狺荶婬寅崟淫银鄞夤蔩銀殥誾嚚檭蟫霪齗\
+        鷣廴尹引吲饮蚓隐鈏飲靷朄輑趛檃瘾隱濦螾蘟癮讔印茚胤垽堷湚猌廕蒑酳憖粌应英偀莺婴渶\
+        朠煐瑛嫈碤嘤撄甇缨罂蝧賏樱璎罃褮霙鹦嬰應膺韺甖鹰鶧嚶攖罌蘡櫻瓔礯譻鶯纓蠳鷹鸚迎茔\
+        盈荧莹萤营萦塋楹滢蓥潆熒瑩嬴營縈螢濴藀覮謍赢瀅鎣攍瀛瀠瀯瀴贏籯矨郢浧梬颍颖摬影潁\
+        瘿穎廮癭映硬媵鱦蝇蠅哟唷喲佣拥痈邕庸傭嗈鄘雍墉嫞慵滽槦噰壅擁澭郺镛臃雝鏞鳙廱灉饔\
+        鷛癰喁揘顒鰫永甬咏泳俑勇埇悀涌恿傛湧詠塎嵱蛹踊禜踴用醟优忧攸呦怮泑幽逌悠麀憂優鄾\
+        嚘瀀櫌纋耰尢尤由沋犹邮油斿疣浟秞莜莸郵铀偤蚰訧游猶遊鱿楢猷鈾蕕蝣魷輶有卣苃酉羑庮\
+        栯莠聈铕銪槱牖黝懮又右幼佑侑狖囿姷宥峟柚牰祐诱迶蚴貁釉誘鼬友蒏扜纡迂穻陓紆唹淤盓\
+        瘀箊于邘余妤杅欤玗於盂臾衧鱼俞禺竽舁娛娱狳谀馀渔萸隅雩魚堣堬崳嵎嵛愉揄楰渝湡硢腴\
+        逾愚榆歈牏瑜艅虞觎漁睮窬舆褕歶羭蕍蝓諛雓餘澞覦踰歟璵螸輿謣鮽旟籅蘛鰅鸆与予伛宇屿\
+        羽雨俁俣禹语圄峿祤偊圉庾敔鄅斞萭傴寙楀瑀瘐與語窳鋙頨龉噳嶼貐斔麌蘌齬肀玉驭聿芋妪\
+        饫育郁昱狱峪彧浴砡钰预域堉悆欲淢淯谕阈喻寓御棜棫矞裕遇飫馭鹆愈煜稢罭蒮蓣誉鈺預嫗\
+        戫毓獄瘉緎蜮輍噊慾潏蓹薁豫遹鋊鳿澦燏燠蕷諭錥閾鴥儥禦魊鹬癒礜篽繘醧鵒饇譽轝驈鬻鱊\
+        鷸鸒軉鬱灪籲爩鸢冤悁眢鸳渊惌淵葾棩蒬蜎裷箢鳶蜵駌鴛嬽鵷灁鼘元邧员园沅杬垣爰原員圆\
+        笎蚖袁援湲缘鼋園圓塬媴嫄源溒猿獂蒝榞榬辕緣蝝蝯橼羱螈謜轅黿鎱櫞邍騵鶢远遠鋺夗肙妴\
+        苑怨院垸媛掾瑗禐愿裫褑噮願曰约約箹矱月戉刖抈礿岳玥悅悦蚎軏钺阅跃粤越鈅粵鉞閱樾嶽\
+        龠瀹蘥黦爚禴躍籥鸑鸙晕暈氲氳奫蝹縕頵馧贇云勻匀妘沄纭芸昀畇眃郧紜耘鄖雲溳筠熉澐蕓\
+        鋆篔縜允阭抎狁陨荺殒鈗隕殞褞馻賱霣孕运枟郓恽鄆酝惲愠運慍韫韵熅熨緷蕴薀醞餫韗韞蘊\
+        韻",
+    ),
+    (
+        'z',
+        "匝咂拶紮鉔臢杂砸偺喒磼雜囋雥咋災灾甾哉栽菑渽賳宰崽再在载載酨縡糌簪鐕咱昝寁揝噆攒\
+        儹攢趱礸趲暂暫赞錾蹔贊鏨瓒酇灒瓚禶讚饡牂赃臧贓髒驵駔奘脏葬臟傮遭糟蹧凿鑿早枣蚤棗\
+        澡璪繰藻灶皁皂唣造喿慥噪簉燥譟趮躁则择泽责迮則唶啧帻笮舴責矠嘖幘箦樍赜擇澤簀襗謮\
+        賾蠌齰鸅仄庂昃捑崱伬贼賊鯽蠈怎谮譖鄫增憎缯橧璔矰磳罾繒锃甑赠贈扎吒抯奓挓柤哳喳揸\
+        渣楂劄樝觰皻譇齄齇札闸蚻铡煠閘鍘譗厏苲眨砟鮓乍诈咤柞栅炸痄蚱溠詐搾榨霅醡捚斋摘齋\
+        宅檡窄债砦債寨瘵沾毡旃栴粘蛅惉詀詹谵薝邅霑氈瞻譫饘驙魙鱣鸇斩展盏崭斬琖搌盞嶄榐颭\
+        嫸醆橏輾黵占佔战栈站偡绽棧湛綻輚戰虥轏譧蘸驏张張章傽鄣墇嫜彰慞漳獐粻遧暲樟璋蟑騿\
+        鱆仉长長涨掌漲丈仗扙帐杖胀账帳脹障嶂幛賬瘴瞕粀钊妱招昭盄釗啁鉊駋鍣皽爪找沼瑵召兆\
+        诏垗炤狣赵笊旐棹詔照罩肇趙曌鮡櫂蜇嫬遮折矺砓虴哲粍悊晢辄蛰谪馲摺輒磔辙蟄謫謺鮿轍\
+        讋者锗赭褶这柘浙這淛潪鹧蟅鷓着著蔗贞针侦浈珍胗貞桢真砧祯針偵桭寊葴遉斟楨獉甄禎蓁\
+        榛瑧碪禛潧箴樼臻薽轃鍼籈鱵诊抮枕轸畛疹眕袗紾裖診軫絼缜稹駗縥鬒黰圳阵甽侲挋陣鸩振\
+        朕栚紖眹赈酖揕敶瑱誫賑镇震鴆鎮争姃征怔爭诤埩峥挣炡狰烝眐钲崝崢掙猙睁聇铮媜筝蒸睜\
+        鉦箏錚徵篜鯖癥氶糽拯掟晸撜整正证郑帧政症幀証塣諍鄭證之支卮汁芝吱汥枝泜知织肢栀祗\
+        秖胑胝衼秪脂隻梔戠椥臸搘禔稙榰蜘鳷織蘵执侄直姪值埴執淔职植殖犆絷褁跖瓡摭馽嬂慹踯\
+        樴膱縶職蟙蹠躑夂止只旨阯址坁扺汦沚纸芷祉咫恉指枳衹轵淽疻紙趾軹黹酯藢阤至志忮扻豸\
+        制厔帙帜治炙质迣郅峙庢庤挃柣栉洷祑陟挚晊桎狾秩致袟贽轾乿偫掷楖猘畤痔秷窒紩翐鸷傂\
+        彘智滞痣蛭骘寘廌滍稚置跱輊雉墆滯疐製覟誌銍幟摯膣觯質踬鋕擳瀄緻駤鴙懥擲櫛螲懫贄櫍\
+        觶騭礩騺躓鷙鑕謢中伀妐彸忠炂终柊盅钟舯衷終蔠锺螤螽鍾鼨鐘籦肿种冢尰塚煄腫種踵穜仲\
+        众狆祌衶重偅眾堹州舟诌侜周洲珘徟掫淍週喌粥輈銂賙輖盩謅鵃騆譸妯轴軸肘睭鯞纣咒宙绉\
+        冑咮昼紂胄荮皱酎晝甃詶僽皺駎噣縐骤籀驟帚朱侏诛邾洙茱株珠诸猪硃袾铢絑蛛誅跦槠潴蝫\
+        銖橥諸豬鮢鴸瀦櫫櫧蠩竹泏竺笁茿烛窋逐舳瘃築燭蠋躅鱁孎灟欘蠾丶主拄罜陼渚煮嘱麈瞩囑\
+        斸矚伫佇住助苎杼注贮驻壴柱殶炷祝疰眝砫祩莇紵紸羜蛀嵀筑註貯跓軴铸鉒馵箸翥樦駐篫麆\
+        鑄抓檛膼簻髽拽跩专砖專鄟塼嫥瑼膞颛磚諯蟤顓鱄转轉啭瑑腞僎赚撰篆馔篹賺譔饌囀妆庄妝\
+        桩莊梉湷装裝樁壮壯状狀焋撞戇隹追骓锥錐騅鵻沝坠娷惴甀缒畷硾膇墜赘縋諈醊錣餟贅轛鑆\
+        缀綴宒迍肫窀谆諄准埻準綧訰稕卓拙倬捉桌涿棳穛穱蠿圴彴汋灼茁斫浊浞诼酌啄啅梲椓罬斲\
+        禚諑濁篧擢斀濯櫡謶镯鵫灂蠗鐲籗鷟孜兹咨姿茲栥玆紎赀资淄秶缁谘孳嵫椔滋粢辎鄑觜訾貲\
+        資趑锱緇鈭龇輜鼒澬諮錙髭鲻鎡璾鯔鶅齍蓻仔吇姊杍矷胏呰秭籽耔笫梓釨紫滓訿字自芓倳剚\
+        恣牸渍眥眦胔胾漬子崰宗倧综嵕惾棕猣腙朡稯綜熧翪蝬踪鍐豵蹤鬃騣鬷总偬傯蓗摠總纵昮粽\
+        瘲錝縱邹驺诹郰陬菆棷棸鄒緅諏鄹鲰鯫黀騶齱齺走奏揍楱租葅蒩足卒哫崒族傶箤踤踿镞鏃诅\
+        阻组俎珇祖組詛靻钻躜鑽缵纂籫纘攥厜朘嗺纗嶊嘴嶵噿璻絊最晬祽罪蕞醉檇鋷檌尊墫壿嶟遵\
+        樽繜鐏鳟鱒鷷僔噂撙譐捘銌昨莋捽椊琢稓筰左佐繓作坐阼岝怍侳祚胙唑座做葃葄",
+    ),
+];
+
+/// Additional pinyin initials of polyphonic characters.
+pub(crate) const EXTRA_INITIALS: &[(char, char)] = &[
+    ('长', 'c'),
+    ('重', 'c'),
+    ('行', 'h'),
+    ('乐', 'y'),
+    ('传', 'z'),
+    ('藏', 'z'),
+    ('朝', 'z'),
+    ('调', 't'),
+    ('会', 'k'),
+    ('给', 'j'),
+    ('降', 'x'),
+    ('弹', 't'),
+    ('便', 'p'),
+    ('省', 'x'),
+    ('单', 's'),
+    ('单', 'c'),
+    ('解', 'x'),
+    ('曾', 'z'),
+    ('仇', 'q'),
+    ('折', 's'),
+    ('觉', 'j'),
+    ('校', 'j'),
+    ('率', 's'),
+    ('参', 's'),
+    ('识', 'z'),
+    ('炮', 'b'),
+    ('种', 'c'),
+    ('系', 'j'),
+    ('查', 'z'),
+    ('车', 'j'),
+    ('区', 'o'),
+    ('尉', 'y'),
+    ('沈', 's'),
+    ('盛', 'c'),
+    ('宿', 'x'),
+    ('朴', 'p'),
+    ('翟', 'z'),
+    ('秘', 'b'),
+    ('厦', 'x'),
+    ('卡', 'q'),
+    ('壳', 'q'),
+    ('强', 'j'),
+    ('奇', 'j'),
+    ('吓', 'h'),
+    ('長', 'c'),
+    ('重', 'c'),
+    ('樂', 'y'),
+    ('傳', 'z'),
+    ('調', 't'),
+    ('會', 'k'),
+    ('給', 'j'),
+    ('彈', 't'),
+    ('單', 's'),
+    ('單', 'c'),
+    ('覺', 'j'),
+    ('識', 'z'),
+    ('種', 'c'),
+    ('車', 'j'),
+    ('區', 'o'),
+];
@@ -3,7 +3,7 @@
 use crate::Result;
 use sha2::{Digest, Sha256};
 use std::fs::File;
-use std::io::{BufReader, Read};
+use std::io::{BufReader, Read, Seek, SeekFrom};
 use std::path::Path;
 
 /// Calculate SHA256 hash of a file.
@@ -25,6 +25,39 @@ pub fn sha256_file(path: &Path) -> Result<String> {
     Ok(format!("{:x}", result))
 }
 
+/// Size of each chunk read by [`partial_hash`].
+pub const PARTIAL_CHUNK_SIZE: u64 = 64 * 1024;
+
+/// Fast SHA256 over the file size and its head, middle and tail chunks.
+///
+/// Files with different partial hashes differ; equal partial hashes must be
+/// confirmed with [`sha256_file`]. Small files are hashed whole.
+pub fn partial_hash(path: &Path) -> Result<String> {
+    let mut file = File::open(path)?;
+    let size = file.metadata()?.len();
+    let mut hasher = Sha256::new();
+    hasher.update(size.to_le_bytes());
+
+    let (offsets, chunk_len) = if size <= PARTIAL_CHUNK_SIZE * 3 {
+        (vec![0], size)
+    } else {
+        let middle = size / 2 - PARTIAL_CHUNK_SIZE / 2;
+        (
+            vec![0, middle, size - PARTIAL_CHUNK_SIZE],
+            PARTIAL_CHUNK_SIZE,
+        )
+    };
+
+    let mut buffer = vec![0u8; chunk_len as usize];
+    for offset in offsets {
+        file.seek(SeekFrom::Start(offset))?;
+        file.read_exact(&mut buffer)?;
+        hasher.update(&buffer);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
 /// Calculate SHA256 hash of a string.
 pub fn sha256_string(s: &str) -> String {
     let mut hasher = Sha256::new();
@@ -37,6 +70,28 @@ pub fn sha256_string(s: &str) -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_partial_hash() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let big: Vec<u8> = (0..PARTIAL_CHUNK_SIZE * 5)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let a = dir.path().join("a.mkv");
+        let b = dir.path().join("b.mkv");
+        std::fs::write(&a, &big).unwrap();
+        // Differs only between the sampled chunks
+        let mut unsampled = big.clone();
+        unsampled[(PARTIAL_CHUNK_SIZE + 10) as usize] ^= 0xff;
+        std::fs::write(&b, &unsampled).unwrap();
+
+        assert_eq!(partial_hash(&a).unwrap(), partial_hash(&b).unwrap());
+        assert_ne!(sha256_file(&a).unwrap(), sha256_file(&b).unwrap());
+
+        std::fs::write(&b, b"small").unwrap();
+        assert_ne!(partial_hash(&a).unwrap(), partial_hash(&b).unwrap());
+        assert_eq!(partial_hash(&b).unwrap(), partial_hash(&b).unwrap());
+    }
+
     #[test]
     fn test_sha256_string() {
         let hash = sha256_string("hello world");
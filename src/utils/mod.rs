@@ -1,5 +1,6 @@
 //! Utility modules.
 
 pub mod chinese;
+mod chinese_data;
 pub mod fs;
 pub mod hash;
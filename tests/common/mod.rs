@@ -0,0 +1,78 @@
+//! Shared helpers for integration tests.
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+/// A request captured by [`start_http_stub`].
+#[derive(Debug, Clone)]
+pub struct CapturedRequest {
+    /// Request line, e.g. "POST /hook HTTP/1.1".
+    pub request_line: String,
+    /// Header lines (name: value), as received.
+    pub headers: Vec<String>,
+    /// Request body.
+    pub body: String,
+}
+
+/// Start a minimal HTTP server that answers every request with `status` and
+/// forwards the captured requests. Returns the base URL and the receiver.
+pub async fn start_http_stub(status: u16) -> (String, mpsc::UnboundedReceiver<CapturedRequest>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let mut buf = Vec::new();
+                let mut chunk = [0u8; 4096];
+                // Read headers, then as much body as Content-Length announces
+                let (head_len, content_length) = loop {
+                    let n = stream.read(&mut chunk).await.unwrap_or(0);
+                    if n == 0 {
+                        return;
+                    }
+                    buf.extend_from_slice(&chunk[..n]);
+                    if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                        let head = String::from_utf8_lossy(&buf[..pos]).to_string();
+                        let length = head
+                            .lines()
+                            .filter_map(|l| l.split_once(':'))
+                            .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+                            .and_then(|(_, v)| v.trim().parse::<usize>().ok())
+                            .unwrap_or(0);
+                        break (pos + 4, length);
+                    }
+                };
+                while buf.len() < head_len + content_length {
+                    let n = stream.read(&mut chunk).await.unwrap_or(0);
+                    if n == 0 {
+                        break;
+                    }
+                    buf.extend_from_slice(&chunk[..n]);
+                }
+
+                let head = String::from_utf8_lossy(&buf[..head_len - 4]).to_string();
+                let mut lines = head.lines();
+                let request_line = lines.next().unwrap_or_default().to_string();
+                let headers = lines.map(|l| l.to_string()).collect();
+                let body = String::from_utf8_lossy(&buf[head_len..]).to_string();
+
+                let response = format!(
+                    "HTTP/1.1 {} Stub\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+                let _ = tx.send(CapturedRequest {
+                    request_line,
+                    headers,
+                    body,
+                });
+            });
+        }
+    });
+
+    (format!("http://{}", addr), rx)
+}
@@ -0,0 +1,247 @@
+//! Integration tests for post-command hooks.
+//!
+//! Tests cover:
+//! - Summaries for plan, execution and rollback
+//! - Shell command hooks receiving the summary on stdin
+//! - Webhooks against a local stub server
+//! - Event filtering and failure reporting
+
+mod common;
+
+use media_organizer::core::hooks::{run_hooks, HookSummary};
+use media_organizer::models::config::{Config, HookConfig, HookEvent};
+use media_organizer::models::media::{MediaType, MovieMetadata, VideoFile, VideoMetadata};
+use media_organizer::models::plan::{
+    Operation, OperationType, ParsedInfo, Plan, PlanItem, PlanItemStatus, TargetInfo,
+};
+use media_organizer::models::rollback::{
+    Rollback, RollbackAction, RollbackActionType, RollbackOpType, RollbackOperation,
+};
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn movie_item(source: &Path, target: &Path, title: &str, year: u16) -> PlanItem {
+    PlanItem {
+        id: title.to_string(),
+        status: PlanItemStatus::Pending,
+        source: VideoFile {
+            path: source.to_path_buf(),
+            filename: source.file_name().unwrap().to_string_lossy().to_string(),
+            size: 0,
+            modified: chrono::Utc::now(),
+            is_sample: false,
+            parent_dir: source.parent().unwrap().to_path_buf(),
+        },
+        parsed: ParsedInfo::default(),
+        movie_metadata: Some(MovieMetadata {
+            title: title.to_string(),
+            year,
+            ..Default::default()
+        }),
+        tvshow_metadata: None,
+        episode_metadata: None,
+        video_metadata: VideoMetadata::default(),
+        target: TargetInfo {
+            folder: String::new(),
+            filename: String::new(),
+            full_path: target.to_path_buf(),
+            nfo: String::new(),
+            poster: None,
+        },
+        operations: vec![Operation {
+            op: OperationType::Move,
+            from: Some(source.to_path_buf()),
+            to: target.to_path_buf(),
+            url: None,
+            content_ref: None,
+        }],
+        placement: None,
+    }
+}
+
+fn plan(items: Vec<PlanItem>) -> Plan {
+    Plan {
+        media_type: Some(MediaType::Movies),
+        source_path: PathBuf::from("/in"),
+        target_path: PathBuf::from("/out"),
+        items,
+        ..Default::default()
+    }
+}
+
+fn move_op(seq: u32, from: &Path, to: &Path, executed: bool) -> RollbackOperation {
+    RollbackOperation {
+        seq,
+        op_type: RollbackOpType::Move,
+        from: from.to_path_buf(),
+        to: to.to_path_buf(),
+        checksum: None,
+        rollback: RollbackAction {
+            op: RollbackActionType::Move,
+            path: to.to_path_buf(),
+            to: Some(from.to_path_buf()),
+        },
+        executed,
+    }
+}
+
+fn hook(events: Vec<HookEvent>, command: Option<String>, url: Option<String>) -> HookConfig {
+    HookConfig {
+        events,
+        command,
+        url,
+        timeout: 10,
+    }
+}
+
+// ========== SUMMARY TESTS ==========
+
+#[test]
+fn test_plan_summary_deduplicates_titles() {
+    let mut skipped = movie_item(Path::new("/in/c.mkv"), Path::new("/out/c.mkv"), "C", 2003);
+    skipped.status = PlanItemStatus::Skip;
+    let p = plan(vec![
+        movie_item(Path::new("/in/a1.mkv"), Path::new("/out/a1.mkv"), "A", 2001),
+        movie_item(Path::new("/in/a2.mkv"), Path::new("/out/a2.mkv"), "A", 2001),
+        movie_item(Path::new("/in/b.mkv"), Path::new("/out/b.mkv"), "B", 2002),
+        skipped,
+    ]);
+
+    let summary = HookSummary::for_plan(&p, None);
+    assert_eq!(summary.event, HookEvent::Plan);
+    assert_eq!(summary.planned, 3);
+    assert_eq!(summary.titles, vec!["A (2001)", "B (2002)"]);
+}
+
+#[test]
+fn test_execution_and_rollback_summaries() {
+    let temp = TempDir::new().unwrap();
+    let moved_src = temp.path().join("a.mkv");
+    let moved_dst = temp.path().join("A (2001).mkv");
+    let failed_src = temp.path().join("b.mkv");
+    let failed_dst = temp.path().join("B (2002).mkv");
+    std::fs::write(&moved_dst, b"a").unwrap();
+    std::fs::write(&failed_src, b"b").unwrap();
+
+    let p = plan(vec![
+        movie_item(&moved_src, &moved_dst, "A", 2001),
+        movie_item(&failed_src, &failed_dst, "B", 2002),
+    ]);
+    let rollback = Rollback {
+        operations: vec![
+            move_op(1, &moved_src, &moved_dst, true),
+            move_op(2, &failed_src, &failed_dst, false),
+        ],
+        ..Default::default()
+    };
+
+    let summary = HookSummary::for_execution(&p, &rollback, None);
+    assert_eq!(summary.moved, 1);
+    assert_eq!(summary.failed, 1);
+    assert_eq!(summary.titles, vec!["A (2001)"]);
+
+    // After the rollback the moved file is back at its source
+    std::fs::rename(&moved_dst, &moved_src).unwrap();
+    let summary = HookSummary::for_rollback(&rollback, Some(&p), 0, None);
+    assert_eq!(summary.event, HookEvent::Rollback);
+    assert_eq!(summary.restored, 1);
+    assert_eq!(summary.titles, vec!["A (2001)"]);
+}
+
+// ========== HOOK EXECUTION TESTS ==========
+
+#[tokio::test]
+async fn test_command_hook_receives_summary_on_stdin() {
+    let temp = TempDir::new().unwrap();
+    let out = temp.path().join("summary.json");
+    let command = format!(
+        "{{ cat; echo; echo \"$MEDIA_ORGANIZER_EVENT\"; }} > '{}'",
+        out.display()
+    );
+    let summary = HookSummary::for_plan(
+        &plan(vec![movie_item(
+            Path::new("/in/a.mkv"),
+            Path::new("/out/a.mkv"),
+            "A",
+            2001,
+        )]),
+        None,
+    );
+
+    let errors = run_hooks(&[hook(vec![], Some(command), None)], &summary).await;
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let written = std::fs::read_to_string(&out).unwrap();
+    let (json, event) = written.trim_end().rsplit_once('\n').unwrap();
+    let received: serde_json::Value = serde_json::from_str(json).unwrap();
+    assert_eq!(received["event"], "plan");
+    assert_eq!(received["planned"], 1);
+    assert_eq!(received["titles"][0], "A (2001)");
+    assert_eq!(event, "plan");
+}
+
+#[tokio::test]
+async fn test_webhook_posts_summary() {
+    let (url, mut requests) = common::start_http_stub(200).await;
+    let summary = HookSummary::for_plan(&plan(vec![]), None);
+
+    let errors = run_hooks(
+        &[hook(
+            vec![HookEvent::Plan],
+            None,
+            Some(format!("{}/hook", url)),
+        )],
+        &summary,
+    )
+    .await;
+    assert!(errors.is_empty(), "{:?}", errors);
+
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request.request_line, "POST /hook HTTP/1.1");
+    assert!(request
+        .headers
+        .iter()
+        .any(|h| h.eq_ignore_ascii_case("content-type: application/json")));
+    let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+    assert_eq!(body["event"], "plan");
+    assert_eq!(body["media_type"], "movies");
+}
+
+#[tokio::test]
+async fn test_hooks_filtered_by_event_and_failures_reported() {
+    let (url, mut requests) = common::start_http_stub(500).await;
+    let summary = HookSummary::for_plan(&plan(vec![]), None);
+
+    let hooks = vec![
+        // Not subscribed to plan events: must not run
+        hook(vec![HookEvent::Execute], Some("exit 1".to_string()), None),
+        hook(vec![HookEvent::Plan], Some("exit 3".to_string()), None),
+        hook(vec![], None, Some(url)),
+    ];
+    let errors = run_hooks(&hooks, &summary).await;
+
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert!(errors[0].starts_with("exit 3"));
+    assert!(errors[1].contains("500"));
+    assert!(requests.recv().await.is_some());
+}
+
+// ========== CONFIG TESTS ==========
+
+#[test]
+fn test_config_with_only_hooks() {
+    let config: Config = toml::from_str(
+        r#"
+        [[hooks]]
+        events = ["execute", "rollback"]
+        url = "http://localhost:9000/hook"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(config.hooks.len(), 1);
+    assert_eq!(config.hooks[0].timeout, 30);
+    assert!(config.hooks[0].handles(HookEvent::Rollback));
+    assert!(!config.hooks[0].handles(HookEvent::Plan));
+    assert_eq!(config.ollama.port, 11434);
+}
@@ -0,0 +1,438 @@
+//! Integration tests for the SQLite central index store.
+//!
+//! Tests cover:
+//! - Schema migrations and persistence across reopen
+//! - Per-disk merges matching the JSON index semantics
+//! - Search in SQL matching the in-memory search, including title ranking
+//! - Collections kept across merges
+//! - User data kept when a disk is rescanned
+//! - In-place updates of single entries and disks
+//! - Removing a disk together with its entries
+//! - Disk paths followed to a new mount point on rescan
+
+use media_organizer::core::index_store::{IndexChanges, IndexTotals, SqliteIndex};
+use media_organizer::core::indexer::{merge_disk_into_central, search, SearchResults};
+use media_organizer::models::index::{CentralIndex, DiskIndex, DiskInfo, MovieEntry, TvShowEntry};
+use std::collections::HashMap;
+use tempfile::TempDir;
+
+// ========== TEST FIXTURES ==========
+
+fn disk_index(
+    label: &str,
+    media_type: &str,
+    movies: Vec<MovieEntry>,
+    tvshows: Vec<TvShowEntry>,
+) -> DiskIndex {
+    let mut paths = HashMap::new();
+    paths.insert(
+        media_type.to_string(),
+        format!("/mnt/{}/{}", label, media_type),
+    );
+
+    DiskIndex {
+        version: "1.0".to_string(),
+        disk: DiskInfo {
+            label: label.to_string(),
+            uuid: Some(format!("{}-uuid", label)),
+            last_indexed: chrono::Utc::now().to_rfc3339(),
+            movie_count: movies.len(),
+            tvshow_count: tvshows.len(),
+            paths,
+            ..Default::default()
+        },
+        movies,
+        tvshows,
+    }
+}
+
+fn movie(id: &str, title: &str, disk: &str, year: u16) -> MovieEntry {
+    MovieEntry {
+        id: id.to_string(),
+        disk: disk.to_string(),
+        relative_path: format!("{}/movie.nfo", title),
+        title: title.to_string(),
+        year: Some(year),
+        tmdb_id: Some(year as u64),
+        country: Some("US".to_string()),
+        genres: vec!["Action".to_string()],
+        actors: vec!["Actor One".to_string()],
+        directors: vec!["Director One".to_string()],
+        runtime: Some(120),
+        rating: Some(7.5),
+        size_bytes: 1_000,
+        resolution: Some("1080p".to_string()),
+        indexed_at: chrono::Utc::now().to_rfc3339(),
+        ..Default::default()
+    }
+}
+
+fn tvshow(id: &str, title: &str, disk: &str, year: u16) -> TvShowEntry {
+    TvShowEntry {
+        id: id.to_string(),
+        disk: disk.to_string(),
+        relative_path: format!("{}/tvshow.nfo", title),
+        title: title.to_string(),
+        year: Some(year),
+        tmdb_id: Some(year as u64 + 10_000),
+        country: Some("CN".to_string()),
+        genres: vec!["Drama".to_string()],
+        actors: vec!["Actor Two".to_string()],
+        seasons: 1,
+        episodes: 10,
+        size_bytes: 5_000,
+        indexed_at: chrono::Utc::now().to_rfc3339(),
+        ..Default::default()
+    }
+}
+
+/// Scans merged in order into both backends.
+fn scans() -> Vec<DiskIndex> {
+    let mut matrix = movie("m1", "The Matrix", "D1", 1999);
+    matrix.original_title = Some("Матрица".to_string());
+    matrix.collection_id = Some(2344);
+    matrix.collection_name = Some("The Matrix Collection".to_string());
+    matrix.collection_total_movies = Some(4);
+    let mut reloaded = movie("m2", "The Matrix Reloaded", "D1", 2003);
+    reloaded.collection_id = Some(2344);
+    reloaded.collection_name = Some("The Matrix Collection".to_string());
+    reloaded.directors = vec!["Lana Wachowski".to_string()];
+    let mut hero = movie("m3", "英雄", "D2", 2002);
+    hero.country = Some("CN".to_string());
+    hero.genres = vec!["Wuxia".to_string(), "Action".to_string()];
+    hero.year = None;
+
+    vec![
+        disk_index("D1", "movies", vec![matrix, reloaded], vec![]),
+        disk_index("D2", "movies", vec![hero], vec![]),
+        disk_index(
+            "D1",
+            "tvshows",
+            vec![],
+            vec![tvshow("t1", "Nirvana in Fire", "D1", 2015)],
+        ),
+    ]
+}
+
+fn ids(results: &SearchResults) -> (Vec<String>, Vec<String>, Vec<u64>) {
+    (
+        results.movies.iter().map(|m| m.id.clone()).collect(),
+        results.tvshows.iter().map(|t| t.id.clone()).collect(),
+        results.collections.iter().map(|c| c.id).collect(),
+    )
+}
+
+// ========== STORAGE TESTS ==========
+
+#[test]
+fn test_merge_matches_json_index_and_persists() {
+    let temp = TempDir::new().unwrap();
+    let db = temp.path().join("central_index.db");
+
+    let mut central = CentralIndex::default();
+    {
+        let mut store = SqliteIndex::open(&db).unwrap();
+        assert!(store.is_empty().unwrap());
+        for scan in scans() {
+            merge_disk_into_central(&mut central, scan.clone());
+            store.merge_disk(&scan).unwrap();
+        }
+    }
+
+    let store = SqliteIndex::open(&db).unwrap();
+    assert_eq!(store.schema_version().unwrap(), 1);
+    assert_eq!(
+        store.totals().unwrap(),
+        IndexTotals {
+            movies: 3,
+            tvshows: 1,
+            disks: 2
+        }
+    );
+
+    let loaded = store.load().unwrap();
+    let movie_ids: Vec<&str> = loaded.movies.iter().map(|m| m.id.as_str()).collect();
+    assert_eq!(movie_ids, vec!["m1", "m2", "m3"]);
+
+    // Composite disk: both media types, counts and paths merged
+    let d1 = &loaded.disks["D1"];
+    assert_eq!(d1.movie_count, central.disks["D1"].movie_count);
+    assert_eq!(d1.tvshow_count, 1);
+    assert_eq!(d1.total_size_bytes, central.disks["D1"].total_size_bytes);
+    assert_eq!(d1.paths.len(), 2);
+
+    assert_eq!(
+        loaded.statistics.total_size_bytes,
+        central.statistics.total_size_bytes
+    );
+    assert_eq!(
+        loaded.indexes.by_actor.len(),
+        central.indexes.by_actor.len()
+    );
+    let collection = &loaded.collections[&2344];
+    assert_eq!(collection.owned_count, 2);
+    assert_eq!(collection.total_in_collection, 4);
+}
+
+#[test]
+fn test_rescan_replaces_only_scanned_media_type() {
+    let mut store = SqliteIndex::open_in_memory().unwrap();
+    for scan in scans() {
+        store.merge_disk(&scan).unwrap();
+    }
+
+    // Rescan D1 movies with a single entry; the TV show must stay
+    store
+        .merge_disk(&disk_index(
+            "D1",
+            "movies",
+            vec![movie("m4", "Speed", "D1", 1994)],
+            vec![],
+        ))
+        .unwrap();
+    // An empty scan changes nothing
+    store
+        .merge_disk(&disk_index("D1", "movies", vec![], vec![]))
+        .unwrap();
+
+    let loaded = store.load().unwrap();
+    let d1_movies: Vec<&str> = loaded
+        .movies
+        .iter()
+        .filter(|m| m.disk == "D1")
+        .map(|m| m.id.as_str())
+        .collect();
+    assert_eq!(d1_movies, vec!["m4"]);
+    assert_eq!(loaded.tvshows.len(), 1);
+    assert_eq!(loaded.disks["D1"].movie_count, 1);
+    assert_eq!(loaded.disks["D1"].tvshow_count, 1);
+}
+
+#[test]
+fn test_rescan_keeps_user_data() {
+    let mut store = SqliteIndex::open_in_memory().unwrap();
+    let mut heat = movie("m1", "Heat", "D1", 1995);
+    heat.user_data.watched = true;
+    heat.user_data.tags = vec!["Rewatch".to_string()];
+    store
+        .merge_disk(&disk_index("D1", "movies", vec![heat], vec![]))
+        .unwrap();
+
+    // Rescanned under a new ID, same folder
+    store
+        .merge_disk(&disk_index(
+            "D1",
+            "movies",
+            vec![movie("m9", "Heat", "D1", 1995)],
+            vec![],
+        ))
+        .unwrap();
+
+    let loaded = store.load().unwrap();
+    assert_eq!(loaded.movies[0].id, "m9");
+    assert!(loaded.movies[0].user_data.watched);
+    assert_eq!(loaded.movies[0].user_data.tags, vec!["Rewatch"]);
+}
+
+#[test]
+fn test_rescan_from_new_mount_keeps_scanned_path() {
+    // First scanned from a folder on the root filesystem
+    let mut first = disk_index(
+        "D1",
+        "movies",
+        vec![movie("m1", "Heat", "D1", 1995)],
+        vec![],
+    );
+    first
+        .disk
+        .paths
+        .insert("movies".to_string(), "/data/Movies".to_string());
+    first.disk.mount_point = Some("/".to_string());
+    first.disk.last_seen = Some(chrono::Utc::now().to_rfc3339());
+
+    // Then from the disk mounted at /mnt/D1, whose path is under the old mount
+    let mut second = disk_index(
+        "D1",
+        "tvshows",
+        vec![],
+        vec![tvshow("t1", "Dark", "D1", 2017)],
+    );
+    second
+        .disk
+        .paths
+        .insert("tvshows".to_string(), "/mnt/D1/TV".to_string());
+    second.disk.mount_point = Some("/mnt/D1".to_string());
+    second.disk.last_seen = Some(chrono::Utc::now().to_rfc3339());
+
+    let mut central = CentralIndex::default();
+    let mut store = SqliteIndex::open_in_memory().unwrap();
+    for scan in [first, second] {
+        merge_disk_into_central(&mut central, scan.clone());
+        store.merge_disk(&scan).unwrap();
+    }
+
+    for disk in [&central.disks["D1"], &store.load().unwrap().disks["D1"]] {
+        assert_eq!(disk.paths["tvshows"], "/mnt/D1/TV");
+        assert_eq!(disk.paths["movies"], "/mnt/D1/data/Movies");
+        assert_eq!(disk.mount_point.as_deref(), Some("/mnt/D1"));
+    }
+}
+
+#[test]
+fn test_replace_all_round_trip() {
+    let mut central = CentralIndex::default();
+    for scan in scans() {
+        merge_disk_into_central(&mut central, scan);
+    }
+    central
+        .collections
+        .get_mut(&2344)
+        .unwrap()
+        .total_in_collection = 5;
+
+    let mut store = SqliteIndex::open_in_memory().unwrap();
+    store.replace_all(&central).unwrap();
+    let loaded = store.load().unwrap();
+
+    assert_eq!(loaded.created_at, central.created_at);
+    assert_eq!(loaded.movies.len(), central.movies.len());
+    assert_eq!(loaded.disks.len(), central.disks.len());
+    assert_eq!(loaded.collections[&2344].total_in_collection, 5);
+    assert_eq!(
+        loaded.statistics.complete_collections,
+        central.statistics.complete_collections
+    );
+
+    store.replace_all(&CentralIndex::default()).unwrap();
+    assert!(store.is_empty().unwrap());
+}
+
+#[test]
+fn test_apply_changes_updates_entries_in_place() {
+    let mut store = SqliteIndex::open_in_memory().unwrap();
+    for scan in scans() {
+        store.merge_disk(&scan).unwrap();
+    }
+    let before = store.totals().unwrap();
+
+    // Mark one movie watched and move another to a different disk
+    let loaded = store.load().unwrap();
+    let mut matrix = loaded.movies.iter().find(|m| m.id == "m1").unwrap().clone();
+    matrix.user_data.watched = true;
+    let mut hero = loaded.movies.iter().find(|m| m.id == "m3").unwrap().clone();
+    hero.disk = "D1".to_string();
+    let mut d1 = loaded.disks["D1"].clone();
+    d1.free_bytes = Some(42);
+    store
+        .apply_changes(&IndexChanges {
+            movies: vec![matrix, hero],
+            disks: vec![d1],
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(store.totals().unwrap(), before);
+    let loaded = store.load().unwrap();
+    let by_id = |id: &str| loaded.movies.iter().find(|m| m.id == id).unwrap();
+    assert!(by_id("m1").user_data.watched);
+    assert!(!by_id("m2").user_data.watched);
+    assert_eq!(by_id("m3").disk, "D1");
+    assert_eq!(loaded.disks["D1"].free_bytes, Some(42));
+    assert_eq!(loaded.collections[&2344].movies.len(), 2);
+
+    // Terms are kept for the moved entry
+    let results = store
+        .search(None, None, None, None, None, None, Some("Wuxia"), None)
+        .unwrap();
+    assert_eq!(results.movies.len(), 1);
+    assert_eq!(results.movies[0].disk, "D1");
+}
+
+#[test]
+fn test_remove_disk_deletes_its_entries() {
+    let mut store = SqliteIndex::open_in_memory().unwrap();
+    for scan in scans() {
+        store.merge_disk(&scan).unwrap();
+    }
+
+    store.remove_disk("D1").unwrap();
+
+    let loaded = store.load().unwrap();
+    assert!(!loaded.disks.contains_key("D1"));
+    assert!(loaded.disks.contains_key("D2"));
+    let movie_ids: Vec<&str> = loaded.movies.iter().map(|m| m.id.as_str()).collect();
+    assert_eq!(movie_ids, vec!["m3"]);
+    assert!(loaded.tvshows.is_empty());
+
+    // Terms of the removed entries go with them
+    let results = store
+        .search(None, None, None, None, None, None, Some("Action"), None)
+        .unwrap();
+    assert_eq!(ids(&results).0, vec!["m3".to_string()]);
+}
+
+// ========== SEARCH TESTS ==========
+
+#[test]
+fn test_search_matches_in_memory_search() {
+    let mut central = CentralIndex::default();
+    let mut store = SqliteIndex::open_in_memory().unwrap();
+    for scan in scans() {
+        merge_disk_into_central(&mut central, scan.clone());
+        store.merge_disk(&scan).unwrap();
+    }
+
+    type Query<'a> = (
+        Option<&'a str>,
+        Option<&'a str>,
+        Option<&'a str>,
+        Option<&'a str>,
+        Option<u16>,
+        Option<(u16, u16)>,
+        Option<&'a str>,
+        Option<&'a str>,
+    );
+    let queries: Vec<Query> = vec![
+        (None, None, None, None, None, None, None, None),
+        (Some("matrix"), None, None, None, None, None, None, None),
+        (Some("МАТРИЦА"), None, None, None, None, None, None, None),
+        (Some("英雄"), None, None, None, None, None, None, None),
+        (
+            Some("matrx reloaded"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        (Some("yx"), None, None, None, None, None, None, None),
+        (Some("the-matrix"), None, None, None, None, None, None, None),
+        (None, Some("actor"), None, None, None, None, None, None),
+        (None, None, Some("lana"), None, None, None, None, None),
+        (None, None, None, Some("matrix"), None, None, None, None),
+        (None, None, None, None, Some(1999), None, None, None),
+        (None, None, None, None, None, Some((2000, 2020)), None, None),
+        (None, None, None, None, None, None, Some("wux"), None),
+        (None, None, None, None, None, None, None, Some("cn")),
+        (
+            Some("the"),
+            Some("one"),
+            None,
+            None,
+            None,
+            Some((1990, 2000)),
+            Some("action"),
+            Some("US"),
+        ),
+    ];
+
+    for q in queries {
+        let expected = search(&central, q.0, q.1, q.2, q.3, q.4, q.5, q.6, q.7);
+        let actual = store
+            .search(q.0, q.1, q.2, q.3, q.4, q.5, q.6, q.7)
+            .unwrap();
+        assert_eq!(ids(&actual), ids(&expected), "query {:?}", q);
+    }
+}
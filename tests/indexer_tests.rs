@@ -3,13 +3,32 @@
 //! Tests cover:
 //! - Composite storage (one disk label with multiple media types)
 //! - Search functionality (by title, year, actor, director, genre, country)
+//! - Query expressions applied to search results (filters, sorting, limits)
 //! - Collection indexing and completeness detection
 //! - Cross-disk duplicate detection
 //! - Edge cases (empty scans, repeated scans, path updates)
-
-use media_organizer::core::indexer::{merge_disk_into_central, search};
-use media_organizer::models::index::{CentralIndex, DiskIndex, DiskInfo, MovieEntry, TvShowEntry};
+//! - Episode files recorded per TV show and summarized per season
+//! - Video codec, HDR and bitrate detected when scanning movies
+//! - Incremental scans (unchanged, updated, moved, added and removed entries)
+//! - Entries kept when their NFO can no longer be parsed
+//! - Folders without NFO recognized by name and filled from TMDB details
+//! - Disk space and mount points recorded and followed to new mount points
+//! - Watched state and personal data imported from NFOs and kept across rescans
+//! - Watched state set by hand not overridden by NFO watch history
+
+use media_organizer::core::indexer::{
+    apply_movie_details, current_disk_paths, merge_disk_into_central, mount_point_of_device,
+    parse_df_output, previous_scan, record_mount, scan_directory, scan_directory_incremental,
+    search, summarize_seasons, MountInfo, ScanChanges,
+};
+use media_organizer::core::query::Query;
+use media_organizer::models::index::{
+    CentralIndex, DiskIndex, DiskInfo, EpisodeFile, MovieEntry, TvShowEntry, UserDataUpdate,
+};
+use media_organizer::services::tmdb::MovieDetails;
 use std::collections::HashMap;
+use std::fs;
+use tempfile::TempDir;
 
 // ========== TEST FIXTURES ==========
 
@@ -25,10 +44,10 @@ fn create_movie_disk_index(label: &str, path: &str, movies: Vec<MovieEntry>) ->
             uuid: Some("test-uuid".to_string()),
             last_indexed: chrono::Utc::now().to_rfc3339(),
             movie_count: movies.len(),
-            tvshow_count: 0,
             total_size_bytes: movies.iter().map(|m| m.size_bytes).sum(),
             base_path: path.to_string(),
             paths,
+            ..Default::default()
         },
         movies,
         tvshows: Vec::new(),
@@ -46,11 +65,11 @@ fn create_tvshow_disk_index(label: &str, path: &str, tvshows: Vec<TvShowEntry>)
             label: label.to_string(),
             uuid: Some("test-uuid".to_string()),
             last_indexed: chrono::Utc::now().to_rfc3339(),
-            movie_count: 0,
             tvshow_count: tvshows.len(),
             total_size_bytes: tvshows.iter().map(|t| t.size_bytes).sum(),
             base_path: path.to_string(),
             paths,
+            ..Default::default()
         },
         movies: Vec::new(),
         tvshows,
@@ -65,13 +84,8 @@ fn create_test_movie(id: &str, title: &str, disk: &str, tmdb_id: u64) -> MovieEn
         disk_uuid: Some("test-uuid".to_string()),
         relative_path: format!("{}/movie.nfo", title),
         title: title.to_string(),
-        original_title: None,
         year: Some(2024),
         tmdb_id: Some(tmdb_id),
-        imdb_id: None,
-        collection_id: None,
-        collection_name: None,
-        collection_total_movies: None,
         country: Some("US".to_string()),
         genres: vec!["Action".to_string()],
         actors: vec!["Actor1".to_string()],
@@ -81,6 +95,7 @@ fn create_test_movie(id: &str, title: &str, disk: &str, tmdb_id: u64) -> MovieEn
         size_bytes: 1_000_000_000,
         resolution: Some("1080p".to_string()),
         indexed_at: chrono::Utc::now().to_rfc3339(),
+        ..Default::default()
     }
 }
 
@@ -92,10 +107,8 @@ fn create_test_tvshow(id: &str, title: &str, disk: &str, tmdb_id: u64) -> TvShow
         disk_uuid: Some("test-uuid".to_string()),
         relative_path: format!("{}/tvshow.nfo", title),
         title: title.to_string(),
-        original_title: None,
         year: Some(2024),
         tmdb_id: Some(tmdb_id),
-        imdb_id: None,
         country: Some("US".to_string()),
         genres: vec!["Drama".to_string()],
         actors: vec!["Actor1".to_string()],
@@ -103,6 +116,7 @@ fn create_test_tvshow(id: &str, title: &str, disk: &str, tmdb_id: u64) -> TvShow
         episodes: 24,
         size_bytes: 5_000_000_000,
         indexed_at: chrono::Utc::now().to_rfc3339(),
+        ..Default::default()
     }
 }
 
@@ -549,6 +563,128 @@ fn test_search_year_range() {
     assert_eq!(results.movies[0].title, "Movie 2020");
 }
 
+#[test]
+fn test_search_fuzzy_titles_ranked_by_relevance() {
+    let mut central = CentralIndex::default();
+
+    let mut endgame = create_test_movie("m1", "Avengers: Endgame", "TestDisk", 1001);
+    endgame.original_title = Some("復仇者聯盟4：終局之戰".to_string());
+    endgame.year = Some(2019);
+    let mut avengers = create_test_movie("m2", "The Avengers", "TestDisk", 1002);
+    avengers.year = Some(2012);
+    let mut hero = create_test_movie("m3", "英雄", "TestDisk", 1003);
+    hero.year = Some(2002);
+
+    let movies = vec![avengers, endgame, hero];
+    let disk = create_movie_disk_index("TestDisk", "/mnt/TestDisk/Movies", movies);
+    merge_disk_into_central(&mut central, disk);
+
+    let titles = |query: &str| -> Vec<String> {
+        search(
+            &central,
+            Some(query),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .movies
+        .into_iter()
+        .map(|m| m.title)
+        .collect()
+    };
+
+    // Punctuation-insensitive, exact match ranked first
+    assert_eq!(titles("Avengers Endgame"), vec!["Avengers: Endgame"]);
+    // Typo tolerated; the closer match comes first
+    assert_eq!(titles("avengrs"), vec!["Avengers: Endgame", "The Avengers"]);
+    // Simplified query matches the Traditional original title
+    assert_eq!(titles("复仇者联盟"), vec!["Avengers: Endgame"]);
+    // Pinyin initials
+    assert_eq!(titles("fczlm"), vec!["Avengers: Endgame"]);
+    assert_eq!(titles("yx"), vec!["英雄"]);
+}
+
+#[test]
+fn test_search_query_expression() {
+    let mut central = CentralIndex::default();
+
+    let mut affairs = create_test_movie("m1", "Infernal Affairs", "TestDisk", 1001);
+    affairs.year = Some(2002);
+    affairs.rating = Some(8.1);
+    affairs.actors = vec!["Tony Leung".to_string()];
+    affairs.resolution = Some("2160p".to_string());
+    let mut mood = create_test_movie("m2", "In the Mood for Love", "TestDisk", 1002);
+    mood.year = Some(2000);
+    mood.rating = Some(8.3);
+    mood.actors = vec!["Tony Leung".to_string()];
+    mood.genres = vec!["Romance".to_string()];
+    mood.resolution = Some("2160p".to_string());
+    let mut ghost = create_test_movie("m3", "Ghost House", "TestDisk", 1003);
+    ghost.year = Some(2005);
+    ghost.rating = Some(7.8);
+    ghost.actors = vec!["Tony Leung".to_string()];
+    ghost.genres = vec!["Horror".to_string()];
+    ghost.resolution = Some("2160p".to_string());
+    let mut hard_boiled = create_test_movie("m4", "Hard Boiled", "TestDisk", 1004);
+    hard_boiled.year = Some(1992);
+    hard_boiled.rating = Some(7.9);
+    hard_boiled.actors = vec!["Tony Leung".to_string()];
+
+    let movies = vec![affairs, mood, ghost, hard_boiled];
+    let disk = create_movie_disk_index("TestDisk", "/mnt/TestDisk/Movies", movies);
+    merge_disk_into_central(&mut central, disk);
+    let disk = create_tvshow_disk_index(
+        "TestDisk",
+        "/mnt/TestDisk/TV",
+        vec![create_test_tvshow(
+            "t1",
+            "Tony Leung Stories",
+            "TestDisk",
+            2001,
+        )],
+    );
+    merge_disk_into_central(&mut central, disk);
+
+    let titles = |query: &str| -> (Vec<String>, usize) {
+        let results = search(&central, None, None, None, None, None, None, None, None);
+        let results = Query::parse(query).unwrap().apply(results);
+        (
+            results.movies.into_iter().map(|m| m.title).collect(),
+            results.tvshows.len(),
+        )
+    };
+
+    // Movie-only fields never match TV shows
+    assert_eq!(
+        titles(
+            r#"actor:"Tony Leung" year:2000..2010 rating>7.5 resolution:2160p -genre:horror sort:rating"#
+        ),
+        (
+            vec![
+                "In the Mood for Love".to_string(),
+                "Infernal Affairs".to_string()
+            ],
+            0
+        )
+    );
+    assert_eq!(
+        titles("genre:horror OR year<1995 sort:year:asc"),
+        (
+            vec!["Hard Boiled".to_string(), "Ghost House".to_string()],
+            0
+        )
+    );
+    assert_eq!(
+        titles("type:movie sort:title limit:1"),
+        (vec!["Ghost House".to_string()], 0)
+    );
+    assert_eq!(titles("type:tv seasons>=3").1, 1);
+}
+
 // ========== COLLECTION TESTS ==========
 
 #[test]
@@ -851,6 +987,527 @@ fn test_statistics_update() {
     assert_eq!(central.statistics.by_decade.get("2020s"), Some(&2));
 }
 
+// ========== EPISODE TESTS ==========
+
+#[test]
+fn test_scan_records_episode_files() {
+    let temp = TempDir::new().unwrap();
+    let show = temp.path().join("Nirvana in Fire");
+    fs::create_dir_all(show.join("Season 01")).unwrap();
+    fs::create_dir_all(show.join("Season 02")).unwrap();
+    fs::create_dir_all(show.join("Extras")).unwrap();
+    fs::write(
+        show.join("tvshow.nfo"),
+        "<tvshow><title>Nirvana in Fire</title><uniqueid type=\"tmdb\">64197</uniqueid></tvshow>",
+    )
+    .unwrap();
+
+    // Organized name, episode NFO, and plain names in a season directory
+    fs::write(
+        show.join(
+            "Season 01/[Nirvana in Fire]-S01E01-[Episode 1]-1080p-WEB-DL-h264-8bit-aac-2.0.mkv",
+        ),
+        vec![0u8; 100],
+    )
+    .unwrap();
+    fs::write(show.join("Season 01/ep03.mkv"), vec![0u8; 50]).unwrap();
+    fs::write(
+        show.join("Season 01/ep03.nfo"),
+        "<episodedetails><title>Reunion</title><season>1</season><episode>3</episode></episodedetails>",
+    )
+    .unwrap();
+    fs::write(show.join("Season 01/Show.S01E04.2160p.mkv"), vec![0u8; 10]).unwrap();
+    fs::write(show.join("Season 02/第2集.mp4"), vec![0u8; 10]).unwrap();
+    fs::write(show.join("Extras/trailer.mkv"), vec![0u8; 10]).unwrap();
+
+    let index = scan_directory(temp.path(), "D1", None, "tvshows").unwrap();
+    let entry = &index.tvshows[0];
+    let episodes: Vec<(u16, u16)> = entry
+        .episode_files
+        .iter()
+        .map(|e| (e.season, e.episode))
+        .collect();
+    assert_eq!(episodes, vec![(1, 1), (1, 3), (1, 4), (2, 2)]);
+
+    let first = &entry.episode_files[0];
+    assert_eq!(first.title.as_deref(), Some("Episode 1"));
+    assert_eq!(first.resolution.as_deref(), Some("1080p"));
+    assert_eq!(first.size_bytes, 100);
+    assert!(first
+        .relative_path
+        .starts_with("Nirvana in Fire/Season 01/"));
+    assert_eq!(entry.episode_files[1].title.as_deref(), Some("Reunion"));
+    assert_eq!(entry.episode_files[2].resolution.as_deref(), Some("2160p"));
+}
+
+#[test]
+fn test_summarize_seasons_across_disks() {
+    let mut show_d1 = create_test_tvshow("t1", "Show", "D1", 100);
+    let mut show_d2 = create_test_tvshow("t2", "Show", "D2", 100);
+    let episode = |season: u16, episode: u16| EpisodeFile {
+        season,
+        episode,
+        title: None,
+        resolution: None,
+        size_bytes: 10,
+        relative_path: format!("Show/S{:02}E{:02}.mkv", season, episode),
+    };
+    show_d1.episode_files = vec![episode(1, 1), episode(1, 2), episode(2, 1)];
+    show_d2.episode_files = vec![episode(2, 2), episode(2, 5), episode(3, 1)];
+
+    let seasons = summarize_seasons(&[&show_d1, &show_d2]);
+    assert_eq!(seasons.len(), 3);
+    assert!(seasons[0].is_complete());
+    assert_eq!(seasons[0].disks, vec!["D1"]);
+    assert_eq!(seasons[1].episodes, vec![1, 2, 5]);
+    assert_eq!(seasons[1].missing, vec![3, 4]);
+    assert_eq!(seasons[1].disks, vec!["D1", "D2"]);
+    assert_eq!(seasons[1].size_bytes, 30);
+    assert_eq!(seasons[2].disks, vec!["D2"]);
+}
+
+// ========== VIDEO QUALITY TESTS ==========
+
+#[test]
+fn test_scan_detects_video_quality() {
+    let temp = TempDir::new().unwrap();
+    let with_streams = temp.path().join("Dune (2021)");
+    let name_only = temp.path().join("Heat (1995)");
+    fs::create_dir_all(&with_streams).unwrap();
+    fs::create_dir_all(&name_only).unwrap();
+
+    fs::write(
+        with_streams.join("movie.nfo"),
+        "<movie><title>Dune</title><year>2021</year><runtime>1</runtime>\
+         <fileinfo><streamdetails><video><codec>x265</codec><width>3840</width>\
+         <height>1600</height><hdrtype>dolbyvision</hdrtype></video></streamdetails></fileinfo></movie>",
+    )
+    .unwrap();
+    fs::write(with_streams.join("Dune.mkv"), vec![0u8; 750_000]).unwrap();
+
+    fs::write(
+        name_only.join("movie.nfo"),
+        "<movie><title>Heat</title><year>1995</year></movie>",
+    )
+    .unwrap();
+    fs::write(
+        name_only.join("Heat.1995.1080p.BluRay.HDR10.x264.mkv"),
+        vec![0u8; 10],
+    )
+    .unwrap();
+
+    let index = scan_directory(temp.path(), "D1", None, "movies").unwrap();
+    let dune = index.movies.iter().find(|m| m.title == "Dune").unwrap();
+    assert_eq!(dune.resolution.as_deref(), Some("2160p"));
+    assert_eq!(dune.video_codec.as_deref(), Some("hevc"));
+    assert_eq!(dune.hdr.as_deref(), Some("Dolby Vision"));
+    // 750 KB over one minute
+    assert_eq!(dune.bitrate_kbps, Some(100));
+
+    let heat = index.movies.iter().find(|m| m.title == "Heat").unwrap();
+    assert_eq!(heat.resolution.as_deref(), Some("1080p"));
+    assert_eq!(heat.video_codec.as_deref(), Some("h264"));
+    assert_eq!(heat.hdr.as_deref(), Some("HDR10"));
+    assert_eq!(heat.bitrate_kbps, None);
+}
+
+// ========== INCREMENTAL SCAN TESTS ==========
+
+fn write_movie(root: &std::path::Path, folder: &str, title: &str, tmdb_id: u64) {
+    let dir = root.join(folder);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("movie.nfo"),
+        format!(
+            "<movie><title>{}</title><tmdbid>{}</tmdbid></movie>",
+            title, tmdb_id
+        ),
+    )
+    .unwrap();
+    fs::write(dir.join("movie.mkv"), vec![0u8; 100]).unwrap();
+}
+
+#[test]
+fn test_incremental_scan_detects_changes() {
+    let temp = TempDir::new().unwrap();
+    write_movie(temp.path(), "Heat (1995)", "Heat", 949);
+    write_movie(temp.path(), "Alien (1979)", "Alien", 348);
+    write_movie(temp.path(), "Brazil (1985)", "Brazil", 68);
+
+    let (first, changes) =
+        scan_directory_incremental(temp.path(), "D1", None, "movies", None).unwrap();
+    assert_eq!(changes.added, 3);
+    let mut central = CentralIndex::default();
+    merge_disk_into_central(&mut central, first.clone());
+
+    // Untouched, edited, moved, deleted and new folders
+    fs::write(
+        temp.path().join("Alien (1979)/movie.nfo"),
+        "<movie><title>Alien (Director's Cut)</title><tmdbid>348</tmdbid></movie>",
+    )
+    .unwrap();
+    fs::create_dir_all(temp.path().join("Sci-Fi")).unwrap();
+    fs::rename(
+        temp.path().join("Brazil (1985)"),
+        temp.path().join("Sci-Fi/Brazil (1985)"),
+    )
+    .unwrap();
+    fs::remove_dir_all(temp.path().join("Heat (1995)")).unwrap();
+    write_movie(temp.path(), "Ran (1985)", "Ran", 11645);
+    write_movie(temp.path(), "Kagemusha (1980)", "Kagemusha", 11953);
+
+    let previous = previous_scan(&central, "D1", "movies", temp.path()).unwrap();
+    let (second, changes) =
+        scan_directory_incremental(temp.path(), "D1", None, "movies", Some(&previous)).unwrap();
+    assert_eq!(
+        changes,
+        ScanChanges {
+            added: 2,
+            updated: 1,
+            moved: 1,
+            removed: 1,
+            unchanged: 0,
+        }
+    );
+
+    let id_of = |index: &DiskIndex, title: &str| {
+        index
+            .movies
+            .iter()
+            .find(|m| m.title.starts_with(title))
+            .map(|m| m.id.clone())
+    };
+    assert_eq!(id_of(&second, "Brazil"), id_of(&first, "Brazil"));
+    assert_eq!(id_of(&second, "Alien"), id_of(&first, "Alien"));
+    let alien = second
+        .movies
+        .iter()
+        .find(|m| m.tmdb_id == Some(348))
+        .unwrap();
+    assert_eq!(alien.title, "Alien (Director's Cut)");
+
+    // Nothing changed since the second scan
+    merge_disk_into_central(&mut central, second);
+    let previous = previous_scan(&central, "D1", "movies", temp.path()).unwrap();
+    let (_, changes) =
+        scan_directory_incremental(temp.path(), "D1", None, "movies", Some(&previous)).unwrap();
+    assert_eq!(changes.unchanged, 4);
+    assert_eq!(changes.added + changes.updated + changes.removed, 0);
+
+    // A scan from another path does not reuse entries
+    assert!(previous_scan(&central, "D1", "movies", &temp.path().join("Sci-Fi")).is_none());
+    assert!(previous_scan(&central, "D1", "tvshows", temp.path()).is_none());
+}
+
+#[test]
+fn test_incremental_scan_keeps_entry_with_corrupted_nfo() {
+    let temp = TempDir::new().unwrap();
+    write_movie(temp.path(), "Heat (1995)", "Heat", 949);
+    write_movie(temp.path(), "Alien (1979)", "Alien", 348);
+
+    let (first, _) = scan_directory_incremental(temp.path(), "D1", None, "movies", None).unwrap();
+    let mut central = CentralIndex::default();
+    merge_disk_into_central(&mut central, first.clone());
+
+    fs::write(temp.path().join("Alien (1979)/movie.nfo"), "\0\0garbage").unwrap();
+
+    let previous = previous_scan(&central, "D1", "movies", temp.path()).unwrap();
+    let (second, changes) =
+        scan_directory_incremental(temp.path(), "D1", None, "movies", Some(&previous)).unwrap();
+    assert_eq!(changes.unchanged, 2);
+    assert_eq!(changes.removed, 0);
+    let alien = second
+        .movies
+        .iter()
+        .find(|m| m.tmdb_id == Some(348))
+        .unwrap();
+    let old_alien = first
+        .movies
+        .iter()
+        .find(|m| m.tmdb_id == Some(348))
+        .unwrap();
+    assert_eq!(alien.id, old_alien.id);
+    assert_eq!(alien.title, "Alien");
+}
+
+// ========== FOLDERS WITHOUT NFO TESTS ==========
+
+#[test]
+fn test_scan_indexes_folders_without_nfo() {
+    let temp = TempDir::new().unwrap();
+    write_movie(temp.path(), "Heat (1995)", "Heat", 949);
+    for folder in [
+        "The Matrix (1999) {tmdb-603}",
+        "[Upgrade][升级](2018)-tt6499752-tmdb500664",
+        "Alien (1979)",
+        "Heat (1995)/Extras (1995)",
+    ] {
+        let dir = temp.path().join(folder);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("movie.mkv"), vec![0u8; 100]).unwrap();
+    }
+    // Neither a recognizable name nor videos
+    fs::create_dir_all(temp.path().join("Downloads")).unwrap();
+    fs::write(temp.path().join("Downloads/movie.mkv"), vec![0u8; 100]).unwrap();
+    fs::create_dir_all(temp.path().join("Brazil (1985)")).unwrap();
+
+    let index = scan_directory(temp.path(), "D1", None, "movies").unwrap();
+    let mut found: Vec<(&str, Option<u64>, u64)> = index
+        .movies
+        .iter()
+        .map(|m| (m.title.as_str(), m.tmdb_id, m.size_bytes))
+        .collect();
+    found.sort();
+    assert_eq!(
+        found,
+        vec![
+            ("Alien", None, 100),
+            // Videos below an NFO folder belong to it
+            ("Heat", Some(949), 200),
+            ("The Matrix", Some(603), 100),
+            ("升级", Some(500664), 100),
+        ]
+    );
+    let upgrade = index.movies.iter().find(|m| m.title == "升级").unwrap();
+    assert_eq!(upgrade.original_title.as_deref(), Some("Upgrade"));
+    assert_eq!(upgrade.imdb_id.as_deref(), Some("tt6499752"));
+
+    // TV shows are recognized with episodes in season folders
+    let temp = TempDir::new().unwrap();
+    let show_dir = temp.path().join("Dark (2017) [tmdbid-70523]/Season 01");
+    fs::create_dir_all(&show_dir).unwrap();
+    fs::write(show_dir.join("Dark - S01E01.mkv"), vec![0u8; 10]).unwrap();
+    fs::write(show_dir.join("Dark - S01E02.mkv"), vec![0u8; 10]).unwrap();
+    let index = scan_directory(temp.path(), "D1", None, "tvshows").unwrap();
+    assert_eq!(index.tvshows.len(), 1);
+    let show = &index.tvshows[0];
+    assert_eq!((show.title.as_str(), show.tmdb_id), ("Dark", Some(70523)));
+    assert_eq!((show.seasons, show.episodes), (1, 2));
+}
+
+#[test]
+fn test_apply_movie_details_fills_empty_fields() {
+    let details: MovieDetails = serde_json::from_value(serde_json::json!({
+        "id": 603,
+        "imdb_id": "tt0133093",
+        "title": "The Matrix",
+        "original_title": "The Matrix",
+        "original_language": "en",
+        "release_date": "1999-03-30",
+        "runtime": 136,
+        "vote_average": 8.2,
+        "genres": [{"id": 28, "name": "Action"}],
+        "production_countries": [{"iso_3166_1": "US", "name": "United States of America"}],
+        "credits": {
+            "cast": [{"id": 6384, "name": "Keanu Reeves"}],
+            "crew": [
+                {"id": 9339, "name": "Lilly Wachowski", "job": "Director", "department": "Directing"},
+                {"id": 9340, "name": "Joel Silver", "job": "Producer", "department": "Production"}
+            ]
+        },
+        "belongs_to_collection": {"id": 2344, "name": "The Matrix Collection"}
+    }))
+    .unwrap();
+
+    // As indexed from a folder name, with a rating set by hand
+    let mut movie = create_test_movie("m1", "Matrix", "D1", 0);
+    movie.tmdb_id = None;
+    movie.country = None;
+    movie.genres.clear();
+    movie.actors.clear();
+    movie.directors.clear();
+    movie.runtime = None;
+    movie.rating = Some(9.0);
+    apply_movie_details(&mut movie, &details);
+
+    assert_eq!(movie.tmdb_id, Some(603));
+    assert_eq!(movie.imdb_id.as_deref(), Some("tt0133093"));
+    assert_eq!(movie.original_title.as_deref(), Some("The Matrix"));
+    assert_eq!(movie.genres, vec!["Action"]);
+    assert_eq!(movie.actors, vec!["Keanu Reeves"]);
+    assert_eq!(movie.directors, vec!["Lilly Wachowski"]);
+    assert_eq!(movie.country.as_deref(), Some("US"));
+    assert_eq!(movie.collection_id, Some(2344));
+    assert_eq!(movie.runtime, Some(136));
+    // Values already indexed are kept
+    assert_eq!(movie.rating, Some(9.0));
+    assert_eq!(movie.title, "Matrix");
+}
+
+// ========== DISK TRACKING TESTS ==========
+
+#[test]
+fn test_parse_df_output() {
+    let output = "Filesystem     Type 1-blocks          Used     Available Capacity Mounted on
+/dev/sdb1      ext4 4000787030016 3000000000000 1000787030016      75% /run/media/johnny/My Disk
+";
+    let mount = parse_df_output(output).unwrap();
+    assert_eq!(mount.device, "/dev/sdb1");
+    assert_eq!(mount.filesystem, "ext4");
+    assert_eq!(mount.capacity_bytes, 4_000_787_030_016);
+    assert_eq!(mount.free_bytes, 1_000_787_030_016);
+    assert_eq!(
+        mount.mount_point,
+        std::path::PathBuf::from("/run/media/johnny/My Disk")
+    );
+    assert!(parse_df_output("Filesystem Type").is_none());
+}
+
+#[test]
+fn test_mount_point_of_device() {
+    let mounts = "/dev/sda2 / btrfs rw 0 0
+/dev/sdz9 /mnt/Media\\040Disk ntfs3 rw 0 0
+";
+    assert_eq!(
+        mount_point_of_device(mounts, std::path::Path::new("/dev/sdz9")),
+        Some(std::path::PathBuf::from("/mnt/Media Disk"))
+    );
+    assert!(mount_point_of_device(mounts, std::path::Path::new("/dev/sdz8")).is_none());
+}
+
+#[test]
+fn test_disk_mounted_elsewhere_keeps_paths_and_space() {
+    let mut disk = create_movie_disk_index("D1", "/run/media/johnny/D1/Movies", vec![]).disk;
+    disk.mount_point = Some("/run/media/johnny/D1".to_string());
+
+    // Paths are followed to where the disk is mounted now
+    assert_eq!(
+        current_disk_paths(&disk, std::path::Path::new("/mnt/d1")),
+        vec![
+            std::path::PathBuf::from("/mnt/d1/Movies"),
+            std::path::PathBuf::from("/mnt/d1/Movies")
+        ]
+    );
+
+    let mount = MountInfo {
+        device: "/dev/sdb1".to_string(),
+        filesystem: "exfat".to_string(),
+        capacity_bytes: 2_000,
+        free_bytes: 500,
+        mount_point: std::path::PathBuf::from("/mnt/d1"),
+    };
+    record_mount(&mut disk, &mount);
+    assert_eq!(disk.paths["movies"], "/mnt/d1/Movies");
+    assert_eq!(disk.base_path, "/mnt/d1/Movies");
+    assert_eq!(disk.free_bytes, Some(500));
+    assert_eq!(disk.filesystem.as_deref(), Some("exfat"));
+    assert!(disk.last_seen.is_some());
+
+    // A rescan records the space; later scans without it keep what was seen
+    let mut central = CentralIndex::default();
+    let mut scan = create_movie_disk_index("D1", "/mnt/d1/Movies", vec![]);
+    scan.disk = disk.clone();
+    merge_disk_into_central(&mut central, scan);
+    merge_disk_into_central(
+        &mut central,
+        create_movie_disk_index("D1", "/mnt/d1/Movies", vec![]),
+    );
+    assert_eq!(central.disks["D1"].capacity_bytes, Some(2_000));
+    assert_eq!(central.disks["D1"].mount_point.as_deref(), Some("/mnt/d1"));
+}
+
+// ========== USER DATA TESTS ==========
+
+#[test]
+fn test_user_data_imported_and_kept_across_rescans() {
+    let temp = TempDir::new().unwrap();
+    write_movie(temp.path(), "Heat (1995)", "Heat", 949);
+    write_movie(temp.path(), "Alien (1979)", "Alien", 348);
+    let alien_nfo = temp.path().join("Alien (1979)/movie.nfo");
+    fs::write(
+        &alien_nfo,
+        "<movie><title>Alien</title><tmdbid>348</tmdbid><playcount>3</playcount>\
+         <lastplayed>2024-05-01 21:30:00</lastplayed><userrating>8</userrating></movie>",
+    )
+    .unwrap();
+
+    let mut central = CentralIndex::default();
+    let first = scan_directory(temp.path(), "D1", None, "movies").unwrap();
+    merge_disk_into_central(&mut central, first);
+    let find = |central: &CentralIndex, title: &str| {
+        central
+            .movies
+            .iter()
+            .find(|m| m.title == title)
+            .unwrap()
+            .user_data
+            .clone()
+    };
+    let alien = find(&central, "Alien");
+    assert!(alien.watched);
+    assert_eq!(alien.play_count, 3);
+    assert_eq!(alien.watched_at.as_deref(), Some("2024-05-01 21:30:00"));
+    assert_eq!(alien.rating, Some(8.0));
+    assert!(find(&central, "Heat").is_empty());
+
+    // Marked by hand
+    let heat = central
+        .movies
+        .iter_mut()
+        .find(|m| m.title == "Heat")
+        .unwrap();
+    heat.user_data.apply(&UserDataUpdate {
+        watched: Some(true),
+        watched_at: Some("2024-06-01".to_string()),
+        add_tags: vec!["Rewatch".to_string()],
+        notes: Some("Diner scene".to_string()),
+        ..Default::default()
+    });
+
+    // A full rescan creates new entries; the media center reset its play count
+    fs::write(
+        &alien_nfo,
+        "<movie><title>Alien</title><tmdbid>348</tmdbid><playcount>0</playcount></movie>",
+    )
+    .unwrap();
+    let rescan = scan_directory(temp.path(), "D1", None, "movies").unwrap();
+    merge_disk_into_central(&mut central, rescan);
+
+    let heat = find(&central, "Heat");
+    assert!(heat.watched);
+    assert_eq!(heat.watched_at.as_deref(), Some("2024-06-01"));
+    assert_eq!(heat.tags, vec!["Rewatch"]);
+    assert_eq!(heat.notes.as_deref(), Some("Diner scene"));
+    assert!(find(&central, "Alien").watched);
+
+    let watched = Query::parse("watched:yes tag:rewatch").unwrap();
+    let results = watched.apply(search(
+        &central, None, None, None, None, None, None, None, None,
+    ));
+    assert_eq!(results.movies.len(), 1);
+    assert_eq!(results.movies[0].title, "Heat");
+}
+
+#[test]
+fn test_user_data_marked_unwatched_not_reset_by_nfo() {
+    let temp = TempDir::new().unwrap();
+    write_movie(temp.path(), "Alien (1979)", "Alien", 348);
+    fs::write(
+        temp.path().join("Alien (1979)/movie.nfo"),
+        "<movie><title>Alien</title><tmdbid>348</tmdbid><playcount>3</playcount>\
+         <lastplayed>2024-05-01 21:30:00</lastplayed></movie>",
+    )
+    .unwrap();
+
+    let mut central = CentralIndex::default();
+    let first = scan_directory(temp.path(), "D1", None, "movies").unwrap();
+    merge_disk_into_central(&mut central, first);
+    assert!(central.movies[0].user_data.watched);
+
+    central.movies[0].user_data.apply(&UserDataUpdate {
+        watched: Some(false),
+        ..Default::default()
+    });
+
+    // The NFO still records the old plays
+    let rescan = scan_directory(temp.path(), "D1", None, "movies").unwrap();
+    merge_disk_into_central(&mut central, rescan);
+    let alien = &central.movies[0].user_data;
+    assert!(!alien.watched);
+    assert_eq!(alien.play_count, 0);
+    assert_eq!(alien.watched_at, None);
+}
+
 // ========== BACKWARD COMPATIBILITY TESTS ==========
 
 #[test]
@@ -3,8 +3,10 @@
 //! Tests cover:
 //! - Plan save/load
 //! - Rollback save/load
+//! - Schema migration on load
 //! - Session management
 
+use media_organizer::core::migration::{PLAN_VERSION, ROLLBACK_VERSION};
 use media_organizer::core::planner::{load_plan, save_plan};
 use media_organizer::core::rollback::{load_rollback, save_rollback};
 use media_organizer::models::media::MediaType;
@@ -18,7 +20,7 @@ use tempfile::TempDir;
 #[test]
 fn test_save_and_load_plan() {
     let plan = Plan {
-        version: "1.0".to_string(),
+        version: PLAN_VERSION.to_string(),
         created_at: "2024-01-01T00:00:00Z".to_string(),
         media_type: Some(MediaType::Movies),
         source_path: PathBuf::from("/source"),
@@ -64,6 +66,37 @@ fn test_plan_round_trip_with_items() {
     assert_eq!(loaded.media_type, Some(MediaType::TvShows));
 }
 
+#[test]
+fn test_load_migrates_old_plan() {
+    let temp_dir = TempDir::new().unwrap();
+    let plan_path = temp_dir.path().join("old_plan.json");
+    std::fs::write(
+        &plan_path,
+        r#"{
+            "version": "1.0",
+            "created_at": "2024-01-01T00:00:00Z",
+            "media_type": "movies",
+            "source_path": "/source",
+            "target_path": "/target",
+            "items": []
+        }"#,
+    )
+    .unwrap();
+
+    let loaded = load_plan(&plan_path).unwrap();
+    assert_eq!(loaded.version, PLAN_VERSION);
+    assert!(loaded.unknown.is_empty());
+}
+
+#[test]
+fn test_load_rejects_newer_plan() {
+    let temp_dir = TempDir::new().unwrap();
+    let plan_path = temp_dir.path().join("future_plan.json");
+    std::fs::write(&plan_path, r#"{"version": "99.0", "items": []}"#).unwrap();
+
+    assert!(load_plan(&plan_path).is_err());
+}
+
 #[test]
 fn test_load_nonexistent_plan() {
     let result = load_plan(&PathBuf::from("/nonexistent/plan.json"));
@@ -83,15 +116,15 @@ fn test_save_and_load_rollback() {
     save_rollback(&rollback, &path).unwrap();
     assert!(path.exists());
 
-    // Load
+    // Load (unversioned files are migrated to the current schema)
     let loaded = load_rollback(&path).unwrap();
-    assert_eq!(loaded.version, rollback.version);
+    assert_eq!(loaded.version, ROLLBACK_VERSION);
 }
 
 #[test]
 fn test_rollback_round_trip() {
     let rollback = Rollback {
-        version: "1.0".to_string(),
+        version: ROLLBACK_VERSION.to_string(),
         plan_id: "test-plan-id".to_string(),
         executed_at: chrono::Utc::now().to_rfc3339(),
         operations: vec![],
@@ -0,0 +1,153 @@
+//! Integration tests for media server library refresh.
+//!
+//! Tests cover:
+//! - Affected folder detection from a rollback
+//! - Request building and path translation
+//! - Jellyfin/Emby and Plex requests against a local mock server
+
+mod common;
+
+use media_organizer::models::config::{MediaServerConfig, MediaServerKind};
+use media_organizer::models::rollback::{
+    Rollback, RollbackAction, RollbackActionType, RollbackOpType, RollbackOperation,
+};
+use media_organizer::services::media_server::{affected_folders, build_requests, send_requests};
+use std::path::PathBuf;
+
+fn op(seq: u32, op_type: RollbackOpType, to: &str, executed: bool) -> RollbackOperation {
+    RollbackOperation {
+        seq,
+        op_type,
+        from: PathBuf::from("/in/file"),
+        to: PathBuf::from(to),
+        checksum: None,
+        rollback: RollbackAction {
+            op: RollbackActionType::Move,
+            path: PathBuf::from(to),
+            to: None,
+        },
+        executed,
+    }
+}
+
+fn server(kind: MediaServerKind, url: &str) -> MediaServerConfig {
+    MediaServerConfig {
+        kind,
+        url: url.to_string(),
+        token: "secret".to_string(),
+        section_id: Some(2),
+        local_root: None,
+        server_root: None,
+    }
+}
+
+// ========== FOLDER DETECTION ==========
+
+#[test]
+fn test_affected_folders() {
+    let rollback = Rollback {
+        operations: vec![
+            op(1, RollbackOpType::Mkdir, "/lib/B (2002)", true),
+            op(2, RollbackOpType::Move, "/lib/B (2002)/B.mkv", true),
+            op(3, RollbackOpType::Move, "/lib/B (2002)/B.srt", true),
+            op(4, RollbackOpType::Move, "/lib/A (2001)/A.mkv", true),
+            op(5, RollbackOpType::Move, "/lib/C (2003)/C.mkv", false),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        affected_folders(&rollback),
+        vec![
+            PathBuf::from("/lib/A (2001)"),
+            PathBuf::from("/lib/B (2002)")
+        ]
+    );
+}
+
+// ========== REQUEST BUILDING ==========
+
+#[test]
+fn test_build_requests_translates_paths() {
+    let mut jellyfin = server(MediaServerKind::Jellyfin, "http://jf:8096/");
+    jellyfin.local_root = Some(PathBuf::from("/mnt/media"));
+    jellyfin.server_root = Some(PathBuf::from("/data"));
+    let folders = vec![
+        PathBuf::from("/mnt/media/Movies/A (2001)"),
+        PathBuf::from("/other/B"),
+    ];
+
+    let requests = build_requests(&jellyfin, &folders).unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].url, "http://jf:8096/Library/Media/Updated");
+    let body = requests[0].body.as_ref().unwrap();
+    assert_eq!(body["Updates"][0]["Path"], "/data/Movies/A (2001)");
+    assert_eq!(body["Updates"][0]["UpdateType"], "Created");
+    assert_eq!(body["Updates"][1]["Path"], "/other/B");
+
+    let plex = server(MediaServerKind::Plex, "http://plex:32400");
+    let requests = build_requests(&plex, &folders).unwrap();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(
+        requests[0].url,
+        "http://plex:32400/library/sections/2/refresh?path=%2Fmnt%2Fmedia%2FMovies%2FA%20%282001%29"
+    );
+    assert!(requests.iter().all(|r| r.body.is_none()));
+}
+
+#[test]
+fn test_plex_requires_section() {
+    let mut plex = server(MediaServerKind::Plex, "http://plex:32400");
+    plex.section_id = None;
+    assert!(build_requests(&plex, &[PathBuf::from("/lib/A")]).is_err());
+    assert!(build_requests(&plex, &[]).unwrap().is_empty());
+}
+
+// ========== MOCK SERVER TESTS ==========
+
+#[tokio::test]
+async fn test_jellyfin_refresh_against_mock() {
+    let (url, mut requests) = common::start_http_stub(204).await;
+    let emby = server(MediaServerKind::Emby, &url);
+    let built = build_requests(&emby, &[PathBuf::from("/lib/A (2001)")]).unwrap();
+
+    send_requests(&emby, &built).await.unwrap();
+
+    let request = requests.recv().await.unwrap();
+    assert_eq!(request.request_line, "POST /Library/Media/Updated HTTP/1.1");
+    assert!(request
+        .headers
+        .iter()
+        .any(|h| h.eq_ignore_ascii_case("x-emby-token: secret")));
+    let body: serde_json::Value = serde_json::from_str(&request.body).unwrap();
+    assert_eq!(body["Updates"][0]["Path"], "/lib/A (2001)");
+}
+
+#[tokio::test]
+async fn test_plex_refresh_against_mock() {
+    let (url, mut requests) = common::start_http_stub(200).await;
+    let plex = server(MediaServerKind::Plex, &url);
+    let built = build_requests(&plex, &[PathBuf::from("/lib/A")]).unwrap();
+
+    send_requests(&plex, &built).await.unwrap();
+
+    let request = requests.recv().await.unwrap();
+    assert_eq!(
+        request.request_line,
+        "GET /library/sections/2/refresh?path=%2Flib%2FA HTTP/1.1"
+    );
+    assert!(request
+        .headers
+        .iter()
+        .any(|h| h.eq_ignore_ascii_case("x-plex-token: secret")));
+}
+
+#[tokio::test]
+async fn test_refresh_error_status() {
+    let (url, _requests) = common::start_http_stub(401).await;
+    let jellyfin = server(MediaServerKind::Jellyfin, &url);
+    let built = build_requests(&jellyfin, &[PathBuf::from("/lib/A")]).unwrap();
+
+    let err = send_requests(&jellyfin, &built).await.unwrap_err();
+    assert!(err.to_string().contains("401"));
+}
@@ -0,0 +1,329 @@
+//! Integration tests for the HTTP/JSON API server.
+//!
+//! Tests cover:
+//! - Health and schema endpoints
+//! - Session listing and plan item edits
+//! - Execution and rollback through the API
+//! - Background plan jobs
+//! - Error responses
+
+use media_organizer::core::migration::PLAN_VERSION;
+use media_organizer::core::planner::{load_plan, save_to_sessions_in};
+use media_organizer::core::sessions;
+use media_organizer::models::media::{MediaType, VideoFile, VideoMetadata};
+use media_organizer::models::plan::{
+    Operation, OperationType, ParsedInfo, Plan, PlanItem, PlanItemStatus, TargetInfo,
+};
+use media_organizer::server::{self, AppState};
+use std::path::Path;
+use tempfile::TempDir;
+use tokio::net::TcpListener;
+
+/// Start a server on an ephemeral port and return its base URL.
+async fn start_server(sessions_dir: &Path) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let state = AppState::new(sessions_dir.to_path_buf());
+    tokio::spawn(server::serve_on(listener, state));
+    format!("http://{}/api", addr)
+}
+
+/// Create a session with a one-item plan moving `source/a.mkv` to `target/A/a.mkv`.
+fn create_session(root: &Path) -> (String, Plan) {
+    let source = root.join("source");
+    let target = root.join("target");
+    std::fs::create_dir_all(&source).unwrap();
+    std::fs::write(source.join("a.mkv"), b"video").unwrap();
+
+    let video = source.join("a.mkv");
+    let folder = target.join("A");
+    let full_path = folder.join("a.mkv");
+    let plan = Plan {
+        version: PLAN_VERSION.to_string(),
+        created_at: chrono::Utc::now().to_rfc3339(),
+        media_type: Some(MediaType::Movies),
+        source_path: source.clone(),
+        target_path: target.clone(),
+        items: vec![PlanItem {
+            id: "item-1".to_string(),
+            status: PlanItemStatus::Pending,
+            source: VideoFile {
+                path: video.clone(),
+                filename: "a.mkv".to_string(),
+                size: 5,
+                modified: chrono::Utc::now(),
+                is_sample: false,
+                parent_dir: source.clone(),
+            },
+            parsed: ParsedInfo::default(),
+            movie_metadata: None,
+            tvshow_metadata: None,
+            episode_metadata: None,
+            video_metadata: VideoMetadata::default(),
+            target: TargetInfo {
+                folder: "A".to_string(),
+                filename: "a.mkv".to_string(),
+                full_path: full_path.clone(),
+                nfo: "a.nfo".to_string(),
+                poster: None,
+            },
+            operations: vec![
+                Operation {
+                    op: OperationType::Mkdir,
+                    from: None,
+                    to: folder,
+                    url: None,
+                    content_ref: None,
+                },
+                Operation {
+                    op: OperationType::Move,
+                    from: Some(video),
+                    to: full_path,
+                    url: None,
+                    content_ref: None,
+                },
+            ],
+            placement: None,
+        }],
+        samples: vec![],
+        unknown: vec![],
+    };
+
+    let sessions_dir = root.join("sessions");
+    let session_dir = save_to_sessions_in(&sessions_dir, &plan).unwrap();
+    let id = session_dir
+        .file_name()
+        .unwrap()
+        .to_string_lossy()
+        .to_string();
+    (id, plan)
+}
+
+// ========== METADATA ENDPOINTS ==========
+
+#[tokio::test]
+async fn test_health_and_schemas() {
+    let temp = TempDir::new().unwrap();
+    let base = start_server(&temp.path().join("sessions")).await;
+    let client = reqwest::Client::new();
+
+    let health: serde_json::Value = client
+        .get(format!("{}/health", base))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(health["status"], "ok");
+
+    let names: Vec<String> = client
+        .get(format!("{}/schemas", base))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(names.contains(&"plan".to_string()));
+
+    let schema: serde_json::Value = client
+        .get(format!("{}/schemas/plan", base))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(schema["title"], "Plan");
+    assert!(schema["properties"]["items"].is_object());
+
+    let missing = client
+        .get(format!("{}/schemas/nope", base))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(missing.status(), 404);
+}
+
+// ========== SESSION ENDPOINTS ==========
+
+#[tokio::test]
+async fn test_list_sessions_and_edit_item() {
+    let temp = TempDir::new().unwrap();
+    let (id, _) = create_session(temp.path());
+    let base = start_server(&temp.path().join("sessions")).await;
+    let client = reqwest::Client::new();
+
+    let list: serde_json::Value = client
+        .get(format!("{}/sessions", base))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(list[0]["id"], id.as_str());
+    assert_eq!(list[0]["manifest"]["status"], "planned");
+
+    let item: serde_json::Value = client
+        .patch(format!("{}/sessions/{}/items/item-1", base, id))
+        .json(&serde_json::json!({ "status": "skip" }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(item["status"], "skip");
+
+    let session = sessions::get(&temp.path().join("sessions"), &id).unwrap();
+    let plan = load_plan(&session.plan_path()).unwrap();
+    assert_eq!(plan.items[0].status, PlanItemStatus::Skip);
+
+    let missing = client
+        .patch(format!("{}/sessions/{}/items/nope", base, id))
+        .json(&serde_json::json!({ "status": "skip" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(missing.status(), 404);
+}
+
+#[tokio::test]
+async fn test_execute_and_rollback_session() {
+    let temp = TempDir::new().unwrap();
+    let (id, plan) = create_session(temp.path());
+    let base = start_server(&temp.path().join("sessions")).await;
+    let client = reqwest::Client::new();
+    let source = &plan.items[0].source.path;
+    let target = &plan.items[0].target.full_path;
+
+    let manifest: serde_json::Value = client
+        .post(format!("{}/sessions/{}/execute", base, id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(manifest["status"], "executed");
+    assert!(target.exists());
+    assert!(!source.exists());
+
+    // Executing twice is refused
+    let again = client
+        .post(format!("{}/sessions/{}/execute", base, id))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(again.status(), 409);
+
+    let result: serde_json::Value = client
+        .post(format!("{}/sessions/{}/rollback", base, id))
+        .json(&serde_json::json!({ "dry_run": false }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(result["error_count"], 0);
+    assert!(source.exists());
+    assert!(!target.exists());
+
+    let detail: serde_json::Value = client
+        .get(format!("{}/sessions/{}", base, id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(detail["manifest"]["status"], "rolled-back");
+}
+
+// ========== PLAN JOBS ==========
+
+#[tokio::test]
+async fn test_plan_job_saves_session() {
+    let temp = TempDir::new().unwrap();
+    let source = temp.path().join("empty");
+    std::fs::create_dir_all(&source).unwrap();
+    let base = start_server(&temp.path().join("sessions")).await;
+    let client = reqwest::Client::new();
+
+    let response = client
+        .post(format!("{}/plans", base))
+        .json(&serde_json::json!({
+            "source": source,
+            "target": temp.path().join("target"),
+            "media_type": "movies",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 202);
+    let job: serde_json::Value = response.json().await.unwrap();
+    let job_id = job["id"].as_str().unwrap().to_string();
+
+    let mut status = serde_json::Value::Null;
+    for _ in 0..50 {
+        status = client
+            .get(format!("{}/jobs/{}", base, job_id))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        if status["status"] == "completed" || status["status"] == "failed" {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    assert_eq!(status["status"], "completed");
+
+    let session_id = status["session_id"].as_str().unwrap();
+    assert!(sessions::get(&temp.path().join("sessions"), session_id).is_ok());
+}
+
+// ========== ERROR RESPONSES ==========
+
+#[tokio::test]
+async fn test_unknown_session_and_job() {
+    let temp = TempDir::new().unwrap();
+    let base = start_server(&temp.path().join("sessions")).await;
+    let client = reqwest::Client::new();
+
+    let session = client
+        .get(format!("{}/sessions/20240101_000000_deadbeef", base))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(session.status(), 404);
+    let body: serde_json::Value = session.json().await.unwrap();
+    assert!(body["error"]
+        .as_str()
+        .unwrap()
+        .contains("Session not found"));
+
+    let job = client
+        .get(format!("{}/jobs/unknown", base))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(job.status(), 404);
+
+    let plan = client
+        .post(format!("{}/plans", base))
+        .json(&serde_json::json!({
+            "source": temp.path().join("missing"),
+            "target": temp.path().join("target"),
+            "media_type": "movies",
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(plan.status(), 400);
+}
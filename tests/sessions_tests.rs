@@ -0,0 +1,191 @@
+//! Integration tests for session tracking across commands.
+//!
+//! Tests cover:
+//! - Executing a session plan marks the session as executed
+//! - Rolling back with the rollback command leaves nothing for undo-last
+//! - A partial rollback leaves the rest for undo-last
+
+use media_organizer::cli::commands::execute::{execute_plan, LibraryRefresh};
+use media_organizer::cli::commands::rollback::rollback;
+use media_organizer::cli::commands::sessions::undo_last;
+use media_organizer::core::planner;
+use media_organizer::core::rollback::{self as core_rollback, RollbackSelection};
+use media_organizer::core::sessions;
+use media_organizer::models::media::{MediaType, MovieMetadata, VideoFile, VideoMetadata};
+use media_organizer::models::plan::{
+    Operation, OperationType, ParsedInfo, Plan, PlanItem, PlanItemStatus, TargetInfo,
+};
+use media_organizer::models::session::SessionStatus;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Tests point HOME at their own directory, so they must not run at once.
+static HOME_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+fn movie_item(source: &Path, target: &Path) -> PlanItem {
+    PlanItem {
+        id: "movie".to_string(),
+        status: PlanItemStatus::Pending,
+        source: VideoFile {
+            path: source.to_path_buf(),
+            filename: source.file_name().unwrap().to_string_lossy().to_string(),
+            size: 0,
+            modified: chrono::Utc::now(),
+            is_sample: false,
+            parent_dir: source.parent().unwrap().to_path_buf(),
+        },
+        parsed: ParsedInfo::default(),
+        movie_metadata: Some(MovieMetadata {
+            title: "Movie".to_string(),
+            year: 2020,
+            ..Default::default()
+        }),
+        tvshow_metadata: None,
+        episode_metadata: None,
+        video_metadata: VideoMetadata::default(),
+        target: TargetInfo {
+            folder: String::new(),
+            filename: String::new(),
+            full_path: target.to_path_buf(),
+            nfo: String::new(),
+            poster: None,
+        },
+        operations: vec![Operation {
+            op: OperationType::Move,
+            from: Some(source.to_path_buf()),
+            to: target.to_path_buf(),
+            url: None,
+            content_ref: None,
+        }],
+        placement: None,
+    }
+}
+
+// ========== Rollback Command ==========
+
+#[tokio::test]
+async fn test_rollback_command_leaves_nothing_to_undo() {
+    let _home_lock = HOME_LOCK.lock().await;
+    let home = TempDir::new().unwrap();
+    std::env::set_var("HOME", home.path());
+
+    let work = TempDir::new().unwrap();
+    let source_dir = work.path().join("in");
+    let target_dir = work.path().join("out");
+    fs::create_dir_all(&source_dir).unwrap();
+    fs::create_dir_all(&target_dir).unwrap();
+    let source = source_dir.join("movie.mkv");
+    let target = target_dir.join("Movie (2020).mkv");
+    fs::write(&source, "video").unwrap();
+
+    let plan = Plan {
+        media_type: Some(MediaType::Movies),
+        source_path: source_dir.clone(),
+        target_path: target_dir.clone(),
+        items: vec![movie_item(&source, &target)],
+        ..Default::default()
+    };
+    let session_dir = planner::save_to_sessions(&plan).unwrap();
+    let sessions_dir = planner::sessions_dir().unwrap();
+
+    let rollback_file = work.path().join("rollback.json");
+    execute_plan(
+        &session_dir.join("plan.json"),
+        Some(&rollback_file),
+        false,
+        LibraryRefresh::Off,
+    )
+    .await
+    .unwrap();
+    assert!(target.exists());
+    assert!(sessions::latest_executed(&sessions_dir).unwrap().is_some());
+
+    rollback(
+        &rollback_file,
+        false,
+        &RollbackSelection::default(),
+        None,
+        false,
+    )
+    .await
+    .unwrap();
+    assert!(source.exists());
+    assert!(!target.exists());
+
+    // undo-last must not replay the rollback that was already applied
+    assert!(sessions::latest_executed(&sessions_dir).unwrap().is_none());
+    undo_last(false).await.unwrap();
+    assert!(source.exists());
+}
+
+#[tokio::test]
+async fn test_partial_rollback_leaves_rest_for_undo_last() {
+    let _home_lock = HOME_LOCK.lock().await;
+    let home = TempDir::new().unwrap();
+    std::env::set_var("HOME", home.path());
+
+    let work = TempDir::new().unwrap();
+    let source_dir = work.path().join("in");
+    let target_dir = work.path().join("out");
+    fs::create_dir_all(&source_dir).unwrap();
+    fs::create_dir_all(&target_dir).unwrap();
+    let sources = [source_dir.join("a.mkv"), source_dir.join("b.mkv")];
+    let targets = [
+        target_dir.join("A (2020).mkv"),
+        target_dir.join("B (2020).mkv"),
+    ];
+    let mut items = Vec::new();
+    for (n, (source, target)) in sources.iter().zip(&targets).enumerate() {
+        fs::write(source, "video").unwrap();
+        let mut item = movie_item(source, target);
+        item.id = format!("movie-{}", n);
+        items.push(item);
+    }
+
+    let plan = Plan {
+        media_type: Some(MediaType::Movies),
+        source_path: source_dir.clone(),
+        target_path: target_dir.clone(),
+        items,
+        ..Default::default()
+    };
+    let session_dir = planner::save_to_sessions(&plan).unwrap();
+    let sessions_dir = planner::sessions_dir().unwrap();
+
+    let rollback_file = work.path().join("rollback.json");
+    execute_plan(
+        &session_dir.join("plan.json"),
+        Some(&rollback_file),
+        false,
+        LibraryRefresh::Off,
+    )
+    .await
+    .unwrap();
+
+    let selection = RollbackSelection {
+        seq_range: Some(core_rollback::parse_seq_range("1").unwrap()),
+        ..Default::default()
+    };
+    rollback(&rollback_file, false, &selection, None, false)
+        .await
+        .unwrap();
+    let restored = sources.iter().filter(|s| s.exists()).count();
+    assert_eq!(restored, 1);
+
+    let session = sessions::latest_executed(&sessions_dir).unwrap().unwrap();
+    let manifest = session.manifest();
+    assert_eq!(manifest.status, SessionStatus::PartiallyRolledBack);
+    let residual = core_rollback::residual_rollback_path(&rollback_file);
+    assert_eq!(
+        manifest.residual_rollback_path.as_deref(),
+        Some(residual.as_path())
+    );
+
+    // undo-last rolls back only what is left
+    undo_last(false).await.unwrap();
+    assert!(sources.iter().all(|s| s.exists()));
+    assert!(targets.iter().all(|t| !t.exists()));
+    assert_eq!(session.manifest().status, SessionStatus::RolledBack);
+    assert!(sessions::latest_executed(&sessions_dir).unwrap().is_none());
+}